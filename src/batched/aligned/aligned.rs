@@ -0,0 +1,306 @@
+use ark_ff::Field;
+use ark_std::marker::PhantomData;
+
+use crate::streams::Stream;
+
+/*
+ * Which of the batch's variables the smaller polynomial depends on: Front puts its
+ * variables in the first rounds, Back in the last ones. Either way, the sum over
+ * the bigger hypercube counts every evaluation 2^(n - m) times
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Alignment {
+    Front,
+    Back,
+}
+
+// the claim of a polynomial in inner_num_variables as a sum over num_variables
+pub fn aligned_claim<F: Field>(claim: F, inner_num_variables: usize, num_variables: usize) -> F {
+    claim * F::from(2_u32).pow([(num_variables - inner_num_variables) as u64])
+}
+
+/*
+ * A stream over fewer variables seen over the batch's hypercube, so it joins the
+ * batch's pass like any other stream. The first round binds the leftmost bit, so
+ * Front reads the inner stream at the high bits of a point and Back at the low ones
+ */
+#[derive(Clone, Debug)]
+pub struct AlignedStream<F: Field, S: Stream<F>> {
+    pub alignment: Alignment,
+    pub num_variables: usize,
+    pub stream: S,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field, S: Stream<F>> AlignedStream<F, S> {
+    pub fn new(stream: S, num_variables: usize, alignment: Alignment) -> Self {
+        // the batch can't have fewer variables than any of its claims
+        assert!(stream.num_variables() <= num_variables);
+        Self {
+            alignment,
+            num_variables,
+            stream,
+            _field: PhantomData,
+        }
+    }
+    fn num_dummy_variables(&self) -> usize {
+        self.num_variables - self.stream.num_variables()
+    }
+    fn inner_point(&self, point: usize) -> usize {
+        match self.alignment {
+            Alignment::Front => point >> self.num_dummy_variables(),
+            Alignment::Back => point & ((1 << self.stream.num_variables()) - 1),
+        }
+    }
+}
+
+impl<F: Field, S: Stream<F>> Stream<F> for AlignedStream<F, S> {
+    fn evaluation(&self, point: usize) -> F {
+        self.stream.evaluation(self.inner_point(point))
+    }
+    fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+    fn evaluations_into(&self, start: usize, evaluations: &mut [F]) {
+        if evaluations.is_empty() {
+            return;
+        }
+        match self.alignment {
+            /*
+             * 2^(n - m) copies of each inner evaluation: the inner run is read into the
+             * tail and spread out from the front, which never overwrites a value before
+             * it's copied since every inner evaluation has at least one point left
+             */
+            Alignment::Front => {
+                let len = evaluations.len();
+                let inner_start = self.inner_point(start);
+                let inner_len = self.inner_point(start + len - 1) + 1 - inner_start;
+                self.stream
+                    .evaluations_into(inner_start, &mut evaluations[len - inner_len..]);
+                for offset in 0..len {
+                    let inner_offset = self.inner_point(start + offset) - inner_start;
+                    evaluations[offset] = evaluations[len - inner_len + inner_offset];
+                }
+            }
+            // the inner stream over again every 2^m points, a run of it at a time
+            Alignment::Back => {
+                let inner_len = 1 << self.stream.num_variables();
+                let mut offset = 0;
+                while offset < evaluations.len() {
+                    let inner_point = self.inner_point(start + offset);
+                    let run_len = (inner_len - inner_point).min(evaluations.len() - offset);
+                    self.stream
+                        .evaluations_into(inner_point, &mut evaluations[offset..offset + run_len]);
+                    offset += run_len;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+    use ark_std::rand::Rng;
+
+    use crate::{
+        batched::{
+            aligned_claim, batched_claim, AlignedStream, Alignment, BatchedProductSumcheck,
+            BatchedSumcheck,
+        },
+        multilinear::{SumcheckVerifier, SumcheckVerifierConfig, TimeProver},
+        multilinear_product::{ProductSumcheckVerifier, ProductSumcheckVerifierConfig},
+        streams::{
+            multivariate_evaluation, multivariate_product_claim, multivariate_product_evaluation,
+            LinearCombinationStream, MemoryStream, Stream,
+        },
+        tests::F64,
+        verifier::Verifier,
+        virtual_polynomial::TimeVirtualProver,
+    };
+
+    const NUM_VARIABLES: usize = 8;
+
+    // a full size claim, a front-loaded one and a back-loaded one
+    fn layout() -> Vec<(usize, Alignment)> {
+        vec![
+            (NUM_VARIABLES, Alignment::Front),
+            (5, Alignment::Front),
+            (3, Alignment::Back),
+        ]
+    }
+
+    fn random_stream(num_variables: usize, rng: &mut impl Rng) -> MemoryStream<F64> {
+        MemoryStream::new((0..1 << num_variables).map(|_| F64::rand(rng)).collect())
+    }
+
+    fn aligned(
+        stream: &MemoryStream<F64>,
+        alignment: Alignment,
+    ) -> AlignedStream<F64, MemoryStream<F64>> {
+        AlignedStream::new(stream.clone(), NUM_VARIABLES, alignment)
+    }
+
+    // the part of the point a polynomial actually depends on
+    fn sub_point(point: &[F64], num_variables: usize, alignment: Alignment) -> Vec<F64> {
+        match alignment {
+            Alignment::Front => point[..num_variables].to_vec(),
+            Alignment::Back => point[point.len() - num_variables..].to_vec(),
+        }
+    }
+
+    #[test]
+    fn evaluations() {
+        let mut rng = ark_std::test_rng();
+        let stream = random_stream(3, &mut rng);
+        // with dummy variables or without
+        for (inner, alignment) in [
+            (stream.clone(), Alignment::Front),
+            (stream.clone(), Alignment::Back),
+            (random_stream(6, &mut rng), Alignment::Front),
+        ] {
+            let aligned = AlignedStream::new(inner, 6, alignment);
+            for (start, len) in [(0, 64), (5, 13), (30, 0), (61, 3)] {
+                let mut evaluations = vec![F64::from(0); len];
+                aligned.evaluations_into(start, &mut evaluations);
+                for (offset, evaluation) in evaluations.iter().enumerate() {
+                    assert_eq!(*evaluation, aligned.evaluation(start + offset));
+                }
+            }
+        }
+        // the claim over the bigger hypercube is scaled
+        let claim: F64 = stream.evaluations.iter().sum();
+        let aligned = AlignedStream::new(stream, 6, Alignment::Back);
+        let aligned_sum: F64 = (0..1 << 6).map(|i| aligned.evaluation(i)).sum();
+        assert_eq!(aligned_sum, aligned_claim(claim, 3, 6));
+    }
+
+    #[test]
+    fn batched_sumcheck() {
+        let mut rng = ark_std::test_rng();
+        let streams: Vec<MemoryStream<F64>> = layout()
+            .into_iter()
+            .map(|(num_variables, _)| random_stream(num_variables, &mut rng))
+            .collect();
+        // the sums of the evaluations, scaled by the dummy variables
+        let claims: Vec<F64> = streams
+            .iter()
+            .zip(layout())
+            .map(|(stream, (num_variables, _))| {
+                aligned_claim(
+                    stream.evaluations.iter().sum(),
+                    num_variables,
+                    NUM_VARIABLES,
+                )
+            })
+            .collect();
+        let aligned_streams: Vec<AlignedStream<F64, MemoryStream<F64>>> = streams
+            .iter()
+            .zip(layout())
+            .map(|(stream, (_, alignment))| aligned(stream, alignment))
+            .collect();
+        let batch = BatchedSumcheck::<F64>::prove::<
+            AlignedStream<F64, MemoryStream<F64>>,
+            TimeProver<F64, LinearCombinationStream<F64, AlignedStream<F64, MemoryStream<F64>>>>,
+        >(
+            claims,
+            NUM_VARIABLES,
+            aligned_streams,
+            &mut ark_std::test_rng(),
+        );
+        assert!(batch.sumcheck.is_accepted);
+
+        let (point, reduced_claim) = SumcheckVerifier::new(SumcheckVerifierConfig::new_batched(
+            &batch.claims,
+            &batch.coefficients,
+            NUM_VARIABLES,
+        ))
+        .verify(
+            &batch.sumcheck.prover_messages,
+            &batch.sumcheck.verifier_messages,
+        )
+        .unwrap();
+        let evaluations: Vec<F64> = streams
+            .into_iter()
+            .zip(layout())
+            .map(|(stream, (num_variables, alignment))| {
+                multivariate_evaluation(stream, &sub_point(&point, num_variables, alignment))
+            })
+            .collect();
+        assert_eq!(
+            reduced_claim,
+            batched_claim(&evaluations, &batch.coefficients)
+        );
+    }
+
+    #[test]
+    fn batched_product_sumcheck() {
+        let mut rng = ark_std::test_rng();
+        let streams: Vec<Vec<MemoryStream<F64>>> = layout()
+            .into_iter()
+            .map(|(num_variables, _)| {
+                vec![
+                    random_stream(num_variables, &mut rng),
+                    random_stream(num_variables, &mut rng),
+                ]
+            })
+            .collect();
+        let claims: Vec<F64> = streams
+            .iter()
+            .zip(layout())
+            .map(|(streams, (num_variables, _))| {
+                aligned_claim(
+                    multivariate_product_claim(streams.clone()),
+                    num_variables,
+                    NUM_VARIABLES,
+                )
+            })
+            .collect();
+        // both factors of a claim are aligned the same way
+        let aligned_streams: Vec<AlignedStream<F64, MemoryStream<F64>>> = streams
+            .iter()
+            .zip(layout())
+            .flat_map(|(streams, (_, alignment))| {
+                streams.iter().map(move |stream| aligned(stream, alignment))
+            })
+            .collect();
+        let products = (0..streams.len()).map(|i| (2 * i, 2 * i + 1)).collect();
+        let batch = BatchedProductSumcheck::<F64>::prove::<
+            AlignedStream<F64, MemoryStream<F64>>,
+            TimeVirtualProver<F64, AlignedStream<F64, MemoryStream<F64>>>,
+        >(
+            claims,
+            NUM_VARIABLES,
+            aligned_streams,
+            products,
+            &mut ark_std::test_rng(),
+        );
+        assert!(batch.sumcheck.is_accepted);
+
+        let (point, reduced_claim) =
+            ProductSumcheckVerifier::new(ProductSumcheckVerifierConfig::new_batched(
+                &batch.claims,
+                &batch.coefficients,
+                NUM_VARIABLES,
+            ))
+            .verify(
+                &batch.sumcheck.prover_messages,
+                &batch.sumcheck.verifier_messages,
+            )
+            .unwrap();
+        let evaluations: Vec<F64> = streams
+            .into_iter()
+            .zip(layout())
+            .map(|(streams, (num_variables, alignment))| {
+                multivariate_product_evaluation(
+                    streams,
+                    &sub_point(&point, num_variables, alignment),
+                )
+            })
+            .collect();
+        assert_eq!(
+            reduced_claim,
+            batched_claim(&evaluations, &batch.coefficients)
+        );
+    }
+}
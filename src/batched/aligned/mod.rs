@@ -0,0 +1,3 @@
+mod aligned;
+
+pub use aligned::{aligned_claim, AlignedStream, Alignment};
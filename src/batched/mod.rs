@@ -0,0 +1,9 @@
+mod aligned;
+mod prover;
+mod sumcheck;
+
+pub use aligned::{aligned_claim, AlignedStream, Alignment};
+pub use prover::{
+    BatchedProductProver, BatchedProductProverConfig, BatchedProver, BatchedProverConfig,
+};
+pub use sumcheck::{batched_claim, batching_coefficients, BatchedProductSumcheck, BatchedSumcheck};
@@ -0,0 +1,30 @@
+use ark_ff::Field;
+use ark_std::{marker::PhantomData, vec::Vec};
+
+use crate::streams::Stream;
+
+/*
+ * One prover for the whole batch: every round polynomial is linear in the polynomial
+ * being summed, so the prover for sum_i c_i * f_i walks all the f_i together in one
+ * pass per round and accumulates every claim's sums weighted by its coefficient
+ */
+pub struct BatchedProver<F: Field, S: Stream<F>, P> {
+    pub claims: Vec<F>,
+    pub coefficients: Vec<F>,
+    pub prover: P,
+    pub _streams: PhantomData<S>,
+}
+
+/*
+ * The same for claims about products of two streams, which are terms of one virtual
+ * polynomial over the union of their streams, so a stream shared by many claims is
+ * read once per pass. The virtual provers send the round polynomial at 0, 1 and 2,
+ * the product verifier takes it at 0, 1 and 1/2
+ */
+pub struct BatchedProductProver<F: Field, S: Stream<F>, P> {
+    pub claims: Vec<F>,
+    pub coefficients: Vec<F>,
+    pub prover: P,
+    pub inverse_eight: F,
+    pub _streams: PhantomData<S>,
+}
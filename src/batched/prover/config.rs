@@ -0,0 +1,104 @@
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::{
+    error::SumcheckError,
+    streams::{check_streams, Stream},
+};
+
+// claim i is the sum of streams[i]
+pub struct BatchedProverConfig<F: Field, S: Stream<F>> {
+    pub claims: Vec<F>,
+    pub coefficients: Vec<F>,
+    pub num_variables: usize,
+    pub streams: Vec<S>,
+}
+
+impl<F: Field, S: Stream<F>> BatchedProverConfig<F, S> {
+    pub fn new(
+        claims: Vec<F>,
+        coefficients: Vec<F>,
+        num_variables: usize,
+        streams: Vec<S>,
+    ) -> Self {
+        Self::try_new(claims, coefficients, num_variables, streams).unwrap()
+    }
+    pub fn try_new(
+        claims: Vec<F>,
+        coefficients: Vec<F>,
+        num_variables: usize,
+        streams: Vec<S>,
+    ) -> Result<Self, SumcheckError> {
+        check_streams(&streams, num_variables)?;
+        // one claim and one coefficient per stream
+        for len in [claims.len(), coefficients.len()] {
+            if len != streams.len() {
+                return Err(SumcheckError::NumStreamsMismatch {
+                    expected: len,
+                    found: streams.len(),
+                });
+            }
+        }
+        Ok(Self {
+            claims,
+            coefficients,
+            num_variables,
+            streams,
+        })
+    }
+}
+
+// claim i is the sum of streams[products[i].0] * streams[products[i].1]
+pub struct BatchedProductProverConfig<F: Field, S: Stream<F>> {
+    pub claims: Vec<F>,
+    pub coefficients: Vec<F>,
+    pub num_variables: usize,
+    pub products: Vec<(usize, usize)>,
+    pub streams: Vec<S>,
+}
+
+impl<F: Field, S: Stream<F>> BatchedProductProverConfig<F, S> {
+    pub fn new(
+        claims: Vec<F>,
+        coefficients: Vec<F>,
+        num_variables: usize,
+        streams: Vec<S>,
+        products: Vec<(usize, usize)>,
+    ) -> Self {
+        Self::try_new(claims, coefficients, num_variables, streams, products).unwrap()
+    }
+    pub fn try_new(
+        claims: Vec<F>,
+        coefficients: Vec<F>,
+        num_variables: usize,
+        streams: Vec<S>,
+        products: Vec<(usize, usize)>,
+    ) -> Result<Self, SumcheckError> {
+        check_streams(&streams, num_variables)?;
+        if products.is_empty() {
+            return Err(SumcheckError::NoStreams);
+        }
+        // one claim and one coefficient per product, whose factors are among the streams
+        for len in [claims.len(), coefficients.len()] {
+            if len != products.len() {
+                return Err(SumcheckError::NumStreamsMismatch {
+                    expected: len,
+                    found: products.len(),
+                });
+            }
+        }
+        if let Some(product) = products
+            .iter()
+            .position(|(p, q)| *p >= streams.len() || *q >= streams.len())
+        {
+            return Err(SumcheckError::InvalidTerm(product));
+        }
+        Ok(Self {
+            claims,
+            coefficients,
+            num_variables,
+            products,
+            streams,
+        })
+    }
+}
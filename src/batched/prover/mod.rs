@@ -0,0 +1,6 @@
+mod batched;
+mod config;
+mod prover;
+
+pub use batched::{BatchedProductProver, BatchedProver};
+pub use config::{BatchedProductProverConfig, BatchedProverConfig};
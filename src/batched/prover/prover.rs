@@ -0,0 +1,96 @@
+use ark_ff::Field;
+use ark_std::{marker::PhantomData, vec::Vec};
+
+use crate::{
+    batched::{
+        batched_claim, BatchedProductProver, BatchedProductProverConfig, BatchedProver,
+        BatchedProverConfig,
+    },
+    prover::{Prover, ProverConfig, VirtualProverConfig},
+    streams::{LinearCombinationStream, Stream},
+    virtual_polynomial::VirtualPolynomial,
+};
+
+impl<F, S, P> Prover<F> for BatchedProver<F, S, P>
+where
+    F: Field,
+    S: Stream<F>,
+    P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F)>>,
+    P::ProverConfig: ProverConfig<F, LinearCombinationStream<F, S>>,
+{
+    type ProverConfig = BatchedProverConfig<F, S>;
+    type ProverMessage = Option<(F, F)>;
+    type VerifierMessage = Option<F>;
+
+    fn claim(&self) -> F {
+        batched_claim(&self.claims, &self.coefficients)
+    }
+
+    fn new(prover_config: Self::ProverConfig) -> Self {
+        // every read of the combination reads each stream once
+        let claim = batched_claim(&prover_config.claims, &prover_config.coefficients);
+        let stream =
+            LinearCombinationStream::new(prover_config.streams, prover_config.coefficients.clone());
+        Self {
+            claims: prover_config.claims,
+            coefficients: prover_config.coefficients,
+            prover: P::new(P::ProverConfig::default(
+                claim,
+                prover_config.num_variables,
+                stream,
+            )),
+            _streams: PhantomData,
+        }
+    }
+
+    fn next_message(&mut self, verifier_message: Option<F>) -> Option<(F, F)> {
+        self.prover.next_message(verifier_message)
+    }
+}
+
+impl<F, S, P> Prover<F> for BatchedProductProver<F, S, P>
+where
+    F: Field,
+    S: Stream<F>,
+    P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<Vec<F>>>,
+    P::ProverConfig: VirtualProverConfig<F, S>,
+{
+    type ProverConfig = BatchedProductProverConfig<F, S>;
+    type ProverMessage = Option<(F, F, F)>;
+    type VerifierMessage = Option<F>;
+
+    fn claim(&self) -> F {
+        batched_claim(&self.claims, &self.coefficients)
+    }
+
+    fn new(prover_config: Self::ProverConfig) -> Self {
+        // a term c_i * p * q for every claim
+        let claim = batched_claim(&prover_config.claims, &prover_config.coefficients);
+        let terms: Vec<(F, Vec<usize>)> = prover_config
+            .products
+            .iter()
+            .zip(prover_config.coefficients.iter())
+            .map(|((p, q), coefficient)| (*coefficient, vec![*p, *q]))
+            .collect();
+        let polynomial = VirtualPolynomial::new(prover_config.streams, terms);
+        Self {
+            claims: prover_config.claims,
+            coefficients: prover_config.coefficients,
+            prover: P::new(P::ProverConfig::default(
+                claim,
+                prover_config.num_variables,
+                polynomial,
+            )),
+            inverse_eight: F::from(8_u32).inverse().unwrap(),
+            _streams: PhantomData,
+        }
+    }
+
+    fn next_message(&mut self, verifier_message: Option<F>) -> Option<(F, F, F)> {
+        let evaluations = self.prover.next_message(verifier_message)?;
+        let (zero, one, two) = (evaluations[0], evaluations[1], evaluations[2]);
+        // s(1/2) = (3 * s(0) + 6 * s(1) - s(2)) / 8 for the quadratic through them
+        let half = (zero * F::from(3_u32) + one * F::from(6_u32) - two) * self.inverse_eight;
+        Some((zero, one, half))
+    }
+}
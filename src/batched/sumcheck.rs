@@ -0,0 +1,477 @@
+use ark_ff::Field;
+use ark_std::{rand::Rng, vec::Vec};
+
+use crate::{
+    batched::{
+        BatchedProductProver, BatchedProductProverConfig, BatchedProver, BatchedProverConfig,
+    },
+    prover::{Prover, ProverConfig, VirtualProverConfig},
+    streams::{LinearCombinationStream, Stream},
+    transcript::Transcript,
+    ProductSumcheck, Sumcheck,
+};
+
+/*
+ * Many claims over the same hypercube proven with one transcript: the claim of the
+ * batch is sum_i c_i * claim_i for random c_i, and the reduced claim at the end is
+ * sum_i c_i * f_i(r_1, ..., r_n). The prover P is any multilinear prover (Time, Space
+ * or Blendy) over the combination of the streams, or any virtual polynomial prover
+ * for products
+ */
+#[derive(Debug)]
+pub struct BatchedSumcheck<F: Field> {
+    pub claims: Vec<F>,
+    pub coefficients: Vec<F>,
+    pub sumcheck: Sumcheck<F>,
+}
+
+#[derive(Debug)]
+pub struct BatchedProductSumcheck<F: Field> {
+    pub claims: Vec<F>,
+    pub coefficients: Vec<F>,
+    pub sumcheck: ProductSumcheck<F>,
+}
+
+// the claim of the batch, which the verifier starts from
+pub fn batched_claim<F: Field>(claims: &[F], coefficients: &[F]) -> F {
+    assert_eq!(claims.len(), coefficients.len());
+    claims
+        .iter()
+        .zip(coefficients)
+        .map(|(claim, coefficient)| *claim * coefficient)
+        .sum()
+}
+
+// the coefficients depend on every claim, the verifier derives them the same way
+pub fn batching_coefficients<F: Field, T: Transcript<F>>(
+    transcript: &mut T,
+    claims: &[F],
+) -> Vec<F> {
+    transcript.absorb(claims);
+    claims.iter().map(|_| transcript.challenge()).collect()
+}
+
+impl<F: Field> BatchedSumcheck<F> {
+    // claim i is the sum of streams[i]
+    pub fn prove<S, P>(
+        claims: Vec<F>,
+        num_variables: usize,
+        streams: Vec<S>,
+        rng: &mut impl Rng,
+    ) -> Self
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F)>>,
+        P::ProverConfig: ProverConfig<F, LinearCombinationStream<F, S>>,
+    {
+        let coefficients: Vec<F> = claims.iter().map(|_| F::rand(rng)).collect();
+        Self::prove_with_coefficients::<S, P>(
+            claims,
+            coefficients,
+            num_variables,
+            streams,
+            |prover| Sumcheck::prove::<S, BatchedProver<F, S, P>>(prover, rng),
+        )
+    }
+
+    pub fn prove_non_interactive<S, P, T>(
+        claims: Vec<F>,
+        num_variables: usize,
+        streams: Vec<S>,
+        transcript: &mut T,
+    ) -> Self
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F)>>,
+        P::ProverConfig: ProverConfig<F, LinearCombinationStream<F, S>>,
+        T: Transcript<F>,
+    {
+        let coefficients = batching_coefficients(transcript, &claims);
+        Self::prove_with_coefficients::<S, P>(
+            claims,
+            coefficients,
+            num_variables,
+            streams,
+            |prover| {
+                Sumcheck::prove_non_interactive::<S, BatchedProver<F, S, P>, T>(prover, transcript)
+            },
+        )
+    }
+
+    fn prove_with_coefficients<S, P>(
+        claims: Vec<F>,
+        coefficients: Vec<F>,
+        num_variables: usize,
+        streams: Vec<S>,
+        prove: impl FnOnce(&mut BatchedProver<F, S, P>) -> Sumcheck<F>,
+    ) -> Self
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F)>>,
+        P::ProverConfig: ProverConfig<F, LinearCombinationStream<F, S>>,
+    {
+        let sumcheck = prove(&mut BatchedProver::new(BatchedProverConfig::new(
+            claims.clone(),
+            coefficients.clone(),
+            num_variables,
+            streams,
+        )));
+        Self {
+            claims,
+            coefficients,
+            sumcheck,
+        }
+    }
+}
+
+impl<F: Field> BatchedProductSumcheck<F> {
+    // claim i is the sum of streams[products[i].0] * streams[products[i].1]
+    pub fn prove<S, P>(
+        claims: Vec<F>,
+        num_variables: usize,
+        streams: Vec<S>,
+        products: Vec<(usize, usize)>,
+        rng: &mut impl Rng,
+    ) -> Self
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<Vec<F>>>,
+        P::ProverConfig: VirtualProverConfig<F, S>,
+    {
+        let coefficients: Vec<F> = claims.iter().map(|_| F::rand(rng)).collect();
+        Self::prove_with_coefficients::<S, P>(
+            claims,
+            coefficients,
+            num_variables,
+            streams,
+            products,
+            |prover| ProductSumcheck::prove::<S, BatchedProductProver<F, S, P>>(prover, rng),
+        )
+    }
+
+    pub fn prove_non_interactive<S, P, T>(
+        claims: Vec<F>,
+        num_variables: usize,
+        streams: Vec<S>,
+        products: Vec<(usize, usize)>,
+        transcript: &mut T,
+    ) -> Self
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<Vec<F>>>,
+        P::ProverConfig: VirtualProverConfig<F, S>,
+        T: Transcript<F>,
+    {
+        let coefficients = batching_coefficients(transcript, &claims);
+        Self::prove_with_coefficients::<S, P>(
+            claims,
+            coefficients,
+            num_variables,
+            streams,
+            products,
+            |prover| {
+                ProductSumcheck::prove_non_interactive::<S, BatchedProductProver<F, S, P>, T>(
+                    prover, transcript,
+                )
+            },
+        )
+    }
+
+    fn prove_with_coefficients<S, P>(
+        claims: Vec<F>,
+        coefficients: Vec<F>,
+        num_variables: usize,
+        streams: Vec<S>,
+        products: Vec<(usize, usize)>,
+        prove: impl FnOnce(&mut BatchedProductProver<F, S, P>) -> ProductSumcheck<F>,
+    ) -> Self
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<Vec<F>>>,
+        P::ProverConfig: VirtualProverConfig<F, S>,
+    {
+        let sumcheck = prove(&mut BatchedProductProver::new(
+            BatchedProductProverConfig::new(
+                claims.clone(),
+                coefficients.clone(),
+                num_variables,
+                streams,
+                products,
+            ),
+        ));
+        Self {
+            claims,
+            coefficients,
+            sumcheck,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+    use sha2::Sha256;
+
+    use super::{batched_claim, batching_coefficients, BatchedProductSumcheck, BatchedSumcheck};
+    use crate::{
+        batched::BatchedProductProverConfig,
+        error::SumcheckError,
+        multilinear::{
+            BlendyProver, SpaceProver, SumcheckVerifier, SumcheckVerifierConfig, TimeProver,
+        },
+        multilinear_product::{ProductSumcheckVerifier, ProductSumcheckVerifierConfig},
+        streams::{
+            multivariate_evaluation, multivariate_product_claim, multivariate_product_evaluation,
+            LinearCombinationStream, MemoryStream,
+        },
+        tests::{BenchStream, CountingStream, F64},
+        transcript::HashTranscript,
+        verifier::Verifier,
+        virtual_polynomial::{BlendyVirtualProver, SpaceVirtualProver, TimeVirtualProver},
+    };
+
+    fn random_streams(num_streams: usize, num_variables: usize) -> Vec<MemoryStream<F64>> {
+        let mut rng = ark_std::test_rng();
+        (0..num_streams)
+            .map(|_| {
+                MemoryStream::new(
+                    (0..1 << num_variables)
+                        .map(|_| F64::rand(&mut rng))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn batched_sumcheck() {
+        let num_variables = 10;
+        let streams: Vec<BenchStream<F64>> =
+            (0..3).map(|_| BenchStream::new(num_variables)).collect();
+        let claims: Vec<F64> = streams.iter().map(|stream| stream.claimed_sum).collect();
+        let batch = BatchedSumcheck::<F64>::prove::<
+            BenchStream<F64>,
+            BlendyProver<F64, LinearCombinationStream<F64, BenchStream<F64>>>,
+        >(
+            claims,
+            num_variables,
+            streams.clone(),
+            &mut ark_std::test_rng(),
+        );
+        assert!(batch.sumcheck.is_accepted);
+
+        // the verifier starts from the claims and coefficients
+        let (point, reduced_claim) = SumcheckVerifier::new(SumcheckVerifierConfig::new_batched(
+            &batch.claims,
+            &batch.coefficients,
+            num_variables,
+        ))
+        .verify(
+            &batch.sumcheck.prover_messages,
+            &batch.sumcheck.verifier_messages,
+        )
+        .unwrap();
+
+        // and ends at the same combination of the evaluations
+        let evaluations: Vec<F64> = streams
+            .into_iter()
+            .map(|stream| multivariate_evaluation(stream, &point))
+            .collect();
+        assert_eq!(
+            reduced_claim,
+            batched_claim(&evaluations, &batch.coefficients)
+        );
+    }
+
+    #[test]
+    fn batched_product_sumcheck_non_interactive() {
+        let num_variables = 6;
+        // four claims over five streams, some of them shared
+        let streams = random_streams(5, num_variables);
+        let products = vec![(0, 1), (1, 2), (2, 3), (0, 4)];
+        let claims: Vec<F64> = products
+            .iter()
+            .map(|(p, q)| {
+                multivariate_product_claim(vec![streams[*p].clone(), streams[*q].clone()])
+            })
+            .collect();
+        let batch = BatchedProductSumcheck::<F64>::prove_non_interactive::<
+            MemoryStream<F64>,
+            TimeVirtualProver<F64, MemoryStream<F64>>,
+            HashTranscript<F64, Sha256>,
+        >(
+            claims,
+            num_variables,
+            streams.clone(),
+            products.clone(),
+            &mut HashTranscript::new(b"batched"),
+        );
+        assert!(batch.sumcheck.is_accepted);
+
+        // the verifier rederives the coefficients, then the verifier messages
+        let mut transcript = HashTranscript::<F64, Sha256>::new(b"batched");
+        let coefficients = batching_coefficients(&mut transcript, &batch.claims);
+        assert_eq!(coefficients, batch.coefficients);
+        let (point, reduced_claim) = ProductSumcheckVerifier::new(
+            ProductSumcheckVerifierConfig::new_batched(&batch.claims, &coefficients, num_variables),
+        )
+        .verify_non_interactive(&batch.sumcheck.prover_messages, &mut transcript)
+        .unwrap();
+        assert_eq!(point, batch.sumcheck.verifier_messages);
+        let evaluations: Vec<F64> = products
+            .iter()
+            .map(|(p, q)| {
+                multivariate_product_evaluation(
+                    vec![streams[*p].clone(), streams[*q].clone()],
+                    &point,
+                )
+            })
+            .collect();
+        assert_eq!(reduced_claim, batched_claim(&evaluations, &coefficients));
+    }
+
+    #[test]
+    fn provers_agree() {
+        let num_variables = 8;
+        let streams = random_streams(3, num_variables);
+        let claims: Vec<F64> = streams
+            .iter()
+            .map(|stream| stream.evaluations.iter().sum())
+            .collect();
+        type Combination = LinearCombinationStream<F64, MemoryStream<F64>>;
+        let time = BatchedSumcheck::prove::<MemoryStream<F64>, TimeProver<F64, Combination>>(
+            claims.clone(),
+            num_variables,
+            streams.clone(),
+            &mut ark_std::test_rng(),
+        );
+        let space = BatchedSumcheck::prove::<MemoryStream<F64>, SpaceProver<F64, Combination>>(
+            claims,
+            num_variables,
+            streams.clone(),
+            &mut ark_std::test_rng(),
+        );
+        assert!(time.sumcheck.is_accepted);
+        assert_eq!(
+            time.sumcheck.prover_messages,
+            space.sumcheck.prover_messages
+        );
+
+        // a stream may be both factors of a product
+        let products = vec![(0, 1), (1, 2), (2, 2)];
+        let claims: Vec<F64> = products
+            .iter()
+            .map(|(p, q)| {
+                multivariate_product_claim(vec![streams[*p].clone(), streams[*q].clone()])
+            })
+            .collect();
+        let time = BatchedProductSumcheck::prove::<
+            MemoryStream<F64>,
+            TimeVirtualProver<F64, MemoryStream<F64>>,
+        >(
+            claims.clone(),
+            num_variables,
+            streams.clone(),
+            products.clone(),
+            &mut ark_std::test_rng(),
+        );
+        let space = BatchedProductSumcheck::prove::<
+            MemoryStream<F64>,
+            SpaceVirtualProver<F64, MemoryStream<F64>>,
+        >(
+            claims.clone(),
+            num_variables,
+            streams.clone(),
+            products.clone(),
+            &mut ark_std::test_rng(),
+        );
+        let blendy = BatchedProductSumcheck::prove::<
+            MemoryStream<F64>,
+            BlendyVirtualProver<F64, MemoryStream<F64>>,
+        >(
+            claims,
+            num_variables,
+            streams,
+            products,
+            &mut ark_std::test_rng(),
+        );
+        assert!(time.sumcheck.is_accepted);
+        assert_eq!(time.sumcheck, space.sumcheck);
+        assert_eq!(time.sumcheck, blendy.sumcheck);
+    }
+
+    #[test]
+    fn one_pass_per_round() {
+        let num_variables = 6;
+        let streams: Vec<CountingStream<F64>> = random_streams(3, num_variables)
+            .into_iter()
+            .map(CountingStream::from)
+            .collect();
+        let num_evaluations = 1 << num_variables;
+
+        // the space prover reads the whole combination once per round, so every stream once per round
+        let claims: Vec<F64> = streams
+            .iter()
+            .map(|stream| stream.stream.evaluations.iter().sum())
+            .collect();
+        let batch = BatchedSumcheck::<F64>::prove::<
+            CountingStream<F64>,
+            SpaceProver<F64, LinearCombinationStream<F64, CountingStream<F64>>>,
+        >(
+            claims,
+            num_variables,
+            streams.clone(),
+            &mut ark_std::test_rng(),
+        );
+        assert!(batch.sumcheck.is_accepted);
+        for stream in streams.iter() {
+            assert_eq!(stream.reads(), num_variables * num_evaluations);
+        }
+
+        // and each stream is read once per round however many products share it
+        let streams: Vec<CountingStream<F64>> = random_streams(3, num_variables)
+            .into_iter()
+            .map(CountingStream::from)
+            .collect();
+        let products = vec![(0, 1), (0, 2), (1, 2), (0, 0)];
+        let claims: Vec<F64> = products
+            .iter()
+            .map(|(p, q)| {
+                multivariate_product_claim(vec![streams[*p].clone(), streams[*q].clone()])
+            })
+            .collect();
+        let reads_before: Vec<usize> = streams.iter().map(|stream| stream.reads()).collect();
+        let batch = BatchedProductSumcheck::<F64>::prove::<
+            CountingStream<F64>,
+            SpaceVirtualProver<F64, CountingStream<F64>>,
+        >(
+            claims,
+            num_variables,
+            streams.clone(),
+            products,
+            &mut ark_std::test_rng(),
+        );
+        assert!(batch.sumcheck.is_accepted);
+        for (stream, reads_before) in streams.iter().zip(reads_before) {
+            assert_eq!(
+                stream.reads() - reads_before,
+                num_variables * num_evaluations
+            );
+        }
+    }
+
+    #[test]
+    fn no_products() {
+        // there's nothing to batch, rather than a panic building the polynomial
+        assert!(matches!(
+            BatchedProductProverConfig::<F64, MemoryStream<F64>>::try_new(
+                vec![],
+                vec![],
+                4,
+                random_streams(2, 4),
+                vec![],
+            ),
+            Err(SumcheckError::NoStreams)
+        ));
+    }
+}
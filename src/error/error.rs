@@ -0,0 +1,141 @@
+use ark_serialize::SerializationError;
+use ark_std::{fmt, io};
+
+/*
+ * Everything that can go wrong with a sumcheck: a malformed input or a configuration
+ * that doesn't match its streams, found before a prover starts, or a transcript the
+ * verifier rejects while it runs. Once a prover is built from a checked
+ * configuration it doesn't fail itself
+ */
+#[derive(Debug)]
+pub enum SumcheckError {
+    Io(io::Error),
+    // a checkpoint that can't be read back
+    Serialization(SerializationError),
+    // the number of evaluations isn't a power of two
+    NotPowerOfTwo(usize),
+    // the file length isn't a whole number of serialized field elements
+    TruncatedFile {
+        len: usize,
+        element_size: usize,
+    },
+    NoStreams,
+    NumStreamsMismatch {
+        expected: usize,
+        found: usize,
+    },
+    NumVariablesMismatch {
+        expected: usize,
+        found: usize,
+    },
+    InvalidNumStages {
+        num_stages: usize,
+        num_variables: usize,
+    },
+    InvalidTerm(usize),
+    // the nodes 0, 1, ..., d of a round polynomial of this degree aren't distinct in the field
+    DegreeTooLarge(usize),
+    // this entry of a sparse stream is out of order, repeated, or outside the hypercube
+    InvalidSparseEntry(usize),
+    // a reordering of variables that isn't a permutation of all of them
+    InvalidPermutation(Vec<usize>),
+    // a columnar file whose header doesn't match what it's opened as, this names the field
+    HeaderMismatch(&'static str),
+    // a columnar file can't be written in chunks of no rows
+    InvalidChunkSize,
+    // the data of this chunk of a columnar file isn't what was written
+    ChecksumMismatch(usize),
+    // this element of a file isn't canonical, or in Montgomery form isn't reduced modulo the field
+    InvalidElement(usize),
+    // the verifier rejected the prover message of this round
+    Rejected(usize),
+    // the nodes of a round polynomial of this degree don't include both 0 and 1, so p(1) can't be dropped
+    CannotCompress(usize),
+    // the nodes of a round polynomial of this degree repeat a point, so it can't be interpolated
+    InvalidNodes(usize),
+}
+
+impl fmt::Display for SumcheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "io error: {}", error),
+            Self::Serialization(error) => write!(f, "serialization error: {}", error),
+            Self::NotPowerOfTwo(len) => {
+                write!(f, "number of evaluations {} is not a power of two", len)
+            }
+            Self::TruncatedFile { len, element_size } => write!(
+                f,
+                "file of {} bytes is not a whole number of {} byte elements",
+                len, element_size
+            ),
+            Self::NoStreams => write!(f, "at least one stream is needed"),
+            Self::NumStreamsMismatch { expected, found } => {
+                write!(f, "expected {} streams, found {}", expected, found)
+            }
+            Self::NumVariablesMismatch { expected, found } => {
+                write!(f, "expected {} variables, found {}", expected, found)
+            }
+            Self::InvalidNumStages {
+                num_stages,
+                num_variables,
+            } => write!(
+                f,
+                "{} stages is not a valid schedule for {} variables",
+                num_stages, num_variables
+            ),
+            Self::InvalidTerm(term) => {
+                write!(f, "term {} is empty or refers to a missing stream", term)
+            }
+            Self::DegreeTooLarge(degree) => write!(
+                f,
+                "degree {} is too large for the characteristic of the field",
+                degree
+            ),
+            Self::InvalidPermutation(permutation) => {
+                write!(f, "{:?} is not a permutation of the variables", permutation)
+            }
+            Self::InvalidSparseEntry(position) => write!(
+                f,
+                "sparse entry {} is out of order or outside the hypercube",
+                position
+            ),
+            Self::HeaderMismatch(field) => write!(f, "file header mismatch in {}", field),
+            Self::ChecksumMismatch(chunk) => write!(f, "checksum mismatch in chunk {}", chunk),
+            Self::InvalidChunkSize => write!(f, "chunks of a columnar file can't be empty"),
+            Self::InvalidElement(point) => write!(f, "element {} is not reduced", point),
+            Self::Rejected(round) => write!(f, "rejected in round {}", round),
+            Self::CannotCompress(degree) => write!(
+                f,
+                "the nodes of a degree {} round polynomial don't include 0 and 1",
+                degree
+            ),
+            Self::InvalidNodes(degree) => write!(
+                f,
+                "the nodes of a degree {} round polynomial aren't distinct",
+                degree
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SumcheckError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Serialization(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SumcheckError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<SerializationError> for SumcheckError {
+    fn from(error: SerializationError) -> Self {
+        Self::Serialization(error)
+    }
+}
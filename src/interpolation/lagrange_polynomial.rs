@@ -1,10 +1,26 @@
 use crate::{
+    error::SumcheckError,
     hypercube::{Hypercube, HypercubeMember},
-    messages::VerifierMessages,
+    messages::{Nodes, VerifierMessages},
     order_strategy::{GraycodeOrder, OrderStrategy, SignificantBitOrder},
 };
 use ark_ff::Field;
 
+/*
+ * A round polynomial of degree d is sent as its evaluations at 0, 1, ..., d, which are
+ * only distinct nodes to interpolate from when d is below the characteristic of the field
+ */
+pub fn check_degree<F: Field>(degree: usize) -> Result<(), SumcheckError> {
+    let characteristic = F::characteristic();
+    let is_small = characteristic.iter().skip(1).all(|limb| *limb == 0);
+    match characteristic.first() {
+        Some(low) if is_small && *low != 0 && degree as u64 >= *low => {
+            Err(SumcheckError::DegreeTooLarge(degree))
+        }
+        _ => Ok(()),
+    }
+}
+
 #[derive(Debug)]
 pub struct LagrangePolynomial<'a, F: Field, O: OrderStrategy> {
     order: O,
@@ -28,7 +44,7 @@ impl<'a, F: Field, O: OrderStrategy> LagrangePolynomial<'a, F, O> {
             stop_position: Hypercube::<O>::stop_value(num_vars),
         }
     }
-    pub fn lag_poly(x: Vec<F>, x_hat: Vec<F>, b: HypercubeMember) -> F {
+    pub fn lag_poly(x: &[F], x_hat: &[F], b: HypercubeMember) -> F {
         // Iterate over the zipped triple x, x_hat, and boolean hypercube vectors
         x.iter().zip(x_hat.iter()).zip(b).fold(
             // Initial the accumulation to F::ONE
@@ -43,24 +59,39 @@ impl<'a, F: Field, O: OrderStrategy> LagrangePolynomial<'a, F, O> {
             },
         )
     }
+    // the product messages are at the nodes of Nodes::half()
     pub fn evaluate_from_three_points(verifier_message: F, prover_message: (F, F, F)) -> F {
-        // Hardcoded x-values:
-        let zero = F::zero();
-        let one = F::one();
-        let half = F::from(2_u32).inverse().unwrap();
-
-        // Compute denominators for the Lagrange basis polynomials
-        let inv_denom_0 = ((zero - one) * (zero - half)).inverse().unwrap();
-        let inv_denom_1 = ((one - zero) * (one - half)).inverse().unwrap();
-        let inv_denom_2 = ((half - zero) * (half - one)).inverse().unwrap();
-
-        // Compute the Lagrange basis polynomials evaluated at x
-        let basis_p_0 = (verifier_message - one) * (verifier_message - half) * inv_denom_0;
-        let basis_p_1 = (verifier_message - zero) * (verifier_message - half) * inv_denom_1;
-        let basis_p_2 = (verifier_message - zero) * (verifier_message - one) * inv_denom_2;
-
-        // Return the evaluation of the unique quadratic polynomial
-        prover_message.0 * basis_p_0 + prover_message.1 * basis_p_1 + prover_message.2 * basis_p_2
+        Self::evaluate_from_nodes(
+            verifier_message,
+            &Nodes::half().points(3),
+            &[prover_message.0, prover_message.1, prover_message.2],
+        )
+    }
+    // the prover message should be of a degree check_degree accepts, or this panics
+    pub fn evaluate_from_points(verifier_message: F, prover_message: &[F]) -> F {
+        // x-values are 0, 1, ..., d for a polynomial of degree d
+        Self::evaluate_from_nodes(
+            verifier_message,
+            &Nodes::Consecutive.points(prover_message.len()),
+            prover_message,
+        )
+    }
+    // the polynomial through the evaluations at distinct nodes, evaluated at the verifier message
+    pub fn evaluate_from_nodes(verifier_message: F, nodes: &[F], evaluations: &[F]) -> F {
+        // Sum up the evaluations weighted by the Lagrange basis polynomials evaluated at x
+        let mut evaluation = F::zero();
+        for (i, (node_i, evaluation_i)) in nodes.iter().zip(evaluations).enumerate() {
+            let mut numerator = F::one();
+            let mut denominator = F::one();
+            for (j, node_j) in nodes.iter().enumerate() {
+                if i != j {
+                    numerator *= verifier_message - node_j;
+                    denominator *= *node_i - node_j;
+                }
+            }
+            evaluation += *evaluation_i * numerator * denominator.inverse().unwrap();
+        }
+        evaluation
     }
 }
 
@@ -172,8 +203,12 @@ impl<'a, F: Field> Iterator for LagrangePolynomial<'a, F, SignificantBitOrder> {
 #[cfg(test)]
 mod tests {
     use crate::{
-        hypercube::HypercubeMember, interpolation::LagrangePolynomial, messages::VerifierMessages,
-        order_strategy::GraycodeOrder, tests::F19,
+        error::SumcheckError,
+        hypercube::HypercubeMember,
+        interpolation::{check_degree, LagrangePolynomial},
+        messages::VerifierMessages,
+        order_strategy::GraycodeOrder,
+        tests::{F19, F64},
     };
 
     #[test]
@@ -189,8 +224,8 @@ mod tests {
         let mut lag_poly: LagrangePolynomial<F19, GraycodeOrder> = LagrangePolynomial::new(&vm);
         for gray_code_index in [0, 1, 3, 2, 6, 7, 5, 4] {
             let exp = LagrangePolynomial::<F19, GraycodeOrder>::lag_poly(
-                messages.clone(),
-                message_hats.clone(),
+                &messages,
+                &message_hats,
                 HypercubeMember::new(3, gray_code_index),
             );
             assert_eq!(lag_poly.next().unwrap(), exp);
@@ -198,6 +233,28 @@ mod tests {
         assert_eq!(lag_poly.next(), None);
     }
     #[test]
+    fn evaluate_from_points() {
+        // p(x) = 3x^3 + 2x + 5
+        let p = |x: F19| F19::from(3) * x * x * x + F19::from(2) * x + F19::from(5);
+        let evaluations: Vec<F19> = (0..4).map(|i| p(F19::from(i))).collect();
+        for x in [F19::from(7), F19::from(11), F19::from(18)] {
+            assert_eq!(
+                LagrangePolynomial::<F19, GraycodeOrder>::evaluate_from_points(x, &evaluations),
+                p(x)
+            );
+        }
+    }
+    #[test]
+    fn degree_below_characteristic() {
+        // 0, 1, ..., 18 are the 19 distinct nodes of F19
+        assert!(check_degree::<F19>(18).is_ok());
+        assert!(matches!(
+            check_degree::<F19>(19),
+            Err(SumcheckError::DegreeTooLarge(19))
+        ));
+        assert!(check_degree::<F64>(1 << 20).is_ok());
+    }
+    #[test]
     fn boolean_next() {
         // remember this is gray code ordering!
         let messages: Vec<F19> = vec![F19::from(0), F19::from(1), F19::from(1)];
@@ -210,8 +267,8 @@ mod tests {
         let mut lag_poly: LagrangePolynomial<F19, GraycodeOrder> = LagrangePolynomial::new(&vm);
         for gray_code_index in [0, 1, 3, 2, 6, 7, 5, 4] {
             let exp = LagrangePolynomial::<F19, GraycodeOrder>::lag_poly(
-                messages.clone(),
-                message_hats.clone(),
+                &messages,
+                &message_hats,
                 HypercubeMember::new(3, gray_code_index),
             );
             assert_eq!(lag_poly.next().unwrap(), exp);
@@ -1,2 +1,2 @@
 mod lagrange_polynomial;
-pub use lagrange_polynomial::LagrangePolynomial;
+pub use lagrange_polynomial::{check_degree, LagrangePolynomial};
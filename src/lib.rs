@@ -1,14 +1,25 @@
 #[doc(hidden)]
 pub mod tests;
 
+pub mod batched;
+pub mod error;
 pub mod hypercube;
 pub mod interpolation;
 pub mod messages;
+pub mod multi_product;
 pub mod multilinear;
 pub mod multilinear_product;
 pub mod order_strategy;
+mod parallel;
 pub mod prover;
 pub mod streams;
+pub mod transcript;
+pub mod univariate_skip;
+pub mod verifier;
+pub mod virtual_polynomial;
+pub mod zerocheck;
 
+pub use crate::error::SumcheckError;
+pub use crate::multi_product::MultiProductSumcheck;
 pub use crate::multilinear::Sumcheck;
 pub use crate::multilinear_product::ProductSumcheck;
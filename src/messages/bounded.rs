@@ -0,0 +1,28 @@
+use ark_serialize::{CanonicalDeserialize, Compress, SerializationError, Validate};
+use ark_std::{io::Read, vec::Vec};
+
+// a proof has a round per variable, and a hypercube indexed by usize has at most this many
+pub const MAX_ROUNDS: usize = usize::BITS as usize;
+
+/*
+ * Reads a Vec as ark-serialize writes it, a u64 length and then the elements, but
+ * without trusting the length: anything over max_len is refused, and the elements
+ * are read one at a time so a length past the end of the reader fails before it's
+ * allocated
+ */
+pub(crate) fn deserialize_bounded_vec<T: CanonicalDeserialize, R: Read>(
+    mut reader: R,
+    compress: Compress,
+    validate: Validate,
+    max_len: usize,
+) -> Result<Vec<T>, SerializationError> {
+    let len = u64::deserialize_with_mode(&mut reader, compress, validate)?;
+    if len > max_len as u64 {
+        return Err(SerializationError::InvalidData);
+    }
+    let mut elements = Vec::with_capacity((len as usize).min(MAX_ROUNDS));
+    for _ in 0..len {
+        elements.push(T::deserialize_with_mode(&mut reader, compress, validate)?);
+    }
+    Ok(elements)
+}
@@ -0,0 +1,326 @@
+use ark_ff::Field;
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
+use ark_std::{
+    io::{Read, Write},
+    vec::Vec,
+};
+
+use crate::{
+    error::SumcheckError,
+    messages::{deserialize_bounded_vec, Nodes, RoundPolynomial, MAX_ROUNDS},
+};
+
+// bump this whenever the encoding below changes
+pub const COMPRESSED_PROOF_VERSION: u8 = 1;
+
+/*
+ * The round polynomials of any of the sumchecks, each as its evaluations at the
+ * nodes without p(1), which Verifier::verify_compressed rederives from the running
+ * claim. The nodes are part of the proof, so it can be read by an implementation
+ * that evaluates elsewhere
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompressedProof<F: Field> {
+    pub nodes: Nodes<F>,
+    pub messages: Vec<Vec<F>>,
+}
+
+impl<F: Field> CompressedProof<F> {
+    pub fn new(
+        round_polynomials: &[RoundPolynomial<F>],
+        nodes: Nodes<F>,
+    ) -> Result<Self, SumcheckError> {
+        let messages = round_polynomials
+            .iter()
+            .map(|round_polynomial| round_polynomial.compress(&nodes))
+            .collect::<Result<Vec<Vec<F>>, SumcheckError>>()?;
+        Ok(Self { nodes, messages })
+    }
+}
+
+impl<F: Field> CanonicalSerialize for CompressedProof<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        COMPRESSED_PROOF_VERSION.serialize_with_mode(&mut writer, compress)?;
+        match &self.nodes {
+            Nodes::Consecutive => 0_u8.serialize_with_mode(&mut writer, compress)?,
+            Nodes::Infinity => 1_u8.serialize_with_mode(&mut writer, compress)?,
+            Nodes::Points(points) => {
+                2_u8.serialize_with_mode(&mut writer, compress)?;
+                points.serialize_with_mode(&mut writer, compress)?;
+            }
+        }
+        self.messages.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        let points_size = match &self.nodes {
+            Nodes::Points(points) => points.serialized_size(compress),
+            _ => 0,
+        };
+        COMPRESSED_PROOF_VERSION.serialized_size(compress)
+            + 0_u8.serialized_size(compress)
+            + points_size
+            + self.messages.serialized_size(compress)
+    }
+}
+
+impl<F: Field> Valid for CompressedProof<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if let Nodes::Points(points) = &self.nodes {
+            points.check()?;
+        }
+        self.messages.check()
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for CompressedProof<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        // refuse anything written in an encoding we don't know
+        let version = u8::deserialize_with_mode(&mut reader, compress, validate)?;
+        if version != COMPRESSED_PROOF_VERSION {
+            return Err(SerializationError::InvalidData);
+        }
+        let nodes = match u8::deserialize_with_mode(&mut reader, compress, validate)? {
+            0 => Nodes::Consecutive,
+            1 => Nodes::Infinity,
+            2 => {
+                let points = deserialize_bounded_vec(&mut reader, compress, validate, usize::MAX)?;
+                if !Nodes::distinct(&points) {
+                    return Err(SerializationError::InvalidData);
+                }
+                Nodes::Points(points)
+            }
+            _ => return Err(SerializationError::InvalidData),
+        };
+        // a round per variable, each with however many evaluations the nodes have
+        let num_rounds = u64::deserialize_with_mode(&mut reader, compress, validate)?;
+        if num_rounds > MAX_ROUNDS as u64 {
+            return Err(SerializationError::InvalidData);
+        }
+        let messages = (0..num_rounds)
+            .map(|_| deserialize_bounded_vec(&mut reader, compress, validate, usize::MAX))
+            .collect::<Result<Vec<Vec<F>>, SerializationError>>()?;
+        Ok(Self { nodes, messages })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{AdditiveGroup, Field, UniformRand};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use sha2::Sha256;
+
+    use crate::{
+        error::SumcheckError,
+        messages::{CompressedProof, Nodes, RoundPolynomial},
+        multi_product::{
+            MultiProductSumcheckVerifier, MultiProductSumcheckVerifierConfig,
+            TimeMultiProductProver, TimeMultiProductProverConfig,
+        },
+        multilinear::{SumcheckVerifier, SumcheckVerifierConfig, TimeProver, TimeProverConfig},
+        multilinear_product::{
+            ProductSumcheckVerifier, ProductSumcheckVerifierConfig, TimeProductProver,
+            TimeProductProverConfig,
+        },
+        prover::Prover,
+        streams::{multivariate_product_claim, MemoryStream},
+        tests::{BenchStream, F64},
+        transcript::HashTranscript,
+        verifier::Verifier,
+        MultiProductSumcheck, ProductSumcheck, Sumcheck,
+    };
+
+    fn random_streams(num_streams: usize, num_variables: usize) -> Vec<MemoryStream<F64>> {
+        let mut rng = ark_std::test_rng();
+        (0..num_streams)
+            .map(|_| {
+                MemoryStream::new(
+                    (0..1 << num_variables)
+                        .map(|_| F64::rand(&mut rng))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn product_nodes() {
+        let num_variables = 6;
+        let streams = random_streams(2, num_variables);
+        let claim = multivariate_product_claim(streams.clone());
+        let transcript = ProductSumcheck::<F64>::prove::<
+            MemoryStream<F64>,
+            TimeProductProver<F64, MemoryStream<F64>>,
+        >(
+            &mut TimeProductProver::new(TimeProductProverConfig::new(
+                claim,
+                num_variables,
+                streams,
+            )),
+            &mut ark_std::test_rng(),
+        );
+        let verifier = || {
+            ProductSumcheckVerifier::new(ProductSumcheckVerifierConfig::new(claim, num_variables))
+        };
+        let expected = verifier()
+            .verify(&transcript.prover_messages, &transcript.verifier_messages)
+            .unwrap();
+
+        let two = F64::from(2_u32);
+        for nodes in [
+            Nodes::Consecutive,
+            Nodes::Infinity,
+            Nodes::half(),
+            Nodes::Points(vec![two, F64::ONE, F64::ZERO]),
+        ] {
+            // two evaluations per round, and the nodes survive serialization
+            let proof = transcript.compressed_proof(nodes).unwrap();
+            assert!(proof.messages.iter().all(|message| message.len() == 2));
+            let mut bytes = Vec::new();
+            proof.serialize_compressed(&mut bytes).unwrap();
+            assert_eq!(bytes.len(), proof.compressed_size());
+            let proof = CompressedProof::<F64>::deserialize_compressed(&bytes[..]).unwrap();
+            assert_eq!(
+                verifier().verify_compressed(&proof, &transcript.verifier_messages),
+                Some(expected.clone())
+            );
+
+            // a changed message no longer reduces to the same claim
+            let mut tampered = proof.clone();
+            tampered.messages[2][0] += F64::ONE;
+            assert_ne!(
+                verifier().verify_compressed(&tampered, &transcript.verifier_messages),
+                Some(expected.clone())
+            );
+        }
+
+        // the multilinear verifier doesn't take quadratic round polynomials
+        let proof = transcript.compressed_proof(Nodes::Consecutive).unwrap();
+        assert!(
+            SumcheckVerifier::new(SumcheckVerifierConfig::new(claim, num_variables))
+                .verify_compressed(&proof, &transcript.verifier_messages)
+                .is_none()
+        );
+
+        // repeated nodes can't be interpolated, so they're neither read back nor decompressed
+        let repeated = CompressedProof {
+            nodes: Nodes::Points(vec![F64::ZERO, F64::ONE, F64::ONE]),
+            messages: proof.messages.clone(),
+        };
+        assert!(verifier()
+            .verify_compressed(&repeated, &transcript.verifier_messages)
+            .is_none());
+        assert!(matches!(
+            RoundPolynomial::decompress(&repeated.messages[0], &repeated.nodes, claim),
+            Err(SumcheckError::InvalidNodes(2))
+        ));
+        let mut bytes = Vec::new();
+        repeated.serialize_compressed(&mut bytes).unwrap();
+        assert!(CompressedProof::<F64>::deserialize_compressed(&bytes[..]).is_err());
+
+        // nodes and messages for a higher degree than the verifier's
+        let mut cubic = transcript
+            .compressed_proof(Nodes::Points(vec![two, F64::ONE, F64::ZERO]))
+            .unwrap();
+        cubic.nodes = Nodes::Points(vec![two, F64::ONE, F64::ZERO, two + two]);
+        cubic
+            .messages
+            .iter_mut()
+            .for_each(|message| message.push(F64::ZERO));
+        assert!(verifier()
+            .verify_compressed(&cubic, &transcript.verifier_messages)
+            .is_none());
+
+        // lengths from the bytes are bounded before anything is allocated
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        let mut too_many_rounds = bytes.clone();
+        too_many_rounds[2..10].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(CompressedProof::<F64>::deserialize_compressed(&too_many_rounds[..]).is_err());
+        let mut too_many_evaluations = bytes.clone();
+        too_many_evaluations[10..18].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(CompressedProof::<F64>::deserialize_compressed(&too_many_evaluations[..]).is_err());
+
+        // an unknown version or node set
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        bytes[1] = 3;
+        assert!(CompressedProof::<F64>::deserialize_compressed(&bytes[..]).is_err());
+        bytes[0] += 1;
+        assert!(CompressedProof::<F64>::deserialize_compressed(&bytes[..]).is_err());
+    }
+
+    #[test]
+    fn multilinear_nodes() {
+        let s: BenchStream<F64> = BenchStream::new(8);
+        let claim = s.claimed_sum;
+        let transcript = Sumcheck::<F64>::prove_non_interactive::<
+            BenchStream<F64>,
+            TimeProver<F64, BenchStream<F64>>,
+            HashTranscript<F64, Sha256>,
+        >(
+            &mut TimeProver::new(TimeProverConfig::new(claim, 8, s)),
+            &mut HashTranscript::new(b"compressed"),
+        );
+        // one evaluation per round at 0, 1
+        let proof = transcript.compressed_proof(Nodes::Consecutive).unwrap();
+        assert!(proof.messages.iter().all(|message| message.len() == 1));
+        let (point, _) = SumcheckVerifier::new(SumcheckVerifierConfig::new(claim, 8))
+            .verify_compressed_non_interactive(
+                &proof,
+                &mut HashTranscript::<F64, Sha256>::new(b"compressed"),
+            )
+            .unwrap();
+        assert_eq!(point, transcript.verifier_messages);
+        // but 0, ∞ has no p(1) to drop
+        assert!(matches!(
+            transcript.compressed_proof(Nodes::Infinity),
+            Err(SumcheckError::CannotCompress(1))
+        ));
+    }
+
+    #[test]
+    fn multi_product_nodes() {
+        let num_variables = 5;
+        let streams = random_streams(3, num_variables);
+        let claim = multivariate_product_claim(streams.clone());
+        let transcript = MultiProductSumcheck::<F64>::prove::<
+            MemoryStream<F64>,
+            TimeMultiProductProver<F64, MemoryStream<F64>>,
+        >(
+            &mut TimeMultiProductProver::new(TimeMultiProductProverConfig::new(
+                claim,
+                num_variables,
+                streams,
+            )),
+            &mut ark_std::test_rng(),
+        );
+        let verifier = || {
+            MultiProductSumcheckVerifier::new(MultiProductSumcheckVerifierConfig::new(
+                claim,
+                3,
+                num_variables,
+            ))
+        };
+        let expected = verifier()
+            .verify(&transcript.prover_messages, &transcript.verifier_messages)
+            .unwrap();
+        for nodes in [Nodes::Consecutive, Nodes::Infinity] {
+            let proof = transcript.compressed_proof(nodes).unwrap();
+            assert_eq!(
+                verifier().verify_compressed(&proof, &transcript.verifier_messages),
+                Some(expected.clone())
+            );
+        }
+    }
+}
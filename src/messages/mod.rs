@@ -1,2 +1,9 @@
+mod bounded;
+mod compressed_proof;
+mod round_polynomial;
 mod verifier_messages;
+pub(crate) use bounded::deserialize_bounded_vec;
+pub use bounded::MAX_ROUNDS;
+pub use compressed_proof::{CompressedProof, COMPRESSED_PROOF_VERSION};
+pub use round_polynomial::{Nodes, RoundPolynomial};
 pub use verifier_messages::VerifierMessages;
@@ -0,0 +1,353 @@
+use ark_ff::Field;
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
+use ark_std::{
+    io::{Read, Write},
+    vec::Vec,
+};
+
+use crate::{error::SumcheckError, messages::deserialize_bounded_vec};
+
+/*
+ * Where a round polynomial of degree d is evaluated, d + 1 nodes in all:
+ *   Consecutive: 0, 1, 2, ..., d
+ *   Infinity: 0, 1, ..., d - 1 and ∞, where p(∞) is the leading coefficient
+ *   Points: any distinct points, e.g. 0, 1 and 1/2 for the product provers
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub enum Nodes<F: Field> {
+    Consecutive,
+    Infinity,
+    Points(Vec<F>),
+}
+
+impl<F: Field> Nodes<F> {
+    // the nodes of the (F, F, F) messages of the product provers
+    pub fn half() -> Self {
+        Self::Points(vec![F::ZERO, F::ONE, F::from(2_u32).inverse().unwrap()])
+    }
+    // the finite nodes, ∞ isn't one of them so Infinity needs degree >= 2 to include 1
+    pub(crate) fn points(&self, num_nodes: usize) -> Vec<F> {
+        match self {
+            Self::Consecutive => (0..num_nodes).map(|i| F::from(i as u64)).collect(),
+            Self::Infinity => (0..num_nodes - 1).map(|i| F::from(i as u64)).collect(),
+            Self::Points(points) => {
+                assert_eq!(points.len(), num_nodes);
+                points.clone()
+            }
+        }
+    }
+    /*
+     * Where 0 or 1 is among the nodes, which compression needs: Consecutive has 1 from
+     * degree 1, Infinity from degree 2 and Points only if it's given
+     */
+    fn position(&self, node: F, num_nodes: usize) -> Result<usize, SumcheckError> {
+        let degree = num_nodes.saturating_sub(1);
+        let num_points = match self {
+            Self::Infinity => num_nodes.saturating_sub(1),
+            _ => num_nodes,
+        };
+        if num_nodes == 0 || matches!(self, Self::Points(points) if points.len() != num_points) {
+            return Err(SumcheckError::CannotCompress(degree));
+        }
+        let points = self.points(num_nodes);
+        if !Self::distinct(&points) {
+            return Err(SumcheckError::InvalidNodes(degree));
+        }
+        points
+            .iter()
+            .position(|point| *point == node)
+            .ok_or(SumcheckError::CannotCompress(degree))
+    }
+    // interpolation divides by the differences of the points, so none can repeat
+    pub(crate) fn distinct(points: &[F]) -> bool {
+        points
+            .iter()
+            .enumerate()
+            .all(|(i, point)| !points[..i].contains(point))
+    }
+}
+
+// a univariate polynomial in coefficient form, lowest degree first
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoundPolynomial<F: Field> {
+    pub coefficients: Vec<F>,
+}
+
+impl<F: Field> RoundPolynomial<F> {
+    pub fn new(coefficients: Vec<F>) -> Self {
+        assert!(!coefficients.is_empty());
+        Self { coefficients }
+    }
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+    pub fn evaluate(&self, point: F) -> F {
+        // Horner
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(F::ZERO, |evaluation, coefficient| {
+                evaluation * point + coefficient
+            })
+    }
+    // the sum over the boolean hypercube of this round, p(0) + p(1)
+    pub fn sum_over_boolean(&self) -> F {
+        self.evaluate(F::ZERO) + self.evaluate(F::ONE)
+    }
+    pub fn from_evaluations(evaluations: &[F], nodes: &Nodes<F>) -> Self {
+        let num_nodes = evaluations.len();
+        let points = nodes.points(num_nodes);
+        match nodes {
+            // interpolate q(X) = p(X) - p(∞) * X(X - 1)...(X - d + 1) of degree d - 1 on the finite nodes
+            Nodes::Infinity => {
+                let leading = evaluations[num_nodes - 1];
+                let vanishing = Self::from_roots(&points);
+                let shifted: Vec<F> = points
+                    .iter()
+                    .zip(evaluations)
+                    .map(|(point, evaluation)| *evaluation - leading * vanishing.evaluate(*point))
+                    .collect();
+                let mut coefficients = Self::interpolate(&points, &shifted);
+                coefficients.resize(num_nodes, F::ZERO);
+                for (coefficient, vanishing_coefficient) in
+                    coefficients.iter_mut().zip(vanishing.coefficients.iter())
+                {
+                    *coefficient += leading * vanishing_coefficient;
+                }
+                Self::new(coefficients)
+            }
+            _ => Self::new(Self::interpolate(&points, evaluations)),
+        }
+    }
+    pub fn evaluations(&self, nodes: &Nodes<F>) -> Vec<F> {
+        let num_nodes = self.coefficients.len();
+        let mut evaluations: Vec<F> = nodes
+            .points(num_nodes)
+            .into_iter()
+            .map(|point| self.evaluate(point))
+            .collect();
+        if *nodes == Nodes::Infinity {
+            evaluations.push(self.coefficients[num_nodes - 1]);
+        }
+        evaluations
+    }
+    /*
+     * The standard compression: the evaluations without p(1), which the verifier
+     * derives from the running claim as p(1) = claim - p(0). The nodes have to include
+     * 0 and 1, or this is CannotCompress
+     */
+    pub fn compress(&self, nodes: &Nodes<F>) -> Result<Vec<F>, SumcheckError> {
+        let num_nodes = self.coefficients.len();
+        nodes.position(F::ZERO, num_nodes)?;
+        let one = nodes.position(F::ONE, num_nodes)?;
+        let mut evaluations = self.evaluations(nodes);
+        evaluations.remove(one);
+        Ok(evaluations)
+    }
+    pub fn decompress(compressed: &[F], nodes: &Nodes<F>, claim: F) -> Result<Self, SumcheckError> {
+        let num_nodes = compressed.len() + 1;
+        let one = nodes.position(F::ONE, num_nodes)?;
+        let zero = nodes.position(F::ZERO, num_nodes)?;
+        // zero comes before one in the compressed evaluations if it comes before it in the nodes
+        let evaluation_at_zero = match zero < one {
+            true => compressed[zero],
+            false => compressed[zero - 1],
+        };
+        let mut evaluations = compressed.to_vec();
+        evaluations.insert(one, claim - evaluation_at_zero);
+        Ok(Self::from_evaluations(&evaluations, nodes))
+    }
+    // (X - r_0)(X - r_1)...
+    fn from_roots(roots: &[F]) -> Self {
+        let mut coefficients = vec![F::ONE];
+        for root in roots {
+            coefficients.insert(0, F::ZERO);
+            for i in 0..coefficients.len() - 1 {
+                let next = coefficients[i + 1];
+                coefficients[i] -= *root * next;
+            }
+        }
+        Self::new(coefficients)
+    }
+    // Lagrange interpolation, quadratic in the number of points which is small here
+    fn interpolate(points: &[F], evaluations: &[F]) -> Vec<F> {
+        let mut coefficients = vec![F::ZERO; points.len()];
+        for (i, (point_i, evaluation_i)) in points.iter().zip(evaluations).enumerate() {
+            let others: Vec<F> = points
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, point_j)| *point_j)
+                .collect();
+            let basis = Self::from_roots(&others);
+            let scale = *evaluation_i * basis.evaluate(*point_i).inverse().unwrap();
+            for (coefficient, basis_coefficient) in coefficients.iter_mut().zip(basis.coefficients)
+            {
+                *coefficient += scale * basis_coefficient;
+            }
+        }
+        coefficients
+    }
+}
+
+// the multilinear messages are (p(0), p(1))
+impl<F: Field> From<(F, F)> for RoundPolynomial<F> {
+    fn from(message: (F, F)) -> Self {
+        Self::from_evaluations(&[message.0, message.1], &Nodes::Consecutive)
+    }
+}
+
+// the product messages are (p(0), p(1), p(1/2))
+impl<F: Field> From<(F, F, F)> for RoundPolynomial<F> {
+    fn from(message: (F, F, F)) -> Self {
+        Self::from_evaluations(&[message.0, message.1, message.2], &Nodes::half())
+    }
+}
+
+impl<F: Field> CanonicalSerialize for RoundPolynomial<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.coefficients.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.coefficients.serialized_size(compress)
+    }
+}
+
+impl<F: Field> Valid for RoundPolynomial<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.coefficients.check()
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for RoundPolynomial<F> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let coefficients = deserialize_bounded_vec(reader, compress, validate, usize::MAX)?;
+        // there's no polynomial without coefficients
+        if coefficients.is_empty() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(Self { coefficients })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+    use crate::{
+        error::SumcheckError,
+        interpolation::LagrangePolynomial,
+        messages::{Nodes, RoundPolynomial},
+        order_strategy::GraycodeOrder,
+        tests::F64,
+    };
+
+    #[test]
+    fn node_sets() {
+        let mut rng = ark_std::test_rng();
+        for degree in [2, 3, 5] {
+            let p = RoundPolynomial::new((0..=degree).map(|_| F64::rand(&mut rng)).collect());
+            let points = vec![
+                F64::from(3_u32),
+                F64::from(5_u32),
+                F64::from(0_u32),
+                F64::from(1_u32),
+            ];
+            let mut node_sets = vec![Nodes::Consecutive, Nodes::Infinity];
+            if degree + 1 == points.len() {
+                node_sets.push(Nodes::Points(points));
+            }
+            for nodes in node_sets {
+                // evaluation form and back
+                let evaluations = p.evaluations(&nodes);
+                assert_eq!(evaluations.len(), degree + 1);
+                assert_eq!(RoundPolynomial::from_evaluations(&evaluations, &nodes), p);
+                // and without p(1)
+                let compressed = p.compress(&nodes).unwrap();
+                assert_eq!(compressed.len(), degree);
+                assert_eq!(
+                    RoundPolynomial::decompress(&compressed, &nodes, p.sum_over_boolean()).unwrap(),
+                    p
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn nodes_without_one() {
+        let constant = RoundPolynomial::new(vec![F64::from(3_u32)]);
+        let line = RoundPolynomial::new(vec![F64::from(3_u32), F64::from(5_u32)]);
+        // 0, ∞ for a line and 0 (or only ∞) for a constant have no p(1) to drop
+        for (p, nodes) in [
+            (&line, Nodes::Infinity),
+            (&constant, Nodes::Consecutive),
+            (&constant, Nodes::Infinity),
+            (
+                &line,
+                Nodes::Points(vec![F64::from(0_u32), F64::from(2_u32)]),
+            ),
+            (&line, Nodes::Points(vec![F64::from(0_u32)])),
+        ] {
+            assert!(matches!(
+                p.compress(&nodes),
+                Err(SumcheckError::CannotCompress(degree)) if degree == p.degree()
+            ));
+            let compressed = vec![F64::from(1_u32); p.degree()];
+            assert!(matches!(
+                RoundPolynomial::decompress(&compressed, &nodes, F64::from(1_u32)),
+                Err(SumcheckError::CannotCompress(degree)) if degree == p.degree()
+            ));
+        }
+        // a line at 0, 1 is fine
+        let compressed = line.compress(&Nodes::Consecutive).unwrap();
+        assert_eq!(
+            RoundPolynomial::decompress(&compressed, &Nodes::Consecutive, line.sum_over_boolean())
+                .unwrap(),
+            line
+        );
+    }
+
+    #[test]
+    fn from_messages() {
+        let mut rng = ark_std::test_rng();
+        let point = F64::rand(&mut rng);
+
+        // a product message agrees with the verifier's interpolation
+        let message = (
+            F64::rand(&mut rng),
+            F64::rand(&mut rng),
+            F64::rand(&mut rng),
+        );
+        let p = RoundPolynomial::from(message);
+        assert_eq!(p.degree(), 2);
+        assert_eq!(
+            p.evaluate(point),
+            LagrangePolynomial::<F64, GraycodeOrder>::evaluate_from_three_points(point, message)
+        );
+        // so does a multi product message
+        let message: Vec<F64> = (0..4).map(|_| F64::rand(&mut rng)).collect();
+        assert_eq!(
+            RoundPolynomial::from_evaluations(&message, &Nodes::Consecutive).evaluate(point),
+            LagrangePolynomial::<F64, GraycodeOrder>::evaluate_from_points(point, &message)
+        );
+
+        let mut bytes = Vec::new();
+        p.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            RoundPolynomial::<F64>::deserialize_compressed(&bytes[..]).unwrap(),
+            p
+        );
+    }
+}
@@ -0,0 +1,11 @@
+mod provers;
+mod sumcheck;
+mod verifier;
+
+pub use provers::{
+    blendy::{BlendyMultiProductProver, BlendyMultiProductProverConfig},
+    space::{SpaceMultiProductProver, SpaceMultiProductProverConfig},
+    time::{TimeMultiProductProver, TimeMultiProductProverConfig},
+};
+pub use sumcheck::MultiProductSumcheck;
+pub use verifier::{MultiProductSumcheckVerifier, MultiProductSumcheckVerifierConfig};
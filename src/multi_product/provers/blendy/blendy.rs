@@ -0,0 +1,384 @@
+use crate::{
+    hypercube::Hypercube,
+    interpolation::LagrangePolynomial,
+    messages::VerifierMessages,
+    multi_product::TimeMultiProductProver,
+    order_strategy::{GraycodeOrder, SignificantBitOrder},
+    parallel::{add_sums, fold_range},
+    streams::{add_weighted_evaluations, fold_partial_sums, Stream, EVALUATIONS_CHUNK_SIZE},
+};
+use ark_ff::Field;
+use ark_std::{cfg_chunks_mut, cfg_into_iter, cfg_iter_mut, vec::Vec};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::BTreeSet;
+
+/*
+ * Proves the sum of a product of streams, or of several products of the same streams
+ * at once: the factors of each term index into the streams, and every pass reads each
+ * stream once for all the terms, with one round polynomial per term
+ */
+pub struct BlendyMultiProductProver<F: Field, S: Stream<F>> {
+    pub claim: F,
+    pub current_round: usize,
+    pub streams: Vec<S>,
+    pub terms: Vec<Vec<usize>>,
+    pub num_stages: usize,
+    pub num_variables: usize,
+    pub last_round_phase1: usize,
+    pub verifier_messages: VerifierMessages<F>,
+    pub verifier_messages_round_comp: VerifierMessages<F>,
+    // one per stream
+    pub factor_tables: Vec<Vec<F>>,
+    // one per term
+    pub j_prime_tables: Vec<Vec<F>>,
+    pub stage_size: usize,
+    pub prev_table_round_num: usize,
+    pub prev_table_size: usize,
+    pub state_comp_set: BTreeSet<usize>,
+    pub switched_to_vsbw: bool,
+    pub vsbw_prover: TimeMultiProductProver<F, S>,
+}
+
+impl<F: Field, S: Stream<F>> BlendyMultiProductProver<F, S> {
+    pub fn is_initial_round(&self) -> bool {
+        self.current_round == 0
+    }
+
+    pub fn total_rounds(&self) -> usize {
+        self.num_variables
+    }
+
+    pub fn degree(&self) -> usize {
+        self.terms
+            .iter()
+            .map(|factors| factors.len())
+            .max()
+            .unwrap()
+    }
+
+    // the round polynomials of all the terms side by side, each of degree its number of factors
+    fn flat_sums_len(&self) -> usize {
+        self.terms.iter().map(|factors| factors.len() + 1).sum()
+    }
+
+    fn split_sums(&self, flat_sums: Vec<F>) -> Vec<Vec<F>> {
+        let mut flat_sums = flat_sums.into_iter();
+        self.terms
+            .iter()
+            .map(|factors| flat_sums.by_ref().take(factors.len() + 1).collect())
+            .collect()
+    }
+
+    pub fn init_round_vars(&mut self) {
+        let n = self.num_variables;
+        let j = self.current_round + 1;
+
+        if let Some(&prev_round) = self.state_comp_set.range(..=j).next_back() {
+            self.prev_table_round_num = prev_round;
+            if let Some(&next_round) = self.state_comp_set.range((j + 1)..).next() {
+                self.prev_table_size = next_round - prev_round;
+            } else {
+                self.prev_table_size = n + 1 - prev_round;
+            }
+        } else {
+            self.prev_table_round_num = 0;
+            self.prev_table_size = 0;
+        }
+    }
+
+    /*
+     * Each factor restricted to the current variable is the line p(0) + s * (p(1) - p(0)),
+     * so the round polynomial is a combination of the products of the 0 and 1 halves
+     * with weights Π_k (c_k ? s : 1 - s) where c ∈ {0,1}^d selects a half for every factor
+     */
+    fn half_weights(degree: usize) -> Vec<Vec<F>> {
+        (0..=degree)
+            .map(|s| {
+                let s = F::from(s as u64);
+                (0..1usize << degree)
+                    .map(|c| {
+                        (0..degree).fold(F::ONE, |weight, factor| {
+                            match (c >> (degree - 1 - factor)) & 1 {
+                                1 => weight * s,
+                                _ => weight * (F::ONE - s),
+                            }
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    // adds the product of the lines through the halves of each factor of each term to its round polynomial
+    fn line_products(&self, partial_sums_0: &[F], partial_sums_1: &[F], flat_sums: &mut [F]) {
+        let mut offset = 0;
+        for factors in self.terms.iter() {
+            let mut products = vec![F::ONE; factors.len() + 1];
+            for factor in factors {
+                let step = partial_sums_1[*factor] - partial_sums_0[*factor];
+                let mut value = partial_sums_0[*factor];
+                for product in products.iter_mut() {
+                    *product *= value;
+                    value += step;
+                }
+            }
+            for (sum, product) in flat_sums[offset..].iter_mut().zip(products) {
+                *sum += product;
+            }
+            offset += factors.len() + 1;
+        }
+    }
+
+    pub fn compute_round(&mut self) -> Vec<Vec<F>> {
+        // in the last rounds, we switch to the memory intensive prover
+        if self.switched_to_vsbw {
+            self.terms
+                .iter()
+                .map(|factors| self.vsbw_prover.vsbw_evaluate_factors(factors))
+                .collect()
+        }
+        // if first few rounds, then no table is computed, need to compute sums from the streams
+        else if self.current_round < self.last_round_phase1 {
+            // Lag Poly
+            let mut sequential_lag_poly: LagrangePolynomial<F, SignificantBitOrder> =
+                LagrangePolynomial::new(&self.verifier_messages_round_comp);
+            let lag_polys: Vec<F> =
+                (0..Hypercube::<SignificantBitOrder>::stop_value(self.current_round))
+                    .map(|_| sequential_lag_poly.next().unwrap())
+                    .collect();
+
+            // the blocks of all the streams are read a chunk at a time
+            let flat_sums = fold_partial_sums(
+                &self.streams,
+                self.current_round,
+                || lag_polys.iter().copied(),
+                || vec![F::ZERO; self.flat_sums_len()],
+                |mut sums, partial_sums_0, partial_sums_1| {
+                    self.line_products(partial_sums_0, partial_sums_1, &mut sums);
+                    sums
+                },
+                add_sums,
+            );
+            self.split_sums(flat_sums)
+        }
+        // computing evaluations from the cross product tables
+        else {
+            // things to help iterating
+            let table_num_vars = self.prev_table_size;
+            let b_prime_num_vars = self.current_round + 1 - self.prev_table_round_num;
+            let v_num_vars: usize =
+                self.prev_table_size + self.prev_table_round_num - self.current_round - 2;
+            let b_prime_index_left_shift = v_num_vars + 1;
+
+            // Lag Poly
+            let mut sequential_lag_poly: LagrangePolynomial<F, GraycodeOrder> =
+                LagrangePolynomial::new(&self.verifier_messages_round_comp);
+            let lag_polys_len = Hypercube::<GraycodeOrder>::stop_value(b_prime_num_vars);
+            let mut lag_polys: Vec<F> = vec![F::ONE; lag_polys_len];
+            for (b_prime_index, _) in Hypercube::<GraycodeOrder>::new(b_prime_num_vars) {
+                lag_polys[b_prime_index] = sequential_lag_poly.next().unwrap();
+            }
+
+            // accumulate the table entries of each term for every choice of halves c ∈ {0,1}^d
+            let b_prime_mask = lag_polys_len - 1;
+            self.terms
+                .iter()
+                .zip(self.j_prime_tables.iter())
+                .map(|(factors, j_prime_table)| {
+                    let degree = factors.len();
+                    let half_sums: Vec<F> = fold_range(
+                        1 << (b_prime_num_vars * degree),
+                        || vec![F::ZERO; 1 << degree],
+                        |mut half_sums, b_prime_indices| {
+                            let mut lag_poly = F::ONE;
+                            let mut b_prime_table_index = 0;
+                            for factor in 0..degree {
+                                let b_prime_index = (b_prime_indices
+                                    >> (b_prime_num_vars * (degree - 1 - factor)))
+                                    & b_prime_mask;
+                                lag_poly *= lag_polys[b_prime_index];
+                                b_prime_table_index |= (b_prime_index << b_prime_index_left_shift)
+                                    << (table_num_vars * (degree - 1 - factor));
+                            }
+                            for (v_index, _) in Hypercube::<GraycodeOrder>::new(v_num_vars) {
+                                let v_table_index = (0..degree).fold(0, |index, factor| {
+                                    index | v_index << (table_num_vars * (degree - 1 - factor))
+                                });
+                                for (c, half_sum) in half_sums.iter_mut().enumerate() {
+                                    let c_table_index = (0..degree).fold(0, |index, factor| {
+                                        index
+                                            | ((c >> (degree - 1 - factor)) & 1)
+                                                << (v_num_vars
+                                                    + table_num_vars * (degree - 1 - factor))
+                                    });
+                                    *half_sum += lag_poly
+                                        * j_prime_table
+                                            [b_prime_table_index | c_table_index | v_table_index];
+                                }
+                            }
+                            half_sums
+                        },
+                        add_sums,
+                    );
+                    let mut sums = vec![F::ZERO; degree + 1];
+                    for (sum, weights) in sums.iter_mut().zip(Self::half_weights(degree)) {
+                        for (half_sum, weight) in half_sums.iter().zip(weights) {
+                            *sum += *half_sum * weight;
+                        }
+                    }
+                    sums
+                })
+                .collect()
+        }
+    }
+
+    pub fn compute_state(&mut self) {
+        let j = self.current_round + 1;
+        let p = self.state_comp_set.contains(&j);
+        let is_largest = self.state_comp_set.range((j + 1)..).next().is_none();
+        let num_streams = self.streams.len();
+        if p && !is_largest {
+            let j_prime = self.prev_table_round_num;
+            let t = self.prev_table_size;
+
+            // zero out the tables, each is indexed by one t bit index per factor of its term
+            let factor_table_len = Hypercube::<SignificantBitOrder>::stop_value(t);
+            self.j_prime_tables = self
+                .terms
+                .iter()
+                .map(|factors| vec![F::ZERO; 1 << (t * factors.len())])
+                .collect();
+
+            // basically, this needs to get "zeroed" out at the beginning of state computation
+            self.verifier_messages_round_comp = VerifierMessages::new_from_self(
+                &self.verifier_messages,
+                j_prime - 1,
+                self.verifier_messages.messages.len(),
+            );
+
+            // some stuff for iterating
+            let b_num_vars: usize = self.num_variables + 1 - j_prime - t;
+            let x_num_vars = j_prime - 1;
+
+            // Lag Poly
+            let mut sequential_lag_poly: LagrangePolynomial<F, SignificantBitOrder> =
+                LagrangePolynomial::new(&self.verifier_messages);
+
+            assert!(x_num_vars == self.verifier_messages.messages.len());
+            let lag_polys_len = Hypercube::<SignificantBitOrder>::stop_value(x_num_vars);
+            let lag_polys: Vec<F> = (0..lag_polys_len)
+                .map(|_| sequential_lag_poly.next().unwrap())
+                .collect();
+
+            /*
+             * In significant bit order the evaluations for b' and b are at b' || b after the
+             * bound variables, so a run of consecutive b is contiguous. The factor tables
+             * hold a row of run_len b for every b', recomputed for every run
+             */
+            let run_len = (EVALUATIONS_CHUNK_SIZE / factor_table_len).clamp(
+                1,
+                Hypercube::<SignificantBitOrder>::stop_value(b_num_vars).min(factor_table_len),
+            );
+            let mut rows = vec![F::ZERO; factor_table_len * num_streams * run_len];
+            for run_start in
+                (0..Hypercube::<SignificantBitOrder>::stop_value(b_num_vars)).step_by(run_len)
+            {
+                // a row has the runs of every stream for one b', the read buffer is kept per thread
+                let fill_row = |buffer: &mut Vec<F>, (b_prime_index, row): (usize, &mut [F])| {
+                    row.fill(F::ZERO);
+                    add_weighted_evaluations(
+                        &self.streams,
+                        x_num_vars,
+                        lag_polys.iter().copied(),
+                        b_prime_index << b_num_vars | run_start,
+                        buffer,
+                        row,
+                    );
+                };
+                let row_iter = cfg_chunks_mut!(rows, num_streams * run_len).enumerate();
+                #[cfg(feature = "parallel")]
+                row_iter.for_each_init(|| vec![F::ZERO; num_streams * run_len], fill_row);
+                #[cfg(not(feature = "parallel"))]
+                {
+                    let mut buffer = vec![F::ZERO; num_streams * run_len];
+                    row_iter.for_each(|row| fill_row(&mut buffer, row));
+                }
+                self.factor_tables = (0..num_streams)
+                    .map(|stream| {
+                        rows.chunks(num_streams * run_len)
+                            .flat_map(|row| &row[stream * run_len..(stream + 1) * run_len])
+                            .copied()
+                            .collect()
+                    })
+                    .collect();
+                let factor_tables = &self.factor_tables;
+                for (factors, j_prime_table) in
+                    self.terms.iter().zip(self.j_prime_tables.iter_mut())
+                {
+                    let degree = factors.len();
+                    cfg_iter_mut!(j_prime_table)
+                        .enumerate()
+                        .for_each(|(table_index, entry)| {
+                            for offset in 0..run_len {
+                                *entry += factors.iter().enumerate().fold(
+                                    F::ONE,
+                                    |product, (factor, stream)| {
+                                        let b_prime_index = (table_index
+                                            >> (t * (degree - 1 - factor)))
+                                            & (factor_table_len - 1);
+                                        product
+                                            * factor_tables[*stream]
+                                                [b_prime_index * run_len + offset]
+                                    },
+                                );
+                            }
+                        });
+                }
+            }
+        } else if p && is_largest {
+            // switch to the memory intensive sumcheck on the last round computation
+            let num_variables_new = self.num_variables - j + 1;
+            self.switched_to_vsbw = true;
+
+            // initialize the evaluations for the memory-intensive implementation for the final rounds of the protocol
+            let mut sequential_lag_poly: LagrangePolynomial<F, SignificantBitOrder> =
+                LagrangePolynomial::new(&self.verifier_messages);
+            let lag_polys: Vec<F> = (0..Hypercube::<SignificantBitOrder>::stop_value(j - 1))
+                .map(|_| sequential_lag_poly.next().unwrap())
+                .collect();
+            // in significant bit order the remaining variables are contiguous after the bound ones, so they're read a chunk at a time
+            let evaluations_len = Hypercube::<SignificantBitOrder>::stop_value(num_variables_new);
+            let chunk_len = evaluations_len.min(EVALUATIONS_CHUNK_SIZE);
+            let chunks: Vec<Vec<F>> = cfg_into_iter!(0..evaluations_len / chunk_len)
+                .map(|chunk| {
+                    let mut buffer = vec![F::ZERO; num_streams * chunk_len];
+                    let mut sums = vec![F::ZERO; num_streams * chunk_len];
+                    add_weighted_evaluations(
+                        &self.streams,
+                        j - 1,
+                        lag_polys.iter().copied(),
+                        chunk * chunk_len,
+                        &mut buffer,
+                        &mut sums,
+                    );
+                    sums
+                })
+                .collect();
+            let evaluations: Vec<Vec<F>> = (0..num_streams)
+                .map(|stream| {
+                    chunks
+                        .iter()
+                        .flat_map(|sums| &sums[stream * chunk_len..(stream + 1) * chunk_len])
+                        .copied()
+                        .collect()
+                })
+                .collect();
+            self.vsbw_prover.evaluations = evaluations.into_iter().map(Some).collect();
+        } else if self.switched_to_vsbw {
+            let verifier_message = self.verifier_messages.messages[self.current_round - 1];
+            self.vsbw_prover
+                .vsbw_reduce_evaluations(verifier_message, F::ONE - verifier_message);
+        }
+    }
+}
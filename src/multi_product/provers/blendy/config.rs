@@ -0,0 +1,69 @@
+use ark_ff::Field;
+
+use crate::{
+    error::SumcheckError,
+    interpolation::check_degree,
+    multilinear_product::BlendyProductProverConfig,
+    prover::ProductProverConfig,
+    streams::{check_streams, Stream},
+};
+
+const DEFAULT_NUM_STAGES: usize = 2;
+
+pub struct BlendyMultiProductProverConfig<F, S>
+where
+    F: Field,
+    S: Stream<F>,
+{
+    pub num_stages: usize,
+    pub num_variables: usize,
+    pub claim: F,
+    pub streams: Vec<S>,
+}
+
+impl<F, S> BlendyMultiProductProverConfig<F, S>
+where
+    F: Field,
+    S: Stream<F>,
+{
+    pub fn new(claim: F, num_stages: usize, num_variables: usize, streams: Vec<S>) -> Self {
+        Self {
+            claim,
+            num_stages,
+            num_variables,
+            streams,
+        }
+    }
+    pub fn try_new(
+        claim: F,
+        num_stages: usize,
+        num_variables: usize,
+        streams: Vec<S>,
+    ) -> Result<Self, SumcheckError> {
+        check_streams(&streams, num_variables)?;
+        // the round polynomials are of degree the number of factors
+        check_degree::<F>(streams.len())?;
+        // the schedule needs at least one state computation
+        if num_stages == 0
+            || BlendyProductProverConfig::<F, S>::compute_state_comp_set(num_variables, num_stages)
+                .is_empty()
+        {
+            return Err(SumcheckError::InvalidNumStages {
+                num_stages,
+                num_variables,
+            });
+        }
+        Ok(Self::new(claim, num_stages, num_variables, streams))
+    }
+}
+
+impl<F: Field, S: Stream<F>> ProductProverConfig<F, S> for BlendyMultiProductProverConfig<F, S> {
+    fn default(claim: F, num_variables: usize, streams: Vec<S>) -> Self {
+        Self {
+            claim,
+            num_stages: DEFAULT_NUM_STAGES,
+            num_variables,
+            streams,
+        }
+    }
+}
@@ -0,0 +1,6 @@
+mod blendy;
+mod config;
+mod prover;
+
+pub use blendy::BlendyMultiProductProver;
+pub use config::BlendyMultiProductProverConfig;
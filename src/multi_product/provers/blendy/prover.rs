@@ -0,0 +1,140 @@
+use ark_ff::Field;
+use std::collections::BTreeSet;
+
+use crate::{
+    messages::VerifierMessages,
+    multi_product::{
+        BlendyMultiProductProver, BlendyMultiProductProverConfig, TimeMultiProductProver,
+    },
+    multilinear_product::BlendyProductProverConfig,
+    prover::Prover,
+    streams::Stream,
+};
+
+impl<F: Field, S: Stream<F>> Prover<F> for BlendyMultiProductProver<F, S> {
+    type ProverConfig = BlendyMultiProductProverConfig<F, S>;
+    type ProverMessage = Option<Vec<F>>;
+    type VerifierMessage = Option<F>;
+
+    fn claim(&self) -> F {
+        self.claim
+    }
+
+    fn new(prover_config: Self::ProverConfig) -> Self {
+        // the product of all the streams
+        let factors = (0..prover_config.streams.len()).collect();
+        Self::new_with_terms(
+            prover_config.claim,
+            prover_config.num_stages,
+            prover_config.num_variables,
+            prover_config.streams,
+            vec![factors],
+        )
+    }
+
+    fn next_message(&mut self, verifier_message: Self::VerifierMessage) -> Self::ProverMessage {
+        self.next_term_messages(verifier_message)
+            .map(|mut sums| sums.swap_remove(0))
+    }
+}
+
+impl<F: Field, S: Stream<F>> BlendyMultiProductProver<F, S> {
+    // a prover for several products of the same streams, the factors of each term index into the streams
+    pub(crate) fn new_with_terms(
+        claim: F,
+        num_stages: usize,
+        num_variables: usize,
+        streams: Vec<S>,
+        terms: Vec<Vec<usize>>,
+    ) -> Self {
+        // should be given at least one stream, and terms of them
+        assert!(!streams.is_empty());
+        assert!(terms
+            .iter()
+            .all(|factors| !factors.is_empty() && factors.iter().all(|f| *f < streams.len())));
+        let stage_size: usize = num_variables / num_stages;
+        let last_round_phase1: usize = BlendyProductProverConfig::<F, S>::LAST_ROUND_PHASE1;
+        let state_comp_set: BTreeSet<usize> =
+            BlendyProductProverConfig::<F, S>::compute_state_comp_set(num_variables, num_stages);
+        assert!(!state_comp_set.is_empty());
+
+        let last_round: usize = *state_comp_set.iter().max().unwrap();
+        let vsbw_prover = TimeMultiProductProver::<F, S> {
+            claim,
+            current_round: 0,
+            evaluations: vec![None; streams.len()],
+            streams: None,
+            num_variables: num_variables - last_round + 1,
+        };
+
+        // return the BlendyMultiProductProver instance
+        Self {
+            claim,
+            current_round: 0,
+            streams,
+            terms,
+            num_stages,
+            num_variables,
+            last_round_phase1,
+            verifier_messages: VerifierMessages::new(&vec![]),
+            verifier_messages_round_comp: VerifierMessages::new(&vec![]),
+            factor_tables: vec![],
+            j_prime_tables: vec![],
+            stage_size,
+            prev_table_round_num: 0,
+            prev_table_size: 0,
+            state_comp_set,
+            switched_to_vsbw: false,
+            vsbw_prover,
+        }
+    }
+
+    // the round polynomial of every term
+    pub(crate) fn next_term_messages(
+        &mut self,
+        verifier_message: Option<F>,
+    ) -> Option<Vec<Vec<F>>> {
+        // Ensure the current round is within bounds
+        if self.current_round >= self.total_rounds() {
+            return None;
+        }
+
+        if !self.is_initial_round() {
+            // this holds everything
+            self.verifier_messages
+                .receive_message(verifier_message.unwrap());
+            // this holds the randomness for between state computation r2
+            self.verifier_messages_round_comp
+                .receive_message(verifier_message.unwrap());
+        }
+
+        self.init_round_vars();
+
+        self.compute_state();
+
+        let sums: Vec<Vec<F>> = self.compute_round();
+
+        // Increment the round counter
+        self.current_round += 1;
+        if self.switched_to_vsbw {
+            self.vsbw_prover.current_round += 1;
+        }
+        // Return the computed polynomial evaluations
+        Some(sums)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        multi_product::BlendyMultiProductProver,
+        streams::MemoryStream,
+        tests::{multi_product::consistency_test, F64},
+    };
+
+    #[test]
+    fn parity_with_time_prover() {
+        consistency_test::<F64, MemoryStream<F64>, BlendyMultiProductProver<F64, MemoryStream<F64>>>(
+        );
+    }
+}
@@ -0,0 +1,3 @@
+pub mod blendy;
+pub mod space;
+pub mod time;
@@ -0,0 +1,48 @@
+use ark_ff::Field;
+
+use crate::{
+    error::SumcheckError,
+    interpolation::check_degree,
+    prover::ProductProverConfig,
+    streams::{check_streams, Stream},
+};
+
+pub struct SpaceMultiProductProverConfig<F, S>
+where
+    F: Field,
+    S: Stream<F>,
+{
+    pub num_variables: usize,
+    pub claim: F,
+    pub streams: Vec<S>,
+}
+
+impl<F, S> SpaceMultiProductProverConfig<F, S>
+where
+    F: Field,
+    S: Stream<F>,
+{
+    pub fn new(claim: F, num_variables: usize, streams: Vec<S>) -> Self {
+        Self {
+            claim,
+            num_variables,
+            streams,
+        }
+    }
+    pub fn try_new(claim: F, num_variables: usize, streams: Vec<S>) -> Result<Self, SumcheckError> {
+        check_streams(&streams, num_variables)?;
+        // the round polynomials are of degree the number of factors
+        check_degree::<F>(streams.len())?;
+        Ok(Self::new(claim, num_variables, streams))
+    }
+}
+
+impl<F: Field, S: Stream<F>> ProductProverConfig<F, S> for SpaceMultiProductProverConfig<F, S> {
+    fn default(claim: F, num_variables: usize, streams: Vec<S>) -> Self {
+        Self {
+            claim,
+            num_variables,
+            streams,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use ark_ff::Field;
+
+use crate::{
+    messages::VerifierMessages,
+    multi_product::{SpaceMultiProductProver, SpaceMultiProductProverConfig},
+    prover::Prover,
+    streams::Stream,
+};
+
+impl<F: Field, S: Stream<F>> Prover<F> for SpaceMultiProductProver<F, S> {
+    type ProverConfig = SpaceMultiProductProverConfig<F, S>;
+    type ProverMessage = Option<Vec<F>>;
+    type VerifierMessage = Option<F>;
+
+    fn claim(&self) -> F {
+        self.claim
+    }
+
+    fn new(prover_config: Self::ProverConfig) -> Self {
+        // should be given at least one stream
+        assert!(!prover_config.streams.is_empty());
+        Self {
+            claim: prover_config.claim,
+            streams: prover_config.streams,
+            verifier_messages: VerifierMessages::new(&vec![]),
+            current_round: 0,
+            num_variables: prover_config.num_variables,
+        }
+    }
+
+    fn next_message(&mut self, verifier_message: Self::VerifierMessage) -> Self::ProverMessage {
+        // Ensure the current round is within bounds
+        if self.current_round >= self.num_variables {
+            return None;
+        }
+
+        // If it's not the first round, add the verifier message to verifier_messages
+        if self.current_round != 0 {
+            self.verifier_messages
+                .receive_message(verifier_message.unwrap());
+        }
+
+        // evaluate using cty
+        let sums: Vec<F> = self.cty_evaluate();
+
+        // don't forget to increment the round
+        self.current_round += 1;
+
+        Some(sums)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        multi_product::SpaceMultiProductProver,
+        streams::MemoryStream,
+        tests::{multi_product::sanity_test, F19},
+    };
+
+    #[test]
+    fn sumcheck() {
+        sanity_test::<F19, MemoryStream<F19>, SpaceMultiProductProver<F19, MemoryStream<F19>>>();
+    }
+}
@@ -0,0 +1,51 @@
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::{
+    interpolation::LagrangePolynomial,
+    messages::VerifierMessages,
+    order_strategy::SignificantBitOrder,
+    parallel::add_sums,
+    streams::{fold_partial_sums, Stream},
+};
+
+pub struct SpaceMultiProductProver<F: Field, S: Stream<F>> {
+    pub claim: F,
+    pub current_round: usize,
+    pub streams: Vec<S>,
+    pub num_variables: usize,
+    pub verifier_messages: VerifierMessages<F>,
+}
+
+impl<F: Field, S: Stream<F>> SpaceMultiProductProver<F, S> {
+    pub fn degree(&self) -> usize {
+        self.streams.len()
+    }
+    pub fn cty_evaluate(&self) -> Vec<F> {
+        let degree = self.degree();
+        // the blocks of all the factors are read a chunk at a time, weighted by the Lagrange polynomials of the bound variables
+        fold_partial_sums(
+            &self.streams,
+            self.current_round,
+            || LagrangePolynomial::<F, SignificantBitOrder>::new(&self.verifier_messages),
+            || vec![F::ZERO; degree + 1],
+            |mut sums, partial_sums_0, partial_sums_1| {
+                // walk each factor along the line through its partial sums at 0 and 1
+                let mut products = vec![F::ONE; degree + 1];
+                for factor in 0..degree {
+                    let step = partial_sums_1[factor] - partial_sums_0[factor];
+                    let mut value = partial_sums_0[factor];
+                    for product in products.iter_mut() {
+                        *product *= value;
+                        value += step;
+                    }
+                }
+                for (sum, product) in sums.iter_mut().zip(products) {
+                    *sum += product;
+                }
+                sums
+            },
+            add_sums,
+        )
+    }
+}
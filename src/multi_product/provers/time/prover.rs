@@ -0,0 +1,68 @@
+use ark_ff::Field;
+
+use crate::{
+    multi_product::{TimeMultiProductProver, TimeMultiProductProverConfig},
+    prover::Prover,
+    streams::Stream,
+};
+
+impl<F: Field, S: Stream<F>> Prover<F> for TimeMultiProductProver<F, S> {
+    type ProverConfig = TimeMultiProductProverConfig<F, S>;
+    type ProverMessage = Option<Vec<F>>;
+    type VerifierMessage = Option<F>;
+
+    fn claim(&self) -> F {
+        self.claim
+    }
+
+    fn new(prover_config: Self::ProverConfig) -> Self {
+        // should be given at least one stream
+        assert!(!prover_config.streams.is_empty());
+        Self {
+            claim: prover_config.claim,
+            current_round: 0,
+            evaluations: vec![None; prover_config.streams.len()],
+            streams: Some(prover_config.streams),
+            num_variables: prover_config.num_variables,
+        }
+    }
+
+    fn next_message(&mut self, verifier_message: Option<F>) -> Option<Vec<F>> {
+        // Ensure the current round is within bounds
+        if self.current_round >= self.total_rounds() {
+            return None;
+        }
+
+        // If it's not the first round, reduce the evaluations table
+        if self.current_round != 0 {
+            // update the evaluations table by absorbing leftmost variable assigned to verifier_message
+            self.vsbw_reduce_evaluations(
+                verifier_message.unwrap(),
+                F::ONE - verifier_message.unwrap(),
+            );
+        }
+
+        // evaluate using vsbw
+        let sums = self.vsbw_evaluate();
+
+        // Increment the round counter
+        self.current_round += 1;
+
+        // Return the computed polynomial
+        Some(sums)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        multi_product::TimeMultiProductProver,
+        streams::MemoryStream,
+        tests::{multi_product::sanity_test, F19},
+    };
+
+    #[test]
+    fn sumcheck() {
+        sanity_test::<F19, MemoryStream<F19>, TimeMultiProductProver<F19, MemoryStream<F19>>>();
+    }
+}
@@ -0,0 +1,112 @@
+use ark_ff::Field;
+use ark_std::{cfg_into_iter, vec::Vec};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{
+    parallel::{add_sums, fold_range},
+    streams::{reduce_stream_evaluations, Stream, EVALUATIONS_CHUNK_SIZE},
+};
+
+pub struct TimeMultiProductProver<F: Field, S: Stream<F>> {
+    pub claim: F,
+    pub current_round: usize,
+    pub evaluations: Vec<Option<Vec<F>>>,
+    pub streams: Option<Vec<S>>,
+    pub num_variables: usize,
+}
+
+impl<F: Field, S: Stream<F>> TimeMultiProductProver<F, S> {
+    pub fn total_rounds(&self) -> usize {
+        self.num_variables
+    }
+    pub fn num_free_variables(&self) -> usize {
+        self.num_variables - self.current_round
+    }
+    pub fn degree(&self) -> usize {
+        self.evaluations.len()
+    }
+    fn point_evaluations(&self, factor: usize, start: usize, evaluations: &mut [F]) {
+        match &self.evaluations[factor] {
+            None => match &self.streams {
+                Some(streams) => streams[factor].evaluations_into(start, evaluations),
+                // every config has streams, and the blendy provers fill in the evaluations before switching
+                None => unreachable!("Both streams and evaluations cannot be None"),
+            },
+            Some(table) => evaluations.copy_from_slice(&table[start..start + evaluations.len()]),
+        }
+    }
+    /*
+     * Like the two factor prover, the first round reads directly from the streams,
+     * then each round the d+1 evaluations g(0), g(1), ..., g(d) are computed
+     * by walking every factor along the line through its evaluations at 0 and 1
+     */
+    pub fn vsbw_evaluate(&self) -> Vec<F> {
+        self.vsbw_evaluate_factors(&(0..self.degree()).collect::<Vec<usize>>())
+    }
+    // same as above, for the product of only these factors
+    pub fn vsbw_evaluate_factors(&self, factors: &[usize]) -> Vec<F> {
+        let degree = factors.len();
+
+        // Calculate the bitmask for the number of free variables
+        let bitmask: usize = 1 << (self.num_free_variables() - 1);
+
+        // Iterate through evaluations, a chunk of each half of each factor at a time
+        fold_range(
+            bitmask.div_ceil(EVALUATIONS_CHUNK_SIZE),
+            || vec![F::ZERO; degree + 1],
+            |mut sums, chunk| {
+                let start = chunk * EVALUATIONS_CHUNK_SIZE;
+                let chunk_len = (bitmask - start).min(EVALUATIONS_CHUNK_SIZE);
+                let mut products = vec![vec![F::ONE; degree + 1]; chunk_len];
+                let mut zeros = vec![F::ZERO; chunk_len];
+                let mut ones = vec![F::ZERO; chunk_len];
+                for factor in factors {
+                    self.point_evaluations(*factor, start, &mut zeros);
+                    self.point_evaluations(*factor, start | bitmask, &mut ones);
+                    for ((products, zero), one) in products.iter_mut().zip(&zeros).zip(&ones) {
+                        // p(s) = p(0) + s * (p(1) - p(0)) for s = 0, 1, ..., d
+                        let step = *one - zero;
+                        let mut value = *zero;
+                        for product in products.iter_mut() {
+                            *product *= value;
+                            value += step;
+                        }
+                    }
+                }
+                for products in products {
+                    for (sum, product) in sums.iter_mut().zip(products) {
+                        *sum += product;
+                    }
+                }
+                sums
+            },
+            add_sums,
+        )
+    }
+    pub fn vsbw_reduce_evaluations(&mut self, verifier_message: F, verifier_message_hat: F) {
+        // Calculate what bit needs to be set to index the second half of the last round's evaluations
+        let setbit: usize = 1 << self.num_free_variables();
+
+        for factor in 0..self.degree() {
+            // Iterate through pairs of evaluations, the first fold reads the stream a chunk at a time
+            let evaluations: Vec<F> = match (&self.evaluations[factor], &self.streams) {
+                (Some(evaluations), _) => cfg_into_iter!(0..setbit)
+                    .map(|i0| {
+                        let i1 = i0 | setbit;
+                        evaluations[i0] * verifier_message_hat + evaluations[i1] * verifier_message
+                    })
+                    .collect(),
+                (None, Some(streams)) => reduce_stream_evaluations(
+                    &streams[factor],
+                    verifier_message,
+                    verifier_message_hat,
+                ),
+                (None, None) => unreachable!("Both streams and evaluations cannot be None"),
+            };
+
+            // Update the internal state with the new evaluations vector
+            self.evaluations[factor] = Some(evaluations);
+        }
+    }
+}
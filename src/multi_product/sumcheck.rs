@@ -0,0 +1,313 @@
+use ark_ff::Field;
+use ark_std::{rand::Rng, vec::Vec};
+
+use crate::{
+    error::SumcheckError,
+    interpolation::{check_degree, LagrangePolynomial},
+    messages::{CompressedProof, Nodes, RoundPolynomial},
+    multi_product::MultiProductSumcheckVerifier,
+    order_strategy::GraycodeOrder,
+    prover::Prover,
+    streams::Stream,
+    transcript::Transcript,
+    verifier::Verifier,
+};
+
+#[derive(Debug, PartialEq)]
+pub struct MultiProductSumcheck<F: Field> {
+    pub prover_messages: Vec<Vec<F>>,
+    pub verifier_messages: Vec<F>,
+    // None when the transcript was rejected
+    pub reduced_claim: Option<F>,
+    pub is_accepted: bool,
+}
+
+impl<F: Field> MultiProductSumcheck<F> {
+    pub fn prove<S, P>(prover: &mut P, rng: &mut impl Rng) -> Self
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<Vec<F>>>,
+    {
+        Self::run(prover, |_| F::rand(rng))
+    }
+
+    pub fn prove_non_interactive<S, P, T>(prover: &mut P, transcript: &mut T) -> Self
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<Vec<F>>>,
+        T: Transcript<F>,
+    {
+        // Fiat-Shamir: each verifier message is derived from the claim and all prover messages so far
+        transcript.absorb(&[prover.claim()]);
+        Self::run(prover, |message| {
+            MultiProductSumcheckVerifier::<F>::absorb_prover_message(transcript, message);
+            transcript.challenge()
+        })
+    }
+
+    // like prove, but a rejected transcript is an error
+    pub fn try_prove<S, P>(prover: &mut P, rng: &mut impl Rng) -> Result<Self, SumcheckError>
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<Vec<F>>>,
+    {
+        Self::prove::<S, P>(prover, rng).accepted()
+    }
+
+    pub fn try_prove_non_interactive<S, P, T>(
+        prover: &mut P,
+        transcript: &mut T,
+    ) -> Result<Self, SumcheckError>
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<Vec<F>>>,
+        T: Transcript<F>,
+    {
+        Self::prove_non_interactive::<S, P, T>(prover, transcript).accepted()
+    }
+
+    // the prover messages in coefficient form, e.g. for a verifier with another message format
+    pub fn round_polynomials(&self) -> Vec<RoundPolynomial<F>> {
+        self.prover_messages
+            .iter()
+            .map(|message| RoundPolynomial::from_evaluations(message, &Nodes::Consecutive))
+            .collect()
+    }
+    // the proof as the compressed round polynomials at the given nodes
+    pub fn compressed_proof(&self, nodes: Nodes<F>) -> Result<CompressedProof<F>, SumcheckError> {
+        CompressedProof::new(&self.round_polynomials(), nodes)
+    }
+
+    fn accepted(self) -> Result<Self, SumcheckError> {
+        match self.is_accepted {
+            true => Ok(self),
+            // the rejected message is the last one
+            false => Err(SumcheckError::Rejected(self.prover_messages.len() - 1)),
+        }
+    }
+
+    fn run<P>(prover: &mut P, mut next_verifier_message: impl FnMut(&Vec<F>) -> F) -> Self
+    where
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<Vec<F>>>,
+    {
+        // Initialize vectors to store prover and verifier messages
+        let mut prover_messages: Vec<Vec<F>> = vec![];
+        let mut verifier_messages: Vec<F> = vec![];
+        let mut is_accepted = true;
+
+        // Run the protocol
+        let mut verifier_message: Option<F> = None;
+        while let Some(message) = prover.next_message(verifier_message) {
+            // every round polynomial is given by its evaluations at 0, 1, ..., d
+            let is_round_accepted = message.len() >= 2
+                && check_degree::<F>(message.len() - 1).is_ok()
+                && match verifier_message {
+                    // If first round, compare to claimed_sum
+                    None => message[0] + message[1] == prover.claim(),
+                    Some(prev_verifier_message) => {
+                        verifier_messages.push(prev_verifier_message);
+                        let prev_prover_message = prover_messages.last().unwrap();
+                        message[0] + message[1]
+                            == LagrangePolynomial::<F, GraycodeOrder>::evaluate_from_points(
+                                prev_verifier_message,
+                                prev_prover_message,
+                            )
+                    }
+                };
+
+            // Handle how to proceed
+            prover_messages.push(message);
+            if !is_round_accepted {
+                is_accepted = false;
+                break;
+            }
+
+            verifier_message = Some(next_verifier_message(prover_messages.last().unwrap()));
+        }
+
+        // The last verifier message reduces the claim to the value Π f_k(r_1, ..., r_n) should take
+        let reduced_claim = match (is_accepted, prover_messages.last(), verifier_message) {
+            (true, Some(last_prover_message), Some(last_verifier_message)) => {
+                verifier_messages.push(last_verifier_message);
+                Some(
+                    LagrangePolynomial::<F, GraycodeOrder>::evaluate_from_points(
+                        last_verifier_message,
+                        last_prover_message,
+                    ),
+                )
+            }
+            // there were no rounds at all
+            (true, _, _) => Some(prover.claim()),
+            // rejected, so there is nothing to reduce
+            (false, _, _) => None,
+        };
+
+        // Return a MultiProductSumcheck struct with the collected messages and acceptance status
+        MultiProductSumcheck {
+            prover_messages,
+            verifier_messages,
+            reduced_claim,
+            is_accepted,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MultiProductSumcheck;
+    use crate::{
+        multi_product::{
+            BlendyMultiProductProver, BlendyMultiProductProverConfig, MultiProductSumcheckVerifier,
+            MultiProductSumcheckVerifierConfig, SpaceMultiProductProver, TimeMultiProductProver,
+        },
+        multilinear_product::{TimeProductProver, TimeProductProverConfig},
+        prover::{ProductProverConfig, Prover},
+        streams::{multivariate_product_claim, multivariate_product_evaluation, MemoryStream},
+        tests::{multi_product::consistency_test, BenchStream, F64},
+        transcript::HashTranscript,
+        verifier::Verifier,
+        ProductSumcheck,
+    };
+    use sha2::Sha256;
+
+    #[test]
+    fn algorithm_consistency() {
+        consistency_test::<F64, MemoryStream<F64>, TimeMultiProductProver<F64, MemoryStream<F64>>>(
+        );
+        consistency_test::<F64, MemoryStream<F64>, SpaceMultiProductProver<F64, MemoryStream<F64>>>(
+        );
+    }
+
+    #[test]
+    fn final_oracle_check() {
+        // take three evaluation streams
+        let streams: Vec<BenchStream<F64>> = vec![BenchStream::new(10); 3];
+        let claim = multivariate_product_claim(streams.clone());
+        // run the protocol
+        let transcript = MultiProductSumcheck::<F64>::prove::<
+            BenchStream<F64>,
+            BlendyMultiProductProver<F64, BenchStream<F64>>,
+        >(
+            &mut BlendyMultiProductProver::new(BlendyMultiProductProverConfig::default(
+                claim,
+                10,
+                streams.clone(),
+            )),
+            &mut ark_std::test_rng(),
+        );
+        assert!(transcript.is_accepted);
+        assert_eq!(transcript.verifier_messages.len(), 10);
+        // the reduced claim should be f_1(r_1, ..., r_n) * f_2(r_1, ..., r_n) * f_3(r_1, ..., r_n)
+        assert_eq!(
+            transcript.reduced_claim,
+            Some(multivariate_product_evaluation(
+                streams,
+                &transcript.verifier_messages
+            ))
+        );
+        // and the standalone verifier should agree
+        assert_eq!(
+            MultiProductSumcheckVerifier::new(MultiProductSumcheckVerifierConfig::new(
+                claim, 3, 10
+            ))
+            .verify(&transcript.prover_messages, &transcript.verifier_messages),
+            Some((
+                transcript.verifier_messages.clone(),
+                transcript.reduced_claim.unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn two_factor_parity() {
+        // for two factors, g(0) and g(1) agree with the three point product sumcheck
+        let s: BenchStream<F64> = BenchStream::new(10);
+        let claim = multivariate_product_claim(vec![s.clone(), s.clone()]);
+        let multi_product_transcript = MultiProductSumcheck::<F64>::prove::<
+            BenchStream<F64>,
+            TimeMultiProductProver<F64, BenchStream<F64>>,
+        >(
+            &mut TimeMultiProductProver::new(ProductProverConfig::default(
+                claim,
+                10,
+                vec![s.clone(), s.clone()],
+            )),
+            &mut ark_std::test_rng(),
+        );
+        let product_transcript = ProductSumcheck::<F64>::prove::<
+            BenchStream<F64>,
+            TimeProductProver<F64, BenchStream<F64>>,
+        >(
+            &mut TimeProductProver::new(TimeProductProverConfig::new(
+                claim,
+                10,
+                vec![s.clone(), s],
+            )),
+            &mut ark_std::test_rng(),
+        );
+        assert!(multi_product_transcript.is_accepted);
+        assert_eq!(
+            multi_product_transcript.verifier_messages,
+            product_transcript.verifier_messages
+        );
+        assert_eq!(
+            multi_product_transcript.reduced_claim,
+            product_transcript.reduced_claim
+        );
+        for (multi_product_message, product_message) in multi_product_transcript
+            .prover_messages
+            .iter()
+            .zip(product_transcript.prover_messages)
+        {
+            assert_eq!(multi_product_message[0], product_message.0);
+            assert_eq!(multi_product_message[1], product_message.1);
+        }
+    }
+
+    #[test]
+    fn non_interactive() {
+        // take four evaluation streams
+        let streams: Vec<BenchStream<F64>> = vec![BenchStream::new(10); 4];
+        let claim = multivariate_product_claim(streams.clone());
+        // run the protocol with two different provers
+        let time_prover_transcript = MultiProductSumcheck::<F64>::prove_non_interactive::<
+            BenchStream<F64>,
+            TimeMultiProductProver<F64, BenchStream<F64>>,
+            HashTranscript<F64, Sha256>,
+        >(
+            &mut TimeMultiProductProver::new(ProductProverConfig::default(
+                claim,
+                10,
+                streams.clone(),
+            )),
+            &mut HashTranscript::new(b"multi product sumcheck"),
+        );
+        let blendy_prover_transcript = MultiProductSumcheck::<F64>::prove_non_interactive::<
+            BenchStream<F64>,
+            BlendyMultiProductProver<F64, BenchStream<F64>>,
+            HashTranscript<F64, Sha256>,
+        >(
+            &mut BlendyMultiProductProver::new(BlendyMultiProductProverConfig::default(
+                claim, 10, streams,
+            )),
+            &mut HashTranscript::new(b"multi product sumcheck"),
+        );
+        assert!(time_prover_transcript.is_accepted);
+        // the transcript only depends on the messages, so should be identical
+        assert_eq!(time_prover_transcript, blendy_prover_transcript);
+        // the verifier can rederive the verifier messages
+        assert_eq!(
+            MultiProductSumcheckVerifier::new(MultiProductSumcheckVerifierConfig::new(
+                claim, 4, 10
+            ))
+            .verify_non_interactive(
+                &time_prover_transcript.prover_messages,
+                &mut HashTranscript::<F64, Sha256>::new(b"multi product sumcheck"),
+            ),
+            Some((
+                time_prover_transcript.verifier_messages.clone(),
+                time_prover_transcript.reduced_claim.unwrap()
+            ))
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use ark_ff::Field;
+
+use crate::{error::SumcheckError, interpolation::check_degree, verifier::VerifierConfig};
+
+// the product of two multilinear polynomials, same as the product sumcheck
+const DEFAULT_DEGREE: usize = 2;
+
+pub struct MultiProductSumcheckVerifierConfig<F: Field> {
+    pub degree: usize,
+    pub num_variables: usize,
+    pub claim: F,
+}
+
+impl<F: Field> MultiProductSumcheckVerifierConfig<F> {
+    pub fn new(claim: F, degree: usize, num_variables: usize) -> Self {
+        Self {
+            claim,
+            degree,
+            num_variables,
+        }
+    }
+    pub fn try_new(claim: F, degree: usize, num_variables: usize) -> Result<Self, SumcheckError> {
+        check_degree::<F>(degree)?;
+        Ok(Self::new(claim, degree, num_variables))
+    }
+}
+
+impl<F: Field> VerifierConfig<F> for MultiProductSumcheckVerifierConfig<F> {
+    fn default(claim: F, num_variables: usize) -> Self {
+        Self {
+            claim,
+            degree: DEFAULT_DEGREE,
+            num_variables,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod config;
+mod verifier;
+
+pub use config::MultiProductSumcheckVerifierConfig;
+pub use verifier::MultiProductSumcheckVerifier;
@@ -0,0 +1,171 @@
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::{
+    interpolation::{check_degree, LagrangePolynomial},
+    messages::{Nodes, RoundPolynomial},
+    multi_product::MultiProductSumcheckVerifierConfig,
+    order_strategy::GraycodeOrder,
+    transcript::Transcript,
+    verifier::Verifier,
+};
+
+pub struct MultiProductSumcheckVerifier<F: Field> {
+    pub claim: F,
+    pub current_round: usize,
+    pub degree: usize,
+    pub num_variables: usize,
+    pub running_claim: F,
+    pub verifier_messages: Vec<F>,
+}
+
+impl<F: Field> Verifier<F> for MultiProductSumcheckVerifier<F> {
+    type VerifierConfig = MultiProductSumcheckVerifierConfig<F>;
+    type ProverMessage = Vec<F>;
+
+    fn claim(&self) -> F {
+        self.claim
+    }
+
+    fn new(verifier_config: Self::VerifierConfig) -> Self {
+        Self {
+            claim: verifier_config.claim,
+            current_round: 0,
+            degree: verifier_config.degree,
+            num_variables: verifier_config.num_variables,
+            running_claim: verifier_config.claim,
+            verifier_messages: Vec::with_capacity(verifier_config.num_variables),
+        }
+    }
+
+    fn next_message(&mut self, prover_message: &Vec<F>, verifier_message: F) -> Option<F> {
+        // Ensure the current round is within bounds
+        if self.current_round >= self.num_variables {
+            return None;
+        }
+
+        // a degree d round polynomial is sent as its evaluations at 0, 1, ..., d, which should be distinct
+        if prover_message.len() != self.degree + 1
+            || self.degree == 0
+            || check_degree::<F>(self.degree).is_err()
+        {
+            return None;
+        }
+
+        // g(0) + g(1) should equal the claim carried over from the last round
+        if prover_message[0] + prover_message[1] != self.running_claim {
+            return None;
+        }
+
+        // reduce the claim to g(r)
+        self.running_claim = LagrangePolynomial::<F, GraycodeOrder>::evaluate_from_points(
+            verifier_message,
+            prover_message,
+        );
+        self.verifier_messages.push(verifier_message);
+
+        // don't forget to increment the round
+        self.current_round += 1;
+
+        Some(self.running_claim)
+    }
+
+    fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+
+    fn degree(&self) -> usize {
+        self.degree
+    }
+
+    // evaluations at 0, 1, ..., d for the degree this verifier takes
+    fn prover_message(&self, round_polynomial: &RoundPolynomial<F>) -> Option<Vec<F>> {
+        (round_polynomial.degree() <= self.degree).then(|| {
+            Nodes::Consecutive
+                .points(self.degree + 1)
+                .into_iter()
+                .map(|point| round_polynomial.evaluate(point))
+                .collect()
+        })
+    }
+
+    fn absorb_prover_message<T: Transcript<F>>(
+        transcript: &mut T,
+        prover_message: &Self::ProverMessage,
+    ) {
+        transcript.absorb(prover_message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+
+    use crate::{
+        error::SumcheckError,
+        multi_product::{
+            MultiProductSumcheckVerifier, MultiProductSumcheckVerifierConfig,
+            TimeMultiProductProver, TimeMultiProductProverConfig,
+        },
+        prover::Prover,
+        streams::{multivariate_product_claim, MemoryStream},
+        tests::{polynomials::four_variable_polynomial_evaluations, F19},
+        verifier::Verifier,
+    };
+
+    #[test]
+    fn verify() {
+        // run the prover on its own, as if somebody else produced the transcript
+        let mut rng = ark_std::test_rng();
+        let s: MemoryStream<F19> = MemoryStream::new(four_variable_polynomial_evaluations());
+        let claim = multivariate_product_claim(vec![s.clone(), s.clone(), s.clone()]);
+        let mut prover = TimeMultiProductProver::<F19, MemoryStream<F19>>::new(
+            TimeMultiProductProverConfig::new(claim, 4, vec![s.clone(), s.clone(), s]),
+        );
+        let mut prover_messages: Vec<Vec<F19>> = vec![];
+        let mut verifier_messages: Vec<F19> = vec![];
+        let mut verifier_message: Option<F19> = None;
+        while let Some(message) = prover.next_message(verifier_message) {
+            prover_messages.push(message);
+            verifier_messages.push(F19::rand(&mut rng));
+            verifier_message = verifier_messages.last().copied();
+        }
+
+        // an honest transcript is accepted
+        let mut verifier =
+            MultiProductSumcheckVerifier::new(MultiProductSumcheckVerifierConfig::new(claim, 3, 4));
+        let (point, _) = verifier
+            .verify(&prover_messages, &verifier_messages)
+            .unwrap();
+        assert_eq!(point, verifier_messages);
+
+        // a tampered one is not
+        prover_messages[2][1] += F19::from(1);
+        let mut verifier =
+            MultiProductSumcheckVerifier::new(MultiProductSumcheckVerifierConfig::new(claim, 3, 4));
+        assert!(verifier
+            .verify(&prover_messages, &verifier_messages)
+            .is_none());
+
+        // and neither is one of the wrong degree
+        let mut verifier =
+            MultiProductSumcheckVerifier::new(MultiProductSumcheckVerifierConfig::new(claim, 2, 4));
+        assert!(verifier
+            .verify(&prover_messages, &verifier_messages)
+            .is_none());
+    }
+
+    #[test]
+    fn degree_too_large() {
+        // 20 evaluations can't be told apart at the nodes 0, 1, ..., 19 of F19
+        assert!(matches!(
+            MultiProductSumcheckVerifierConfig::<F19>::try_new(F19::from(0), 19, 1),
+            Err(SumcheckError::DegreeTooLarge(19))
+        ));
+        let prover_messages = vec![vec![F19::from(0); 20]];
+        let mut verifier = MultiProductSumcheckVerifier::new(
+            MultiProductSumcheckVerifierConfig::new(F19::from(0), 19, 1),
+        );
+        assert!(verifier.verify(&prover_messages, &[F19::from(3)]).is_none());
+    }
+}
@@ -1,9 +1,15 @@
+mod proof;
 mod provers;
 mod sumcheck;
+mod verifier;
 
+pub use proof::{SumcheckProof, SUMCHECK_PROOF_VERSION};
 pub use provers::{
     blendy::{BlendyProver, BlendyProverConfig},
+    disk::{DiskTable, DiskTimeProver, DiskTimeProverConfig},
+    extension::{ExtensionProver, ExtensionProverConfig},
     space::{SpaceProver, SpaceProverConfig},
     time::{TimeProver, TimeProverConfig},
 };
 pub use sumcheck::Sumcheck;
+pub use verifier::{SumcheckVerifier, SumcheckVerifierConfig};
@@ -0,0 +1,129 @@
+use ark_ff::Field;
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
+use ark_std::{
+    io::{Read, Write},
+    vec::Vec,
+};
+
+use crate::{
+    messages::{deserialize_bounded_vec, MAX_ROUNDS},
+    multilinear::Sumcheck,
+};
+
+// bump this whenever the encoding below changes
+pub const SUMCHECK_PROOF_VERSION: u8 = 1;
+
+/*
+ * The proof is just the prover messages: the verifier messages are either
+ * the verifier's own randomness or rederived from a transcript.
+ */
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SumcheckProof<F: Field> {
+    pub prover_messages: Vec<(F, F)>,
+}
+
+impl<F: Field> SumcheckProof<F> {
+    pub fn new(prover_messages: Vec<(F, F)>) -> Self {
+        Self { prover_messages }
+    }
+}
+
+impl<F: Field> From<Sumcheck<F>> for SumcheckProof<F> {
+    fn from(transcript: Sumcheck<F>) -> Self {
+        Self::new(transcript.prover_messages)
+    }
+}
+
+impl<F: Field> CanonicalSerialize for SumcheckProof<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        SUMCHECK_PROOF_VERSION.serialize_with_mode(&mut writer, compress)?;
+        self.prover_messages
+            .serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        SUMCHECK_PROOF_VERSION.serialized_size(compress)
+            + self.prover_messages.serialized_size(compress)
+    }
+}
+
+impl<F: Field> Valid for SumcheckProof<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.prover_messages.check()
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for SumcheckProof<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        // refuse anything written in an encoding we don't know
+        let version = u8::deserialize_with_mode(&mut reader, compress, validate)?;
+        if version != SUMCHECK_PROOF_VERSION {
+            return Err(SerializationError::InvalidData);
+        }
+        let prover_messages =
+            deserialize_bounded_vec::<(F, F), _>(&mut reader, compress, validate, MAX_ROUNDS)?;
+        Ok(Self { prover_messages })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+    use crate::{
+        multilinear::{SumcheckProof, TimeProver, TimeProverConfig},
+        prover::Prover,
+        tests::{BenchStream, F64},
+        Sumcheck,
+    };
+
+    #[test]
+    fn round_trip() {
+        let s: BenchStream<F64> = BenchStream::new(10);
+        let transcript =
+            Sumcheck::<F64>::prove::<BenchStream<F64>, TimeProver<F64, BenchStream<F64>>>(
+                &mut TimeProver::new(TimeProverConfig::new(s.claimed_sum, 10, s)),
+                &mut ark_std::test_rng(),
+            );
+        let proof: SumcheckProof<F64> = transcript.into();
+
+        // compressed
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), proof.compressed_size());
+        assert_eq!(
+            SumcheckProof::<F64>::deserialize_compressed(&bytes[..]).unwrap(),
+            proof
+        );
+
+        // uncompressed
+        let mut bytes = Vec::new();
+        proof.serialize_uncompressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), proof.uncompressed_size());
+        assert_eq!(
+            SumcheckProof::<F64>::deserialize_uncompressed(&bytes[..]).unwrap(),
+            proof
+        );
+
+        // a length that's too long for a proof, or past the end of the bytes
+        bytes[1..9].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(SumcheckProof::<F64>::deserialize_uncompressed(&bytes[..]).is_err());
+        bytes[1..9].copy_from_slice(&20_u64.to_le_bytes());
+        assert!(SumcheckProof::<F64>::deserialize_uncompressed(&bytes[..]).is_err());
+
+        // unknown version
+        bytes[0] += 1;
+        assert!(SumcheckProof::<F64>::deserialize_uncompressed(&bytes[..]).is_err());
+    }
+}
@@ -2,10 +2,17 @@ use ark_ff::Field;
 use ark_std::vec::Vec;
 
 use crate::{
-    hypercube::Hypercube, interpolation::LagrangePolynomial, messages::VerifierMessages,
-    order_strategy::GraycodeOrder, streams::Stream,
+    hypercube::{Hypercube, HypercubeMember},
+    interpolation::LagrangePolynomial,
+    messages::VerifierMessages,
+    order_strategy::GraycodeOrder,
+    parallel::{add_sums, fold_range},
+    streams::{for_each_chunk, Stream},
 };
 
+// how many of the leading variables of b1 are split across threads in sum_update
+const SPLIT_NUM_VARS: usize = 6;
+
 pub struct BlendyProver<F, S>
 where
     F: Field,
@@ -103,39 +110,78 @@ where
         let b3_num_vars: usize = self.num_variables - b1_num_vars - b2_num_vars;
 
         // 1. Initialize SUM[b2] := 0 for each b2 ∈ {0,1}^l
-        // we reuse self.sums we just have to zero out on the first access SEE BELOW
-
-        // 2. Initialize st := LagInit((s - l)l, r)
-        let mut sequential_lag_poly: LagrangePolynomial<F, GraycodeOrder> =
-            LagrangePolynomial::new(&self.verifier_messages);
-
-        // 3. For each b1 ∈ {0,1}^(s-1)l
-        let len_sums: usize = self.sums.len();
-        for (b1_index, _) in Hypercube::<GraycodeOrder>::new(b1_num_vars) {
-            // (a) Compute (LagPoly, st) := LagNext(st)
-            let lag_poly = sequential_lag_poly.next().unwrap();
-
-            // (b) For each b2 ∈ {0,1}^l, for each b2 ∈ {0,1}^(k-s)l
-            for (b2_index, _) in Hypercube::<GraycodeOrder>::new(b2_num_vars) {
-                for (b3_index, _) in Hypercube::<GraycodeOrder>::new(b3_num_vars) {
-                    // Calculate the index for the current combination of b1, b2, and b3
-                    let index = b1_index << (b2_num_vars + b3_num_vars)
-                        | b2_index << b3_num_vars
-                        | b3_index;
-
-                    // Update SUM[b2]
-                    self.sums[b2_index] =
-                        match b1_index == 0 && b3_index == 0 && b2_index < len_sums {
-                            // SEE HERE zero out the array on first access per update
-                            true => lag_poly * self.evaluation_stream.evaluation(index),
-                            false => {
-                                self.sums[b2_index]
-                                    + lag_poly * self.evaluation_stream.evaluation(index)
-                            }
-                        };
-                }
+        let b2_len: usize = Hypercube::<GraycodeOrder>::stop_value(b2_num_vars);
+
+        // a sparse stream only adds its non-zeros, sorted so eq(r, b1) changes with b1 only
+        if let Some(sparse_len) = self.evaluation_stream.sparse_len() {
+            self.sums[..b2_len].fill(F::ZERO);
+            let mut lag_poly: Option<(usize, F)> = None;
+            for position in 0..sparse_len {
+                let (index, value) = self.evaluation_stream.sparse_entry(position);
+                let b1_index = index >> (b2_num_vars + b3_num_vars);
+                let b2_index = (index >> b3_num_vars) & (b2_len - 1);
+                let weight = match lag_poly {
+                    Some((b1, weight)) if b1 == b1_index => weight,
+                    _ => {
+                        let weight = LagrangePolynomial::<F, GraycodeOrder>::lag_poly(
+                            &self.verifier_messages.messages[..b1_num_vars],
+                            &self.verifier_messages.message_hats[..b1_num_vars],
+                            HypercubeMember::new(b1_num_vars, b1_index),
+                        );
+                        lag_poly = Some((b1_index, weight));
+                        weight
+                    }
+                };
+                self.sums[b2_index] += weight * value;
             }
+            return;
         }
+
+        // 2. Initialize st := LagInit((s - l)l, r), split so the threads can share the b1 loop:
+        // eq(r, b1) = eq(r_hi, b1_hi) * eq(r_lo, b1_lo), where only the low part is sequential
+        let b1_hi_num_vars: usize = std::cmp::min(b1_num_vars, SPLIT_NUM_VARS);
+        let b1_lo_num_vars: usize = b1_num_vars - b1_hi_num_vars;
+        let verifier_messages_lo: VerifierMessages<F> =
+            VerifierMessages::new_from_self(&self.verifier_messages, b1_hi_num_vars, b1_num_vars);
+
+        // 3. For each b1 ∈ {0,1}^(s-1)l, with one read buffer per thread
+        let (sums, _) = fold_range(
+            Hypercube::<GraycodeOrder>::stop_value(b1_hi_num_vars),
+            || (vec![F::ZERO; b2_len], Vec::new()),
+            |(mut sums, mut buffer), b1_hi_index| {
+                let lag_poly_hi: F = LagrangePolynomial::<F, GraycodeOrder>::lag_poly(
+                    &self.verifier_messages.messages[..b1_hi_num_vars],
+                    &self.verifier_messages.message_hats[..b1_hi_num_vars],
+                    HypercubeMember::new(b1_hi_num_vars, b1_hi_index),
+                );
+                let mut sequential_lag_poly: LagrangePolynomial<F, GraycodeOrder> =
+                    LagrangePolynomial::new(&verifier_messages_lo);
+                for (b1_lo_index, _) in Hypercube::<GraycodeOrder>::new(b1_lo_num_vars) {
+                    // (a) Compute (LagPoly, st) := LagNext(st)
+                    let lag_poly = lag_poly_hi * sequential_lag_poly.next().unwrap();
+                    let b1_index = b1_hi_index << b1_lo_num_vars | b1_lo_index;
+
+                    // (b) For each b2 ∈ {0,1}^l, for each b2 ∈ {0,1}^(k-s)l, which is one block of the stream read a chunk at a time
+                    for_each_chunk(
+                        &self.evaluation_stream,
+                        b1_index << (b2_num_vars + b3_num_vars),
+                        1 << (b2_num_vars + b3_num_vars),
+                        &mut buffer,
+                        |chunk_start, evaluations| {
+                            for (offset, evaluation) in evaluations.iter().enumerate() {
+                                // Update SUM[b2]
+                                let b2_index =
+                                    ((chunk_start + offset) >> b3_num_vars) & (b2_len - 1);
+                                sums[b2_index] += lag_poly * evaluation;
+                            }
+                        },
+                    );
+                }
+                (sums, buffer)
+            },
+            |(sums, buffer), (other_sums, _)| (add_sums(sums, other_sums), buffer),
+        );
+        self.sums[..b2_len].copy_from_slice(&sums);
     }
     pub fn update_lag_polys(&mut self) {
         // Calculate j_prime as j-(s-1)l
@@ -160,19 +206,44 @@ where
     }
 
     pub fn update_prefix_sums(&mut self) {
-        self.sums = self
-            .sums
-            .clone()
-            .into_iter()
-            .enumerate()
-            .scan(F::ZERO, |sum, (index, item)| {
-                match self.is_single_staged() {
-                    true => *sum += self.evaluation_stream.evaluation(index),
-                    false => *sum += item,
+        // with one stage the table is the stream itself, which a sparse stream scatters into it first
+        if self.is_single_staged() {
+            if let Some(sparse_len) = self.evaluation_stream.sparse_len() {
+                self.sums.fill(F::ZERO);
+                for position in 0..sparse_len {
+                    let (index, value) = self.evaluation_stream.sparse_entry(position);
+                    self.sums[index] = value;
                 }
-                Some(*sum)
-            })
-            .collect::<Vec<F>>();
+                let mut sum = F::ZERO;
+                for entry in self.sums.iter_mut() {
+                    sum += *entry;
+                    *entry = sum;
+                }
+                return;
+            }
+        }
+        // in place, so this doesn't need a second table
+        let mut sum = F::ZERO;
+        if self.is_single_staged() {
+            let sums = &mut self.sums;
+            for_each_chunk(
+                &self.evaluation_stream,
+                0,
+                sums.len(),
+                &mut Vec::new(),
+                |chunk_start, evaluations| {
+                    for (offset, evaluation) in evaluations.iter().enumerate() {
+                        sum += evaluation;
+                        sums[chunk_start + offset] = sum;
+                    }
+                },
+            );
+            return;
+        }
+        for entry in self.sums.iter_mut() {
+            sum += *entry;
+            *entry = sum;
+        }
     }
     pub fn total_rounds(&self) -> usize {
         self.num_variables
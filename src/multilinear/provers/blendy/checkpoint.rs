@@ -0,0 +1,131 @@
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    vec::Vec,
+};
+
+use crate::{
+    error::SumcheckError,
+    messages::VerifierMessages,
+    multilinear::BlendyProver,
+    prover::{read_header, write_header, Checkpoint},
+    streams::Stream,
+};
+
+/*
+ * The prefix sums of the current stage and the lag polys of the last round are
+ * all that's carried between rounds, lag_polys_update is scratch space and the
+ * rest of the verifier message state is rederived from the messages themselves
+ */
+impl<F: Field, S: Stream<F>> Checkpoint for BlendyProver<F, S> {
+    const KIND: u8 = 1;
+    type Streams = S;
+
+    fn checkpoint<W: Write>(&self, mut writer: W) -> Result<(), SumcheckError> {
+        write_header(&mut writer, Self::KIND)?;
+        self.claimed_sum.serialize_uncompressed(&mut writer)?;
+        self.current_round.serialize_uncompressed(&mut writer)?;
+        self.num_stages.serialize_uncompressed(&mut writer)?;
+        self.num_variables.serialize_uncompressed(&mut writer)?;
+        self.verifier_messages
+            .messages
+            .serialize_uncompressed(&mut writer)?;
+        self.sums.serialize_uncompressed(&mut writer)?;
+        self.lag_polys.serialize_uncompressed(&mut writer)?;
+        Ok(())
+    }
+
+    fn resume<R: Read>(mut reader: R, stream: S) -> Result<Self, SumcheckError> {
+        read_header(&mut reader, Self::KIND)?;
+        let claimed_sum = F::deserialize_uncompressed(&mut reader)?;
+        let current_round = usize::deserialize_uncompressed(&mut reader)?;
+        let num_stages = usize::deserialize_uncompressed(&mut reader)?;
+        let num_variables = usize::deserialize_uncompressed(&mut reader)?;
+        let verifier_messages = Vec::<F>::deserialize_uncompressed(&mut reader)?;
+        let sums = Vec::<F>::deserialize_uncompressed(&mut reader)?;
+        let lag_polys = Vec::<F>::deserialize_uncompressed(&mut reader)?;
+        if num_stages == 0 {
+            return Err(SerializationError::InvalidData.into());
+        }
+        if stream.num_variables() != num_variables {
+            return Err(SumcheckError::NumVariablesMismatch {
+                expected: num_variables,
+                found: stream.num_variables(),
+            });
+        }
+        Ok(Self {
+            claimed_sum,
+            current_round,
+            evaluation_stream: stream,
+            lag_polys_update: vec![F::ONE; lag_polys.len()],
+            lag_polys,
+            num_stages,
+            num_variables,
+            stage_size: num_variables / num_stages,
+            sums,
+            verifier_messages: VerifierMessages::new(&verifier_messages),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::SumcheckError,
+        multilinear::{BlendyProver, BlendyProverConfig, TimeProver},
+        prover::{Checkpoint, Prover},
+        tests::{checkpoint_test, BenchStream, F64},
+    };
+
+    #[test]
+    fn resume() {
+        let stream: BenchStream<F64> = BenchStream::new(10);
+        let claim = stream.claimed_sum;
+        // 3 doesn't divide 10, so this covers the oddly sized last stage too
+        for num_stages in [1, 2, 3] {
+            checkpoint_test::<F64, BlendyProver<F64, BenchStream<F64>>>(
+                10,
+                || {
+                    BlendyProver::new(BlendyProverConfig::new(
+                        claim,
+                        num_stages,
+                        10,
+                        stream.clone(),
+                    ))
+                },
+                || stream.clone(),
+            );
+        }
+    }
+
+    #[test]
+    fn save_and_load() {
+        let stream: BenchStream<F64> = BenchStream::new(10);
+        let claim = stream.claimed_sum;
+        let mut prover = BlendyProver::new(BlendyProverConfig::new(claim, 2, 10, stream.clone()));
+        prover.next_message(None);
+        prover.next_message(Some(F64::from(3_u32)));
+        let path = std::env::temp_dir().join("blendy_checkpoint_save_and_load");
+        prover.save(&path).unwrap();
+        let mut resumed = BlendyProver::load(&path, stream.clone()).unwrap();
+        assert_eq!(
+            resumed.next_message(Some(F64::from(4_u32))),
+            prover.next_message(Some(F64::from(4_u32)))
+        );
+        // not with a stream over another number of variables
+        assert!(matches!(
+            BlendyProver::load(&path, BenchStream::<F64>::new(8)),
+            Err(SumcheckError::NumVariablesMismatch {
+                expected: 10,
+                found: 8
+            })
+        ));
+        // nor as a snapshot of another prover
+        assert!(matches!(
+            TimeProver::load(&path, stream),
+            Err(SumcheckError::Serialization(_))
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
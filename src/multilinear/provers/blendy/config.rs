@@ -1,6 +1,12 @@
 use ark_ff::Field;
 
-use crate::{prover::ProverConfig, streams::Stream};
+use crate::{
+    error::SumcheckError,
+    hypercube::Hypercube,
+    order_strategy::GraycodeOrder,
+    prover::ProverConfig,
+    streams::{check_streams, Stream},
+};
 
 pub struct BlendyProverConfig<F, S>
 where
@@ -26,6 +32,69 @@ where
             stream,
         }
     }
+    pub fn try_new(
+        claim: F,
+        num_stages: usize,
+        num_variables: usize,
+        stream: S,
+    ) -> Result<Self, SumcheckError> {
+        check_streams(ark_std::slice::from_ref(&stream), num_variables)?;
+        // every stage needs at least one round
+        if num_stages == 0 || num_stages > std::cmp::max(num_variables, 1) {
+            return Err(SumcheckError::InvalidNumStages {
+                num_stages,
+                num_variables,
+            });
+        }
+        Ok(Self::new(claim, num_stages, num_variables, stream))
+    }
+    /*
+     * Every stage is one pass over the stream, so the fastest configuration that fits
+     * is the one with the fewest passes (and then the least memory), or None if none fit
+     */
+    pub fn new_with_memory_budget(
+        claim: F,
+        num_variables: usize,
+        stream: S,
+        memory_budget: usize,
+        element_size: usize,
+    ) -> Option<Self> {
+        let num_stages = (1..=num_variables)
+            .filter(|num_stages| {
+                Self::predicted_peak_memory(num_variables, *num_stages, element_size)
+                    <= memory_budget
+            })
+            .min_by_key(|num_stages| {
+                (
+                    Self::predicted_num_passes(num_variables, *num_stages),
+                    Self::predicted_peak_memory(num_variables, *num_stages, element_size),
+                )
+            })?;
+        Some(Self::new(claim, num_stages, num_variables, stream))
+    }
+    pub fn num_passes(&self) -> usize {
+        Self::predicted_num_passes(self.num_variables, self.num_stages)
+    }
+    pub fn peak_memory(&self, element_size: usize) -> usize {
+        Self::predicted_peak_memory(self.num_variables, self.num_stages, element_size)
+    }
+    fn predicted_num_passes(num_variables: usize, num_stages: usize) -> usize {
+        // a stage starts every stage_size rounds, the last one may be short
+        num_variables.div_ceil(num_variables / num_stages)
+    }
+    fn predicted_peak_memory(
+        num_variables: usize,
+        num_stages: usize,
+        element_size: usize,
+    ) -> usize {
+        // sums, lag_polys and lag_polys_update, plus the partial sums of sum_update with more than one stage
+        let stage_len = Hypercube::<GraycodeOrder>::stop_value(num_variables / num_stages);
+        let num_tables = match num_stages {
+            1 => 3,
+            _ => 4,
+        };
+        num_tables * stage_len * element_size
+    }
 }
 
 impl<F: Field, S: Stream<F>> ProverConfig<F, S> for BlendyProverConfig<F, S> {
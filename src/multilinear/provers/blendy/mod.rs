@@ -1,4 +1,5 @@
 mod blendy;
+mod checkpoint;
 mod config;
 mod prover;
 
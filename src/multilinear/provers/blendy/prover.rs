@@ -71,13 +71,58 @@ where
 #[cfg(test)]
 mod tests {
     use crate::{
-        multilinear::BlendyProver,
+        multilinear::{BlendyProver, BlendyProverConfig, TimeProver},
+        prover::{Prover, ProverConfig},
         streams::MemoryStream,
-        tests::{multilinear::sanity_test, F19},
+        tests::{multilinear::sanity_test, BenchStream, F19},
+        Sumcheck,
     };
 
     #[test]
     fn sumcheck() {
         sanity_test::<F19, MemoryStream<F19>, BlendyProver<F19, MemoryStream<F19>>>();
     }
+
+    #[test]
+    fn memory_budget() {
+        let num_variables = 12;
+        let element_size = 8;
+        let stream: BenchStream<F19> = BenchStream::new(num_variables);
+        let claim = stream.claimed_sum;
+        let config = |memory_budget: usize| {
+            BlendyProverConfig::new_with_memory_budget(
+                claim,
+                num_variables,
+                stream.clone(),
+                memory_budget,
+                element_size,
+            )
+        };
+        // a large budget takes a single pass
+        let large = config(1 << 20).unwrap();
+        assert_eq!(large.num_stages, 1);
+        assert_eq!(large.num_passes(), 1);
+        // a smaller one takes more passes, but stays within the budget
+        let small = config(1 << 10).unwrap();
+        assert!(small.num_passes() > large.num_passes());
+        assert!(small.peak_memory(element_size) <= 1 << 10);
+        // and nothing fits in too small a budget
+        assert!(config(1).is_none());
+        // the transcript doesn't depend on the configuration
+        let blendy_prover_transcript =
+            Sumcheck::<F19>::prove::<BenchStream<F19>, BlendyProver<F19, BenchStream<F19>>>(
+                &mut BlendyProver::new(small),
+                &mut ark_std::test_rng(),
+            );
+        let time_prover_transcript =
+            Sumcheck::<F19>::prove::<BenchStream<F19>, TimeProver<F19, BenchStream<F19>>>(
+                &mut TimeProver::new(ProverConfig::default(claim, num_variables, stream)),
+                &mut ark_std::test_rng(),
+            );
+        assert!(blendy_prover_transcript.is_accepted);
+        assert_eq!(
+            time_prover_transcript.prover_messages,
+            blendy_prover_transcript.prover_messages
+        );
+    }
 }
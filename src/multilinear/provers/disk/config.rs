@@ -0,0 +1,103 @@
+use ark_ff::Field;
+use ark_std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{
+    error::SumcheckError,
+    prover::ProverConfig,
+    streams::{check_streams, FileSink, Stream},
+};
+
+// tables of up to 2^20 elements are kept in memory by default
+const DEFAULT_MEMORY_THRESHOLD: usize = 1 << 20;
+
+// so provers built with default don't share a scratch file
+static NUM_SCRATCH_FILES: AtomicUsize = AtomicUsize::new(0);
+
+pub struct DiskTimeProverConfig<F, S>
+where
+    F: Field,
+    S: Stream<F>,
+{
+    pub num_variables: usize,
+    pub claim: F,
+    pub stream: S,
+    // the most elements a folded table can have and still be kept in memory
+    pub memory_threshold: usize,
+    // sized for the first fold, none if every folded table fits in memory
+    pub scratch: Option<FileSink<F>>,
+}
+
+impl<F, S> DiskTimeProverConfig<F, S>
+where
+    F: Field,
+    S: Stream<F>,
+{
+    pub fn new(
+        claim: F,
+        num_variables: usize,
+        stream: S,
+        memory_threshold: usize,
+        scratch_path: String,
+    ) -> Self {
+        let scratch = Self::scratch(num_variables, memory_threshold, scratch_path).unwrap();
+        Self {
+            claim,
+            num_variables,
+            stream,
+            memory_threshold,
+            scratch,
+        }
+    }
+    pub fn try_new(
+        claim: F,
+        num_variables: usize,
+        stream: S,
+        memory_threshold: usize,
+        scratch_path: String,
+    ) -> Result<Self, SumcheckError> {
+        check_streams(ark_std::slice::from_ref(&stream), num_variables)?;
+        let scratch = Self::scratch(num_variables, memory_threshold, scratch_path)?;
+        Ok(Self {
+            claim,
+            num_variables,
+            stream,
+            memory_threshold,
+            scratch,
+        })
+    }
+    fn scratch(
+        num_variables: usize,
+        memory_threshold: usize,
+        scratch_path: String,
+    ) -> Result<Option<FileSink<F>>, SumcheckError> {
+        // the first fold leaves the largest table, with one variable less
+        match num_variables > 0 && (1 << (num_variables - 1)) > memory_threshold {
+            true => Ok(Some(FileSink::try_new(scratch_path, num_variables - 1)?)),
+            false => Ok(None),
+        }
+    }
+    pub fn default_scratch_path() -> String {
+        let scratch_file = NUM_SCRATCH_FILES.fetch_add(1, Ordering::Relaxed);
+        std::env::temp_dir()
+            .join(format!(
+                "disk_time_prover_{}_{}.bin",
+                std::process::id(),
+                scratch_file
+            ))
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+}
+
+impl<F: Field, S: Stream<F>> ProverConfig<F, S> for DiskTimeProverConfig<F, S> {
+    fn default(claim: F, num_variables: usize, stream: S) -> Self {
+        Self::new(
+            claim,
+            num_variables,
+            stream,
+            DEFAULT_MEMORY_THRESHOLD,
+            Self::default_scratch_path(),
+        )
+    }
+}
@@ -0,0 +1,150 @@
+use ark_ff::Field;
+use ark_std::{cfg_into_iter, vec::Vec};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{
+    parallel::fold_range,
+    streams::{
+        fold_evaluations, for_each_chunk, reduce_stream_evaluations, FileSink, Stream,
+        EVALUATIONS_CHUNK_SIZE,
+    },
+};
+
+// where the current table lives: the input itself before the first fold, then on disk, then in memory
+pub enum DiskTable<F: Field> {
+    Stream,
+    Disk(FileSink<F>),
+    Memory(Vec<F>),
+}
+
+/*
+ * TimeProver with the folded tables written back to a scratch file until they're
+ * small enough to keep in memory. The first fold reads the stream and writes half
+ * of it to disk, the next ones fold the scratch file in place, since the i-th
+ * element of the new table only depends on the i-th and (i + half)-th of the old one
+ */
+pub struct DiskTimeProver<F: Field, S: Stream<F>> {
+    pub claim: F,
+    pub current_round: usize,
+    pub evaluation_stream: S,
+    pub memory_threshold: usize,
+    pub num_variables: usize,
+    // the scratch file, until the first fold moves it to the table
+    pub scratch: Option<FileSink<F>>,
+    pub table: DiskTable<F>,
+}
+
+impl<F: Field, S: Stream<F>> DiskTimeProver<F, S> {
+    fn num_free_variables(&self) -> usize {
+        self.num_variables - self.current_round
+    }
+    fn evaluation(&self, point: usize) -> F {
+        match &self.table {
+            DiskTable::Stream => self.evaluation_stream.evaluation(point),
+            DiskTable::Disk(sink) => sink.evaluation(point),
+            DiskTable::Memory(evaluations) => evaluations[point],
+        }
+    }
+    pub fn vsbw_evaluate(&self) -> (F, F) {
+        // the first half of the table has the leftmost free variable at zero, the second at one
+        let half: usize = 1 << (self.num_free_variables() - 1);
+        if let DiskTable::Stream = self.table {
+            // the stream is read a chunk at a time
+            let sum = |start| {
+                fold_evaluations(
+                    &self.evaluation_stream,
+                    start,
+                    half,
+                    || F::ZERO,
+                    |sum, _, evaluation| sum + evaluation,
+                    |a, b| a + b,
+                )
+            };
+            return (sum(0), sum(half));
+        }
+        fold_range(
+            half,
+            || (F::ZERO, F::ZERO),
+            |(sum_0, sum_1), i| {
+                (
+                    sum_0 + self.evaluation(i),
+                    sum_1 + self.evaluation(i | half),
+                )
+            },
+            |(a_0, a_1), (b_0, b_1)| (a_0 + b_0, a_1 + b_1),
+        )
+    }
+    pub fn vsbw_reduce_evaluations(&mut self, verifier_message: F, verifier_message_hat: F) {
+        // the table before this fold has one more free variable
+        let half: usize = 1 << self.num_free_variables();
+
+        // small enough, from now on it's just TimeProver
+        if half <= self.memory_threshold {
+            if let DiskTable::Stream = self.table {
+                self.table = DiskTable::Memory(reduce_stream_evaluations(
+                    &self.evaluation_stream,
+                    verifier_message,
+                    verifier_message_hat,
+                ));
+                return;
+            }
+            let evaluations: Vec<F> = cfg_into_iter!(0..half)
+                .map(|i| {
+                    self.evaluation(i) * verifier_message_hat
+                        + self.evaluation(i | half) * verifier_message
+                })
+                .collect();
+            self.table = DiskTable::Memory(evaluations);
+            return;
+        }
+
+        // otherwise fold into the scratch file, which the config sized for the first fold
+        let is_first_fold = matches!(self.table, DiskTable::Stream);
+        let mut sink = match std::mem::replace(&mut self.table, DiskTable::Stream) {
+            DiskTable::Disk(sink) => sink,
+            _ => self.scratch.take().unwrap(),
+        };
+        match is_first_fold {
+            // the stream is read a chunk of each half at a time
+            true => {
+                let mut evaluations_1 = vec![F::ZERO; half.min(EVALUATIONS_CHUNK_SIZE)];
+                for_each_chunk(
+                    &self.evaluation_stream,
+                    0,
+                    half,
+                    &mut Vec::new(),
+                    |chunk_start, evaluations_0| {
+                        let evaluations_1 = &mut evaluations_1[..evaluations_0.len()];
+                        self.evaluation_stream
+                            .evaluations_into(chunk_start | half, evaluations_1);
+                        for (offset, (evaluation_0, evaluation_1)) in
+                            evaluations_0.iter().zip(evaluations_1.iter()).enumerate()
+                        {
+                            sink.set_evaluation(
+                                chunk_start + offset,
+                                *evaluation_0 * verifier_message_hat
+                                    + *evaluation_1 * verifier_message,
+                            );
+                        }
+                    },
+                );
+            }
+            false => {
+                for i in 0..half {
+                    let (evaluation_0, evaluation_1) =
+                        (sink.evaluation(i), sink.evaluation(i | half));
+                    sink.set_evaluation(
+                        i,
+                        evaluation_0 * verifier_message_hat + evaluation_1 * verifier_message,
+                    );
+                }
+            }
+        }
+        sink.truncate(self.num_free_variables());
+        self.table = DiskTable::Disk(sink);
+    }
+    pub fn total_rounds(&self) -> usize {
+        self.num_variables
+    }
+}
@@ -0,0 +1,6 @@
+mod config;
+mod disk;
+mod prover;
+
+pub use config::DiskTimeProverConfig;
+pub use disk::{DiskTable, DiskTimeProver};
@@ -0,0 +1,120 @@
+use ark_ff::Field;
+
+use crate::{
+    multilinear::{DiskTable, DiskTimeProver, DiskTimeProverConfig},
+    prover::Prover,
+    streams::Stream,
+};
+
+impl<F: Field, S: Stream<F>> Prover<F> for DiskTimeProver<F, S> {
+    type ProverConfig = DiskTimeProverConfig<F, S>;
+    type ProverMessage = Option<(F, F)>;
+    type VerifierMessage = Option<F>;
+
+    fn claim(&self) -> F {
+        self.claim
+    }
+
+    fn new(prover_config: Self::ProverConfig) -> Self {
+        Self {
+            claim: prover_config.claim,
+            current_round: 0,
+            evaluation_stream: prover_config.stream,
+            memory_threshold: prover_config.memory_threshold,
+            num_variables: prover_config.num_variables,
+            scratch: prover_config.scratch,
+            table: DiskTable::Stream,
+        }
+    }
+
+    fn next_message(&mut self, verifier_message: Option<F>) -> Option<(F, F)> {
+        // Ensure the current round is within bounds
+        if self.current_round >= self.total_rounds() {
+            return None;
+        }
+
+        // If it's not the first round, fold the table, on disk or in memory
+        if self.current_round != 0 {
+            self.vsbw_reduce_evaluations(
+                verifier_message.unwrap(),
+                F::ONE - verifier_message.unwrap(),
+            )
+        }
+
+        let sums = self.vsbw_evaluate();
+
+        // Increment the round counter
+        self.current_round += 1;
+
+        Some(sums)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        multilinear::{DiskTable, DiskTimeProver, DiskTimeProverConfig},
+        prover::Prover,
+        streams::{MemoryStream, Stream},
+        tests::{
+            multilinear::{parity_test, sanity_test},
+            BenchStream, F19, F64,
+        },
+    };
+
+    #[test]
+    fn sumcheck() {
+        sanity_test::<F19, MemoryStream<F19>, DiskTimeProver<F19, MemoryStream<F19>>>();
+    }
+
+    #[test]
+    fn parity_with_time_prover() {
+        let stream: BenchStream<F64> = BenchStream::new(12);
+        let claim = stream.claimed_sum;
+        let evaluations: Vec<F64> = (0..1 << 12).map(|point| stream.evaluation(point)).collect();
+        // all on disk, switching to memory halfway, and all in memory
+        for memory_threshold in [0, 1 << 6, 1 << 12] {
+            let mut prover = DiskTimeProver::new(DiskTimeProverConfig::new(
+                claim,
+                12,
+                stream.clone(),
+                memory_threshold,
+                DiskTimeProverConfig::<F64, BenchStream<F64>>::default_scratch_path(),
+            ));
+            parity_test::<F64, BenchStream<F64>, DiskTimeProver<F64, BenchStream<F64>>>(
+                evaluations.clone(),
+                &mut prover,
+            );
+            // the last table is in memory unless the threshold is zero
+            assert_eq!(
+                memory_threshold == 0,
+                matches!(prover.table, DiskTable::Disk(_))
+            );
+        }
+    }
+
+    #[test]
+    fn scratch_file() {
+        let stream: BenchStream<F64> = BenchStream::new(4);
+        let missing_dir = std::env::temp_dir()
+            .join("disk_time_prover_missing_dir")
+            .join("scratch.bin")
+            .to_str()
+            .unwrap()
+            .to_string();
+        // the scratch file is created with the config, so a bad path fails there
+        assert!(DiskTimeProverConfig::try_new(
+            stream.claimed_sum,
+            4,
+            stream.clone(),
+            0,
+            missing_dir.clone()
+        )
+        .is_err());
+        // and isn't needed when every folded table fits in memory
+        let config =
+            DiskTimeProverConfig::try_new(stream.claimed_sum, 4, stream, 1 << 3, missing_dir)
+                .unwrap();
+        assert!(config.scratch.is_none());
+    }
+}
@@ -0,0 +1,34 @@
+use ark_ff::Field;
+
+use crate::{
+    error::SumcheckError,
+    streams::{check_streams, Stream},
+};
+
+pub struct ExtensionProverConfig<F, S>
+where
+    F: Field,
+    S: Stream<F::BasePrimeField>,
+{
+    pub num_variables: usize,
+    pub claim: F,
+    pub stream: S,
+}
+
+impl<F, S> ExtensionProverConfig<F, S>
+where
+    F: Field,
+    S: Stream<F::BasePrimeField>,
+{
+    pub fn new(claim: F, num_variables: usize, stream: S) -> Self {
+        Self {
+            claim,
+            num_variables,
+            stream,
+        }
+    }
+    pub fn try_new(claim: F, num_variables: usize, stream: S) -> Result<Self, SumcheckError> {
+        check_streams(ark_std::slice::from_ref(&stream), num_variables)?;
+        Ok(Self::new(claim, num_variables, stream))
+    }
+}
@@ -0,0 +1,81 @@
+use ark_ff::{AdditiveGroup, Field};
+use ark_std::{cfg_into_iter, vec::Vec};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{
+    parallel::fold_range,
+    streams::{fold_evaluations, map_stream_halves, Stream},
+};
+
+/*
+ * Like TimeProver, but the stream is over the base field of the challenges: the
+ * first round only adds base field elements, and binding the first challenge lifts
+ * the table to the extension, where the remaining rounds happen. There's only this
+ * time variant: the lifted table holds 2^(n - 1) extension elements, so memory is
+ * linear in the size of the hypercube
+ */
+pub struct ExtensionProver<F: Field, S: Stream<F::BasePrimeField>> {
+    pub claim: F,
+    pub current_round: usize,
+    pub evaluations: Option<Vec<F>>,
+    pub evaluation_stream: S,
+    pub num_variables: usize,
+}
+
+impl<F: Field, S: Stream<F::BasePrimeField>> ExtensionProver<F, S> {
+    pub fn total_rounds(&self) -> usize {
+        self.num_variables
+    }
+    fn num_free_variables(&self) -> usize {
+        self.num_variables - self.current_round
+    }
+    pub fn base_evaluate(&self) -> (F, F) {
+        // the first half of the stream has the first variable set to 0, the second half to 1
+        let bitmask: usize = 1 << (self.num_free_variables() - 1);
+        // the stream is read a chunk at a time
+        let sum = |start| {
+            fold_evaluations(
+                &self.evaluation_stream,
+                start,
+                bitmask,
+                || F::BasePrimeField::ZERO,
+                |sum, _, evaluation| sum + evaluation,
+                |a, b| a + b,
+            )
+        };
+        let (sum_0, sum_1) = (sum(0), sum(bitmask));
+        (
+            F::from_base_prime_field(sum_0),
+            F::from_base_prime_field(sum_1),
+        )
+    }
+    pub fn vsbw_evaluate(&self) -> (F, F) {
+        let evaluations = self.evaluations.as_ref().unwrap();
+        let bitmask: usize = 1 << (self.num_free_variables() - 1);
+        fold_range(
+            bitmask,
+            || (F::ZERO, F::ZERO),
+            |(sum_0, sum_1), i| (sum_0 + evaluations[i], sum_1 + evaluations[i | bitmask]),
+            |(a_0, a_1), (b_0, b_1)| (a_0 + b_0, a_1 + b_1),
+        )
+    }
+    pub fn lift_evaluations(&mut self, verifier_message: F) {
+        // p(r) = p(0) + r * (p(1) - p(0)), where only the multiplication is in the extension
+        let evaluations: Vec<F> = map_stream_halves(&self.evaluation_stream, |zero, one| {
+            F::from_base_prime_field(zero) + verifier_message.mul_by_base_prime_field(&(one - zero))
+        });
+        self.evaluations = Some(evaluations);
+    }
+    pub fn vsbw_reduce_evaluations(&mut self, verifier_message: F) {
+        let evaluations = self.evaluations.as_ref().unwrap();
+        let setbit: usize = 1 << self.num_free_variables();
+        let evaluations: Vec<F> = cfg_into_iter!(0..setbit)
+            .map(|i0| {
+                let zero = evaluations[i0];
+                zero + verifier_message * (evaluations[i0 | setbit] - zero)
+            })
+            .collect();
+        self.evaluations = Some(evaluations);
+    }
+}
@@ -0,0 +1,6 @@
+mod config;
+mod extension;
+mod prover;
+
+pub use config::ExtensionProverConfig;
+pub use extension::ExtensionProver;
@@ -0,0 +1,112 @@
+use ark_ff::Field;
+
+use crate::{
+    multilinear::{ExtensionProver, ExtensionProverConfig},
+    prover::Prover,
+    streams::Stream,
+};
+
+impl<F: Field, S: Stream<F::BasePrimeField>> Prover<F> for ExtensionProver<F, S> {
+    type ProverConfig = ExtensionProverConfig<F, S>;
+    type ProverMessage = Option<(F, F)>;
+    type VerifierMessage = Option<F>;
+
+    fn claim(&self) -> F {
+        self.claim
+    }
+
+    fn new(prover_config: Self::ProverConfig) -> Self {
+        Self {
+            claim: prover_config.claim,
+            current_round: 0,
+            evaluations: None,
+            evaluation_stream: prover_config.stream,
+            num_variables: prover_config.num_variables,
+        }
+    }
+
+    fn next_message(&mut self, verifier_message: Option<F>) -> Option<(F, F)> {
+        // Ensure the current round is within bounds
+        if self.current_round >= self.total_rounds() {
+            return None;
+        }
+
+        // the first round stays in the base field, the first challenge moves everything to the extension
+        let sums = match self.current_round {
+            0 => self.base_evaluate(),
+            1 => {
+                self.lift_evaluations(verifier_message.unwrap());
+                self.vsbw_evaluate()
+            }
+            _ => {
+                self.vsbw_reduce_evaluations(verifier_message.unwrap());
+                self.vsbw_evaluate()
+            }
+        };
+
+        // Increment the round counter
+        self.current_round += 1;
+
+        Some(sums)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{Field, UniformRand};
+
+    use crate::{
+        multilinear::{ExtensionProver, ExtensionProverConfig, TimeProver, TimeProverConfig},
+        prover::Prover,
+        streams::MemoryStream,
+        tests::{F64Ext2, F64},
+        Sumcheck,
+    };
+
+    #[test]
+    fn parity_with_time_prover() {
+        let num_variables = 8;
+        let mut rng = ark_std::test_rng();
+        let evaluations: Vec<F64> = (0..1 << num_variables)
+            .map(|_| F64::rand(&mut rng))
+            .collect();
+        let claim = F64Ext2::from_base_prime_field(evaluations.iter().sum());
+
+        // the same evaluations, once in the base field and once lifted to the extension
+        let base_stream = MemoryStream::new(evaluations.clone());
+        let lifted_stream = MemoryStream::new(
+            evaluations
+                .into_iter()
+                .map(F64Ext2::from_base_prime_field)
+                .collect(),
+        );
+        let extension_transcript = Sumcheck::<F64Ext2>::prove::<
+            MemoryStream<F64Ext2>,
+            ExtensionProver<F64Ext2, MemoryStream<F64>>,
+        >(
+            &mut ExtensionProver::new(ExtensionProverConfig::new(
+                claim,
+                num_variables,
+                base_stream,
+            )),
+            &mut ark_std::test_rng(),
+        );
+        let time_transcript = Sumcheck::<F64Ext2>::prove::<
+            MemoryStream<F64Ext2>,
+            TimeProver<F64Ext2, MemoryStream<F64Ext2>>,
+        >(
+            &mut TimeProver::new(TimeProverConfig::new(claim, num_variables, lifted_stream)),
+            &mut ark_std::test_rng(),
+        );
+
+        assert!(extension_transcript.is_accepted);
+        assert_eq!(
+            extension_transcript.prover_messages,
+            time_transcript.prover_messages
+        );
+        assert_eq!(
+            extension_transcript.reduced_claim,
+            time_transcript.reduced_claim
+        );
+    }
+}
@@ -1,3 +1,5 @@
 pub mod blendy;
+pub mod disk;
+pub mod extension;
 pub mod space;
 pub mod time;
@@ -1,8 +1,11 @@
 use ark_ff::Field;
 
 use crate::{
-    hypercube::Hypercube, interpolation::LagrangePolynomial, order_strategy::GraycodeOrder,
-    streams::Stream,
+    hypercube::{Hypercube, HypercubeMember},
+    interpolation::LagrangePolynomial,
+    order_strategy::GraycodeOrder,
+    parallel::fold_range,
+    streams::{for_each_chunk, Stream},
 };
 
 pub struct SpaceProver<F: Field, S: Stream<F>> {
@@ -16,10 +19,6 @@ pub struct SpaceProver<F: Field, S: Stream<F>> {
 
 impl<F: Field, S: Stream<F>> SpaceProver<F, S> {
     pub fn cty_evaluate(&self) -> (F, F) {
-        // Initialize accumulators for sum_0 and sum_1
-        let mut sum_0: F = F::ZERO;
-        let mut sum_1: F = F::ZERO;
-
         // Create a bitmask for the number of free variables
         let bitmask: usize = 1 << (self.num_free_variables() - 1);
 
@@ -27,38 +26,74 @@ impl<F: Field, S: Stream<F>> SpaceProver<F, S> {
         let num_vars_outer_loop = self.current_round;
         let num_vars_inner_loop = self.num_variables - num_vars_outer_loop;
 
-        // Outer loop over a subset of variables
-        for (index_outer, outer) in Hypercube::<GraycodeOrder>::new(num_vars_outer_loop) {
-            // Calculate the weight using Lagrange polynomial
-            let lag_poly: F = LagrangePolynomial::<F, GraycodeOrder>::lag_poly(
-                self.verifier_messages.clone(),
-                self.verifier_message_hats.clone(),
-                outer,
-            );
-
-            if lag_poly == F::ZERO {
-                // in this case the inner loop does nothing
-                continue;
+        // a sparse stream only needs its non-zeros, which come sorted so the weight changes with the outer index only
+        if let Some(sparse_len) = self.evaluation_stream.sparse_len() {
+            let (mut sum_0, mut sum_1) = (F::ZERO, F::ZERO);
+            let mut lag_poly: Option<(usize, F)> = None;
+            for position in 0..sparse_len {
+                let (evaluation_index, value) = self.evaluation_stream.sparse_entry(position);
+                let index_outer = evaluation_index >> num_vars_inner_loop;
+                let weight = match lag_poly {
+                    Some((index, weight)) if index == index_outer => weight,
+                    _ => {
+                        let weight = LagrangePolynomial::<F, GraycodeOrder>::lag_poly(
+                            &self.verifier_messages,
+                            &self.verifier_message_hats,
+                            HypercubeMember::new(num_vars_outer_loop, index_outer),
+                        );
+                        lag_poly = Some((index_outer, weight));
+                        weight
+                    }
+                };
+                match evaluation_index & bitmask != 0 {
+                    false => sum_0 += value * weight,
+                    true => sum_1 += value * weight,
+                }
             }
+            return (sum_0, sum_1);
+        }
 
-            // Inner loop over all possible evaluations for the remaining variables
-            for (index_inner, _inner) in Hypercube::<GraycodeOrder>::new(num_vars_inner_loop) {
-                // Calculate the evaluation index
-                let evaluation_index = index_outer << num_vars_inner_loop | index_inner;
-
-                // Check if the bit at the position specified by the bitmask is set
-                let is_set: bool = (evaluation_index & bitmask) != 0;
+        // Outer loop over a subset of variables, accumulating sum_0 and sum_1, with one read buffer per thread
+        let (sum_0, sum_1, _) = fold_range(
+            Hypercube::<GraycodeOrder>::stop_value(num_vars_outer_loop),
+            || (F::ZERO, F::ZERO, Vec::new()),
+            |(mut sum_0, mut sum_1, mut buffer), index_outer| {
+                // Calculate the weight using Lagrange polynomial
+                let lag_poly: F = LagrangePolynomial::<F, GraycodeOrder>::lag_poly(
+                    &self.verifier_messages,
+                    &self.verifier_message_hats,
+                    HypercubeMember::new(num_vars_outer_loop, index_outer),
+                );
 
-                // Use match to accumulate the appropriate value based on whether the bit is set or not
-                let inner_sum = self.evaluation_stream.evaluation(evaluation_index) * lag_poly;
-                match is_set {
-                    false => sum_0 += inner_sum,
-                    true => sum_1 += inner_sum,
+                if lag_poly == F::ZERO {
+                    // in this case the inner loop does nothing
+                    return (sum_0, sum_1, buffer);
                 }
-            }
-        }
 
-        // Return the accumulated sums
+                // Inner loop over all possible evaluations for the remaining variables, read a chunk at a time
+                for_each_chunk(
+                    &self.evaluation_stream,
+                    index_outer << num_vars_inner_loop,
+                    1 << num_vars_inner_loop,
+                    &mut buffer,
+                    |chunk_start, evaluations| {
+                        for (offset, evaluation) in evaluations.iter().enumerate() {
+                            // Check if the bit at the position specified by the bitmask is set
+                            let is_set: bool = ((chunk_start + offset) & bitmask) != 0;
+
+                            // Use match to accumulate the appropriate value based on whether the bit is set or not
+                            let inner_sum = *evaluation * lag_poly;
+                            match is_set {
+                                false => sum_0 += inner_sum,
+                                true => sum_1 += inner_sum,
+                            }
+                        }
+                    },
+                );
+                (sum_0, sum_1, buffer)
+            },
+            |(a_0, a_1, buffer), (b_0, b_1, _)| (a_0 + b_0, a_1 + b_1, buffer),
+        );
         (sum_0, sum_1)
     }
     pub fn num_free_variables(&self) -> usize {
@@ -0,0 +1,73 @@
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{
+    io::{Read, Write},
+    vec::Vec,
+};
+
+use crate::{
+    error::SumcheckError,
+    multilinear::TimeProver,
+    prover::{read_header, write_header, Checkpoint},
+    streams::Stream,
+};
+
+// the stream is only read in the first two rounds, after that the table is everything
+impl<F: Field, S: Stream<F>> Checkpoint for TimeProver<F, S> {
+    const KIND: u8 = 0;
+    type Streams = S;
+
+    fn checkpoint<W: Write>(&self, mut writer: W) -> Result<(), SumcheckError> {
+        write_header(&mut writer, Self::KIND)?;
+        self.claim.serialize_uncompressed(&mut writer)?;
+        self.current_round.serialize_uncompressed(&mut writer)?;
+        self.num_variables.serialize_uncompressed(&mut writer)?;
+        self.evaluations.serialize_uncompressed(&mut writer)?;
+        self.sparse_evaluations
+            .serialize_uncompressed(&mut writer)?;
+        Ok(())
+    }
+
+    fn resume<R: Read>(mut reader: R, stream: S) -> Result<Self, SumcheckError> {
+        read_header(&mut reader, Self::KIND)?;
+        let claim = F::deserialize_uncompressed(&mut reader)?;
+        let current_round = usize::deserialize_uncompressed(&mut reader)?;
+        let num_variables = usize::deserialize_uncompressed(&mut reader)?;
+        let evaluations = Option::<Vec<F>>::deserialize_uncompressed(&mut reader)?;
+        let sparse_evaluations = Option::<Vec<(usize, F)>>::deserialize_uncompressed(&mut reader)?;
+        if stream.num_variables() != num_variables {
+            return Err(SumcheckError::NumVariablesMismatch {
+                expected: num_variables,
+                found: stream.num_variables(),
+            });
+        }
+        Ok(Self {
+            claim,
+            current_round,
+            evaluations,
+            evaluation_stream: stream,
+            num_variables,
+            sparse_evaluations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        multilinear::{TimeProver, TimeProverConfig},
+        prover::Prover,
+        tests::{checkpoint_test, BenchStream, F64},
+    };
+
+    #[test]
+    fn resume() {
+        let stream: BenchStream<F64> = BenchStream::new(8);
+        let claim = stream.claimed_sum;
+        checkpoint_test::<F64, TimeProver<F64, BenchStream<F64>>>(
+            8,
+            || TimeProver::new(TimeProverConfig::new(claim, 8, stream.clone())),
+            || stream.clone(),
+        );
+    }
+}
@@ -1,6 +1,10 @@
 use ark_ff::Field;
 
-use crate::{prover::ProverConfig, streams::Stream};
+use crate::{
+    error::SumcheckError,
+    prover::ProverConfig,
+    streams::{check_streams, Stream},
+};
 
 pub struct TimeProverConfig<F, S>
 where
@@ -24,6 +28,10 @@ where
             stream,
         }
     }
+    pub fn try_new(claim: F, num_variables: usize, stream: S) -> Result<Self, SumcheckError> {
+        check_streams(ark_std::slice::from_ref(&stream), num_variables)?;
+        Ok(Self::new(claim, num_variables, stream))
+    }
 }
 
 impl<F: Field, S: Stream<F>> ProverConfig<F, S> for TimeProverConfig<F, S> {
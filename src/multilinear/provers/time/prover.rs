@@ -22,6 +22,7 @@ impl<F: Field, S: Stream<F>> Prover<F> for TimeProver<F, S> {
             evaluations: None,
             evaluation_stream: prover_config.stream,
             num_variables: prover_config.num_variables,
+            sparse_evaluations: None,
         }
     }
 
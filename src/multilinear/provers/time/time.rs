@@ -1,7 +1,12 @@
 use ark_ff::Field;
-use ark_std::vec::Vec;
+use ark_std::{cfg_into_iter, vec::Vec};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
-use crate::streams::Stream;
+use crate::{
+    parallel::fold_range,
+    streams::{fold_evaluations, reduce_stream_evaluations, Stream},
+};
 
 pub struct TimeProver<F: Field, S: Stream<F>> {
     pub claim: F,
@@ -9,90 +14,166 @@ pub struct TimeProver<F: Field, S: Stream<F>> {
     pub evaluations: Option<Vec<F>>,
     pub evaluation_stream: S, // Keep this for now, case we can do some small optimizations of first round etc
     pub num_variables: usize,
+    // the folded table of a sparse stream while it stays sparse, it's used instead of evaluations
+    pub sparse_evaluations: Option<Vec<(usize, F)>>,
 }
 
 impl<F: Field, S: Stream<F>> TimeProver<F, S> {
     fn num_free_variables(&self) -> usize {
         self.num_variables - self.current_round
     }
+    // the non-zero entries of the current table, if it's sparse
+    fn sparse_len(&self) -> Option<usize> {
+        match (&self.sparse_evaluations, &self.evaluations) {
+            (Some(entries), _) => Some(entries.len()),
+            (None, None) => self.evaluation_stream.sparse_len(),
+            (None, Some(_)) => None,
+        }
+    }
+    fn sparse_entry(&self, position: usize) -> (usize, F) {
+        match &self.sparse_evaluations {
+            Some(entries) => entries[position],
+            None => self.evaluation_stream.sparse_entry(position),
+        }
+    }
     pub fn vsbw_evaluate(&self) -> (F, F) {
-        // Initialize accumulators for sum_0 and sum_1
-        let mut sum_0 = F::ZERO;
-        let mut sum_1 = F::ZERO;
-
         // Calculate the bitmask for the number of free variables
         let bitmask: usize = 1 << (self.num_free_variables() - 1);
 
-        // Determine the length of evaluations to iterate through
-        let evaluations_len = match &self.evaluations {
-            Some(evaluations) => evaluations.len(),
-            None => 2usize.pow(self.evaluation_stream.num_variables() as u32),
-        };
-
-        // Iterate through evaluations
-        for i in 0..evaluations_len {
-            // Check if the bit at the position specified by the bitmask is set
-            let is_set: bool = (i & bitmask) != 0;
+        // a sparse table only needs its non-zeros
+        if let Some(sparse_len) = self.sparse_len() {
+            return fold_range(
+                sparse_len,
+                || (F::ZERO, F::ZERO),
+                |(sum_0, sum_1), position| {
+                    let (index, value) = self.sparse_entry(position);
+                    match index & bitmask != 0 {
+                        false => (sum_0 + value, sum_1),
+                        true => (sum_0, sum_1 + value),
+                    }
+                },
+                |(a_0, a_1), (b_0, b_1)| (a_0 + b_0, a_1 + b_1),
+            );
+        }
 
-            // Get the point evaluation for the current index
-            let point_evaluation = match &self.evaluations {
-                Some(evaluations) => evaluations[i],
-                None => self.evaluation_stream.evaluation(i),
+        // Iterate through evaluations, accumulating sum_0 and sum_1
+        let fold = |(sum_0, sum_1): (F, F), i: usize, point_evaluation: F| {
+            // Accumulate the value based on whether the bit at the bitmask is set or not
+            match (i & bitmask) != 0 {
+                false => (sum_0 + point_evaluation, sum_1),
+                true => (sum_0, sum_1 + point_evaluation),
+            }
+        };
+        let reduce = |(a_0, a_1): (F, F), (b_0, b_1): (F, F)| (a_0 + b_0, a_1 + b_1);
+        match &self.evaluations {
+            Some(evaluations) => fold_range(
+                evaluations.len(),
+                || (F::ZERO, F::ZERO),
+                |sums, i| fold(sums, i, evaluations[i]),
+                reduce,
+            ),
+            // the stream is read a chunk at a time
+            None => fold_evaluations(
+                &self.evaluation_stream,
+                0,
+                1 << self.evaluation_stream.num_variables(),
+                || (F::ZERO, F::ZERO),
+                fold,
+                reduce,
+            ),
+        }
+    }
+    /*
+     * Folds a sparse table: the entries with the leftmost bit at zero are scaled by
+     * 1 - r, the ones with it at one are scaled by r and moved down, and both halves
+     * are still sorted so they're merged in one pass. Once the table is at least half
+     * full it's cheaper as a dense one
+     */
+    fn sparse_reduce_evaluations(
+        &mut self,
+        sparse_len: usize,
+        verifier_message: F,
+        verifier_message_hat: F,
+    ) {
+        let setbit: usize = 1 << self.num_free_variables();
+        let split = (0..sparse_len)
+            .position(|position| self.sparse_entry(position).0 & setbit != 0)
+            .unwrap_or(sparse_len);
+        let mut entries: Vec<(usize, F)> = Vec::with_capacity(sparse_len);
+        let (mut low, mut high) = (0, split);
+        while low < split || high < sparse_len {
+            let low_entry = (low < split).then(|| self.sparse_entry(low));
+            let high_entry = (high < sparse_len).then(|| {
+                let (index, value) = self.sparse_entry(high);
+                (index ^ setbit, value)
+            });
+            let entry = match (low_entry, high_entry) {
+                (Some((i0, v0)), Some((i1, v1))) if i0 == i1 => {
+                    low += 1;
+                    high += 1;
+                    (i0, v0 * verifier_message_hat + v1 * verifier_message)
+                }
+                (Some((i0, v0)), Some((i1, _))) if i0 < i1 => {
+                    low += 1;
+                    (i0, v0 * verifier_message_hat)
+                }
+                (Some((i0, v0)), None) => {
+                    low += 1;
+                    (i0, v0 * verifier_message_hat)
+                }
+                (_, Some((i1, v1))) => {
+                    high += 1;
+                    (i1, v1 * verifier_message)
+                }
+                (None, None) => unreachable!(),
             };
-
-            // Accumulate the value based on whether the bit is set or not
-            match is_set {
-                false => sum_0 += point_evaluation,
-                true => sum_1 += point_evaluation,
+            if !entry.1.is_zero() {
+                entries.push(entry);
             }
         }
 
-        // Return the accumulated sums
-        (sum_0, sum_1)
+        if 2 * entries.len() >= setbit {
+            let mut evaluations = vec![F::ZERO; setbit];
+            for (index, value) in entries {
+                evaluations[index] = value;
+            }
+            self.evaluations = Some(evaluations);
+            self.sparse_evaluations = None;
+        } else {
+            self.sparse_evaluations = Some(entries);
+        }
     }
     pub fn vsbw_reduce_evaluations(&mut self, verifier_message: F, verifier_message_hat: F) {
-        // Clone or initialize the evaluations vector
-        let mut evaluations = match &self.evaluations {
-            Some(evaluations) => evaluations.clone(),
-            None => vec![
-                F::ZERO;
-                2usize.pow(self.evaluation_stream.num_variables().try_into().unwrap()) / 2
-            ],
-        };
+        if let Some(sparse_len) = self.sparse_len() {
+            self.sparse_reduce_evaluations(sparse_len, verifier_message, verifier_message_hat);
+            return;
+        }
 
-        // Determine the length of evaluations to iterate through
-        let evaluations_len = match &self.evaluations {
-            Some(evaluations) => evaluations.len() / 2,
-            None => evaluations.len(),
+        // The first fold reads the stream a chunk at a time
+        let Some(evaluations) = &self.evaluations else {
+            self.evaluations = Some(reduce_stream_evaluations(
+                &self.evaluation_stream,
+                verifier_message,
+                verifier_message_hat,
+            ));
+            return;
         };
 
         // Calculate what bit needs to be set to index the second half of the last round's evaluations
         let setbit: usize = 1 << self.num_free_variables();
 
         // Iterate through pairs of evaluations
-        for i0 in 0..evaluations_len {
-            let i1 = i0 | setbit;
-
-            // Get point evaluations for indices i0 and i1
-            let point_evaluation_i0 = match &self.evaluations {
-                None => self.evaluation_stream.evaluation(i0),
-                Some(evaluations) => evaluations[i0],
-            };
-            let point_evaluation_i1 = match &self.evaluations {
-                None => self.evaluation_stream.evaluation(i1),
-                Some(evaluations) => evaluations[i1],
-            };
-
-            // Update the i0-th evaluation based on the reduction operation
-            evaluations[i0] =
-                point_evaluation_i0 * verifier_message_hat + point_evaluation_i1 * verifier_message;
-        }
+        let evaluations: Vec<F> = cfg_into_iter!(0..evaluations.len() / 2)
+            .map(|i0| {
+                let i1 = i0 | setbit;
 
-        // Truncate the evaluations vector to the correct length
-        evaluations.truncate(evaluations_len);
+                // The i0-th evaluation based on the reduction operation
+                evaluations[i0] * verifier_message_hat + evaluations[i1] * verifier_message
+            })
+            .collect();
 
         // Update the internal state with the new evaluations vector
-        self.evaluations = Some(evaluations.clone());
+        self.evaluations = Some(evaluations);
     }
     pub fn total_rounds(&self) -> usize {
         self.num_variables
@@ -1,12 +1,22 @@
 use ark_ff::Field;
 use ark_std::{rand::Rng, vec::Vec};
 
-use crate::{prover::Prover, streams::Stream};
+use crate::{
+    error::SumcheckError,
+    messages::{CompressedProof, Nodes, RoundPolynomial},
+    multilinear::SumcheckVerifier,
+    prover::Prover,
+    streams::Stream,
+    transcript::Transcript,
+    verifier::Verifier,
+};
 
 #[derive(Debug)]
 pub struct Sumcheck<F: Field> {
     pub prover_messages: Vec<(F, F)>,
     pub verifier_messages: Vec<F>,
+    // None when the transcript was rejected
+    pub reduced_claim: Option<F>,
     pub is_accepted: bool,
 }
 
@@ -15,6 +25,68 @@ impl<F: Field> Sumcheck<F> {
     where
         S: Stream<F>,
         P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F)>>,
+    {
+        Self::run(prover, |_| F::rand(rng))
+    }
+
+    pub fn prove_non_interactive<S, P, T>(prover: &mut P, transcript: &mut T) -> Self
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F)>>,
+        T: Transcript<F>,
+    {
+        // Fiat-Shamir: each verifier message is derived from the claim and all prover messages so far
+        transcript.absorb(&[prover.claim()]);
+        Self::run(prover, |message| {
+            SumcheckVerifier::<F>::absorb_prover_message(transcript, message);
+            transcript.challenge()
+        })
+    }
+
+    // like prove, but a rejected transcript is an error
+    pub fn try_prove<S, P>(prover: &mut P, rng: &mut impl Rng) -> Result<Self, SumcheckError>
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F)>>,
+    {
+        Self::prove::<S, P>(prover, rng).accepted()
+    }
+
+    pub fn try_prove_non_interactive<S, P, T>(
+        prover: &mut P,
+        transcript: &mut T,
+    ) -> Result<Self, SumcheckError>
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F)>>,
+        T: Transcript<F>,
+    {
+        Self::prove_non_interactive::<S, P, T>(prover, transcript).accepted()
+    }
+
+    // the prover messages in coefficient form, e.g. for a verifier with another message format
+    pub fn round_polynomials(&self) -> Vec<RoundPolynomial<F>> {
+        self.prover_messages
+            .iter()
+            .map(|message| RoundPolynomial::from(*message))
+            .collect()
+    }
+    // the proof as the compressed round polynomials at the given nodes
+    pub fn compressed_proof(&self, nodes: Nodes<F>) -> Result<CompressedProof<F>, SumcheckError> {
+        CompressedProof::new(&self.round_polynomials(), nodes)
+    }
+
+    fn accepted(self) -> Result<Self, SumcheckError> {
+        match self.is_accepted {
+            true => Ok(self),
+            // the rejected message is the last one
+            false => Err(SumcheckError::Rejected(self.prover_messages.len() - 1)),
+        }
+    }
+
+    fn run<P>(prover: &mut P, mut next_verifier_message: impl FnMut(&(F, F)) -> F) -> Self
+    where
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F)>>,
     {
         // Initialize vectors to store prover and verifier messages
         let mut prover_messages: Vec<(F, F)> = vec![];
@@ -46,13 +118,29 @@ impl<F: Field> Sumcheck<F> {
                 break;
             }
 
-            verifier_message = Some(F::rand(rng));
+            verifier_message = Some(next_verifier_message(prover_messages.last().unwrap()));
         }
 
+        // The last verifier message reduces the claim to the value f(r_1, ..., r_n) should take
+        let reduced_claim = match (is_accepted, prover_messages.last(), verifier_message) {
+            (true, Some(last_prover_message), Some(last_verifier_message)) => {
+                verifier_messages.push(last_verifier_message);
+                Some(
+                    last_prover_message.0
+                        - (last_prover_message.0 - last_prover_message.1) * last_verifier_message,
+                )
+            }
+            // there were no rounds at all
+            (true, _, _) => Some(prover.claim()),
+            // rejected, so there is nothing to reduce
+            (false, _, _) => None,
+        };
+
         // Return a Sumcheck struct with the collected messages and acceptance status
         Sumcheck {
             prover_messages,
             verifier_messages,
+            reduced_claim,
             is_accepted,
         }
     }
@@ -62,10 +150,18 @@ impl<F: Field> Sumcheck<F> {
 mod tests {
     use super::Sumcheck;
     use crate::{
-        multilinear::{BlendyProver, BlendyProverConfig, TimeProver},
+        error::SumcheckError,
+        multilinear::{
+            BlendyProver, BlendyProverConfig, SpaceProver, SpaceProverConfig, SumcheckVerifier,
+            SumcheckVerifierConfig, TimeProver, TimeProverConfig,
+        },
         prover::{Prover, ProverConfig},
+        streams::multivariate_evaluation,
         tests::{BenchStream, F19},
+        transcript::HashTranscript,
+        verifier::Verifier,
     };
+    use sha2::Sha256;
 
     #[test]
     fn algorithm_consistency() {
@@ -99,4 +195,152 @@ mod tests {
             blendy_prover_transcript.prover_messages
         );
     }
+
+    #[test]
+    fn final_oracle_check() {
+        // take an evaluation stream
+        let evaluation_stream: BenchStream<F19> = BenchStream::new(10);
+        let claim = evaluation_stream.claimed_sum;
+        // run the protocol
+        let transcript =
+            Sumcheck::<F19>::prove::<BenchStream<F19>, SpaceProver<F19, BenchStream<F19>>>(
+                &mut SpaceProver::new(SpaceProverConfig::new(claim, 10, evaluation_stream.clone())),
+                &mut ark_std::test_rng(),
+            );
+        assert!(transcript.is_accepted);
+        assert_eq!(transcript.verifier_messages.len(), 10);
+        // the reduced claim should be f(r_1, ..., r_n)
+        assert_eq!(
+            transcript.reduced_claim,
+            Some(multivariate_evaluation(
+                evaluation_stream,
+                &transcript.verifier_messages
+            ))
+        );
+        // and the standalone verifier should agree
+        assert_eq!(
+            SumcheckVerifier::new(SumcheckVerifierConfig::new(claim, 10))
+                .verify(&transcript.prover_messages, &transcript.verifier_messages),
+            Some((
+                transcript.verifier_messages.clone(),
+                transcript.reduced_claim.unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn rejected_claim() {
+        let evaluation_stream: BenchStream<F19> = BenchStream::new(10);
+        let claim = evaluation_stream.claimed_sum + F19::from(1);
+        let transcript =
+            Sumcheck::<F19>::prove::<BenchStream<F19>, TimeProver<F19, BenchStream<F19>>>(
+                &mut TimeProver::new(TimeProverConfig::new(claim, 10, evaluation_stream)),
+                &mut ark_std::test_rng(),
+            );
+        // a rejection doesn't reduce the claim to anything
+        assert!(!transcript.is_accepted);
+        assert_eq!(transcript.reduced_claim, None);
+    }
+
+    #[test]
+    fn non_interactive() {
+        // take an evaluation stream
+        let evaluation_stream: BenchStream<F19> = BenchStream::new(10);
+        let claim = evaluation_stream.claimed_sum;
+        // run the protocol with two different provers
+        let time_prover_transcript = Sumcheck::<F19>::prove_non_interactive::<
+            BenchStream<F19>,
+            TimeProver<F19, BenchStream<F19>>,
+            HashTranscript<F19, Sha256>,
+        >(
+            &mut TimeProver::new(ProverConfig::default(claim, 10, evaluation_stream.clone())),
+            &mut HashTranscript::new(b"sumcheck"),
+        );
+        let blendy_prover_transcript = Sumcheck::<F19>::prove_non_interactive::<
+            BenchStream<F19>,
+            BlendyProver<F19, BenchStream<F19>>,
+            HashTranscript<F19, Sha256>,
+        >(
+            &mut BlendyProver::new(BlendyProverConfig::new(
+                claim,
+                3,
+                10,
+                evaluation_stream.clone(),
+            )),
+            &mut HashTranscript::new(b"sumcheck"),
+        );
+        assert!(time_prover_transcript.is_accepted);
+        // the transcript only depends on the messages, so should be identical
+        assert_eq!(
+            time_prover_transcript.prover_messages,
+            blendy_prover_transcript.prover_messages
+        );
+        assert_eq!(
+            time_prover_transcript.verifier_messages,
+            blendy_prover_transcript.verifier_messages
+        );
+        // the verifier can rederive the verifier messages
+        assert_eq!(
+            SumcheckVerifier::new(SumcheckVerifierConfig::new(claim, 10)).verify_non_interactive(
+                &time_prover_transcript.prover_messages,
+                &mut HashTranscript::<F19, Sha256>::new(b"sumcheck"),
+            ),
+            Some((
+                time_prover_transcript.verifier_messages.clone(),
+                time_prover_transcript.reduced_claim.unwrap()
+            ))
+        );
+        assert_eq!(
+            time_prover_transcript.reduced_claim,
+            Some(multivariate_evaluation(
+                evaluation_stream,
+                &time_prover_transcript.verifier_messages
+            ))
+        );
+        // but not under a different domain separator
+        assert!(
+            SumcheckVerifier::new(SumcheckVerifierConfig::new(claim, 10))
+                .verify_non_interactive(
+                    &time_prover_transcript.prover_messages,
+                    &mut HashTranscript::<F19, Sha256>::new(b"other"),
+                )
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn fallible() {
+        let evaluation_stream: BenchStream<F19> = BenchStream::new(10);
+        let claim = evaluation_stream.claimed_sum;
+        // a stream with a different number of variables is caught by the config
+        assert!(matches!(
+            TimeProverConfig::try_new(claim, 9, evaluation_stream.clone()),
+            Err(SumcheckError::NumVariablesMismatch {
+                expected: 9,
+                found: 10
+            })
+        ));
+        assert!(matches!(
+            BlendyProverConfig::try_new(claim, 11, 10, evaluation_stream.clone()),
+            Err(SumcheckError::InvalidNumStages { .. })
+        ));
+        // and a wrong claim by the runner
+        let config = TimeProverConfig::try_new(claim + F19::from(1), 10, evaluation_stream.clone());
+        assert!(matches!(
+            Sumcheck::<F19>::try_prove::<BenchStream<F19>, TimeProver<F19, BenchStream<F19>>>(
+                &mut TimeProver::new(config.unwrap()),
+                &mut ark_std::test_rng(),
+            ),
+            Err(SumcheckError::Rejected(0))
+        ));
+        let config = TimeProverConfig::try_new(claim, 10, evaluation_stream);
+        assert!(
+            Sumcheck::<F19>::try_prove::<BenchStream<F19>, TimeProver<F19, BenchStream<F19>>>(
+                &mut TimeProver::new(config.unwrap()),
+                &mut ark_std::test_rng(),
+            )
+            .unwrap()
+            .is_accepted
+        );
+    }
 }
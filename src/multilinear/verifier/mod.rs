@@ -0,0 +1,5 @@
+mod config;
+mod verifier;
+
+pub use config::SumcheckVerifierConfig;
+pub use verifier::SumcheckVerifier;
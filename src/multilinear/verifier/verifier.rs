@@ -0,0 +1,148 @@
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::{
+    messages::RoundPolynomial, multilinear::SumcheckVerifierConfig, transcript::Transcript,
+    verifier::Verifier,
+};
+
+pub struct SumcheckVerifier<F: Field> {
+    pub claim: F,
+    pub current_round: usize,
+    pub num_variables: usize,
+    pub running_claim: F,
+    pub verifier_messages: Vec<F>,
+}
+
+impl<F: Field> Verifier<F> for SumcheckVerifier<F> {
+    type VerifierConfig = SumcheckVerifierConfig<F>;
+    type ProverMessage = (F, F);
+
+    fn claim(&self) -> F {
+        self.claim
+    }
+
+    fn new(verifier_config: Self::VerifierConfig) -> Self {
+        Self {
+            claim: verifier_config.claim,
+            current_round: 0,
+            num_variables: verifier_config.num_variables,
+            running_claim: verifier_config.claim,
+            verifier_messages: Vec::with_capacity(verifier_config.num_variables),
+        }
+    }
+
+    fn next_message(&mut self, prover_message: &(F, F), verifier_message: F) -> Option<F> {
+        // Ensure the current round is within bounds
+        if self.current_round >= self.num_variables {
+            return None;
+        }
+
+        // g(0) + g(1) should equal the claim carried over from the last round
+        if prover_message.0 + prover_message.1 != self.running_claim {
+            return None;
+        }
+
+        // reduce the claim to g(r) = g(0) - (g(0) - g(1)) * r
+        self.running_claim =
+            prover_message.0 - (prover_message.0 - prover_message.1) * verifier_message;
+        self.verifier_messages.push(verifier_message);
+
+        // don't forget to increment the round
+        self.current_round += 1;
+
+        Some(self.running_claim)
+    }
+
+    fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+
+    fn degree(&self) -> usize {
+        1
+    }
+
+    fn prover_message(&self, round_polynomial: &RoundPolynomial<F>) -> Option<(F, F)> {
+        (round_polynomial.degree() <= 1).then(|| {
+            (
+                round_polynomial.evaluate(F::ZERO),
+                round_polynomial.evaluate(F::ONE),
+            )
+        })
+    }
+
+    fn absorb_prover_message<T: Transcript<F>>(
+        transcript: &mut T,
+        prover_message: &Self::ProverMessage,
+    ) {
+        transcript.absorb(&[prover_message.0, prover_message.1]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+
+    use crate::{
+        multilinear::{SumcheckVerifier, SumcheckVerifierConfig, TimeProver, TimeProverConfig},
+        prover::Prover,
+        tests::{BenchStream, F19},
+        verifier::Verifier,
+    };
+
+    fn transcript(num_variables: usize) -> (F19, Vec<(F19, F19)>, Vec<F19>) {
+        // run the prover on its own, as if somebody else produced the transcript
+        let mut rng = ark_std::test_rng();
+        let s: BenchStream<F19> = BenchStream::new(num_variables);
+        let claim = s.claimed_sum;
+        let mut prover = TimeProver::<F19, BenchStream<F19>>::new(TimeProverConfig::new(
+            claim,
+            num_variables,
+            s,
+        ));
+        let mut prover_messages: Vec<(F19, F19)> = vec![];
+        let mut verifier_messages: Vec<F19> = vec![];
+        let mut verifier_message: Option<F19> = None;
+        while let Some(message) = prover.next_message(verifier_message) {
+            prover_messages.push(message);
+            verifier_messages.push(F19::rand(&mut rng));
+            verifier_message = verifier_messages.last().copied();
+        }
+        (claim, prover_messages, verifier_messages)
+    }
+
+    #[test]
+    fn accepts_honest_transcript() {
+        let (claim, prover_messages, verifier_messages) = transcript(8);
+        let mut verifier = SumcheckVerifier::new(SumcheckVerifierConfig::new(claim, 8));
+        let (point, _) = verifier
+            .verify(&prover_messages, &verifier_messages)
+            .unwrap();
+        assert_eq!(point, verifier_messages);
+    }
+
+    #[test]
+    fn rejects_bad_transcript() {
+        let (claim, mut prover_messages, verifier_messages) = transcript(8);
+
+        // wrong claim
+        let mut verifier =
+            SumcheckVerifier::new(SumcheckVerifierConfig::new(claim + F19::from(1), 8));
+        assert!(verifier
+            .verify(&prover_messages, &verifier_messages)
+            .is_none());
+
+        // wrong number of rounds
+        let mut verifier = SumcheckVerifier::new(SumcheckVerifierConfig::new(claim, 8));
+        assert!(verifier
+            .verify(&prover_messages[..7], &verifier_messages[..7])
+            .is_none());
+
+        // tampered message
+        prover_messages[3].0 += F19::from(1);
+        let mut verifier = SumcheckVerifier::new(SumcheckVerifierConfig::new(claim, 8));
+        assert!(verifier
+            .verify(&prover_messages, &verifier_messages)
+            .is_none());
+    }
+}
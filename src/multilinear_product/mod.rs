@@ -1,9 +1,14 @@
+mod proof;
 mod provers;
 mod sumcheck;
+mod verifier;
 
+pub use proof::{ProductSumcheckProof, PRODUCT_SUMCHECK_PROOF_VERSION};
 pub use provers::{
     blendy::{BlendyProductProver, BlendyProductProverConfig},
+    extension::{ExtensionProductProver, ExtensionProductProverConfig},
     space::{SpaceProductProver, SpaceProductProverConfig},
     time::{TimeProductProver, TimeProductProverConfig},
 };
 pub use sumcheck::ProductSumcheck;
+pub use verifier::{ProductSumcheckVerifier, ProductSumcheckVerifierConfig};
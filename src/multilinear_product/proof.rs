@@ -0,0 +1,136 @@
+use ark_ff::Field;
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
+use ark_std::{
+    io::{Read, Write},
+    vec::Vec,
+};
+
+use crate::{
+    messages::{deserialize_bounded_vec, MAX_ROUNDS},
+    multilinear_product::ProductSumcheck,
+};
+
+// bump this whenever the encoding below changes
+pub const PRODUCT_SUMCHECK_PROOF_VERSION: u8 = 1;
+
+/*
+ * The proof is just the prover messages: the verifier messages are either
+ * the verifier's own randomness or rederived from a transcript.
+ */
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProductSumcheckProof<F: Field> {
+    pub prover_messages: Vec<(F, F, F)>,
+}
+
+impl<F: Field> ProductSumcheckProof<F> {
+    pub fn new(prover_messages: Vec<(F, F, F)>) -> Self {
+        Self { prover_messages }
+    }
+}
+
+impl<F: Field> From<ProductSumcheck<F>> for ProductSumcheckProof<F> {
+    fn from(transcript: ProductSumcheck<F>) -> Self {
+        Self::new(transcript.prover_messages)
+    }
+}
+
+impl<F: Field> CanonicalSerialize for ProductSumcheckProof<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        PRODUCT_SUMCHECK_PROOF_VERSION.serialize_with_mode(&mut writer, compress)?;
+        self.prover_messages
+            .serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        PRODUCT_SUMCHECK_PROOF_VERSION.serialized_size(compress)
+            + self.prover_messages.serialized_size(compress)
+    }
+}
+
+impl<F: Field> Valid for ProductSumcheckProof<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.prover_messages.check()
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for ProductSumcheckProof<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        // refuse anything written in an encoding we don't know
+        let version = u8::deserialize_with_mode(&mut reader, compress, validate)?;
+        if version != PRODUCT_SUMCHECK_PROOF_VERSION {
+            return Err(SerializationError::InvalidData);
+        }
+        let prover_messages =
+            deserialize_bounded_vec::<(F, F, F), _>(&mut reader, compress, validate, MAX_ROUNDS)?;
+        Ok(Self { prover_messages })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+    use crate::{
+        multilinear_product::{ProductSumcheckProof, TimeProductProver, TimeProductProverConfig},
+        prover::Prover,
+        streams::multivariate_product_claim,
+        tests::{BenchStream, F64},
+        ProductSumcheck,
+    };
+
+    #[test]
+    fn round_trip() {
+        let s: BenchStream<F64> = BenchStream::new(10);
+        let claim = multivariate_product_claim(vec![s.clone(), s.clone()]);
+        let transcript = ProductSumcheck::<F64>::prove::<
+            BenchStream<F64>,
+            TimeProductProver<F64, BenchStream<F64>>,
+        >(
+            &mut TimeProductProver::new(TimeProductProverConfig::new(
+                claim,
+                10,
+                vec![s.clone(), s],
+            )),
+            &mut ark_std::test_rng(),
+        );
+        let proof: ProductSumcheckProof<F64> = transcript.into();
+
+        // compressed
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), proof.compressed_size());
+        assert_eq!(
+            ProductSumcheckProof::<F64>::deserialize_compressed(&bytes[..]).unwrap(),
+            proof
+        );
+
+        // uncompressed
+        let mut bytes = Vec::new();
+        proof.serialize_uncompressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), proof.uncompressed_size());
+        assert_eq!(
+            ProductSumcheckProof::<F64>::deserialize_uncompressed(&bytes[..]).unwrap(),
+            proof
+        );
+
+        // a length that's too long for a proof
+        let mut too_long = bytes.clone();
+        too_long[1..9].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(ProductSumcheckProof::<F64>::deserialize_uncompressed(&too_long[..]).is_err());
+
+        // unknown version
+        bytes[0] += 1;
+        assert!(ProductSumcheckProof::<F64>::deserialize_uncompressed(&bytes[..]).is_err());
+    }
+}
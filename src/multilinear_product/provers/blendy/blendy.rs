@@ -4,16 +4,23 @@ use crate::{
     messages::VerifierMessages,
     multilinear_product::TimeProductProver,
     order_strategy::{GraycodeOrder, SignificantBitOrder},
-    streams::{Stream, StreamIterator},
+    parallel::fold_range,
+    streams::{
+        add_weighted_evaluations, fold_partial_sums, Stream, StreamIterator, EVALUATIONS_CHUNK_SIZE,
+    },
 };
 use ark_ff::Field;
-use ark_std::vec::Vec;
+use ark_std::{cfg_chunks, cfg_chunks_mut, cfg_into_iter, cfg_iter_mut, vec::Vec};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use std::collections::BTreeSet;
 
 pub struct BlendyProductProver<F: Field, S: Stream<F>> {
     pub claim: F,
     pub current_round: usize,
     pub streams: Vec<S>,
+    // the streams in significant bit order, for callers that walk them the way the rounds do,
+    // the prover itself reads streams at any position so a round can be split across threads
     pub stream_iterators: Vec<StreamIterator<F, S, SignificantBitOrder>>,
     pub num_stages: usize,
     pub num_variables: usize,
@@ -33,6 +40,30 @@ pub struct BlendyProductProver<F: Field, S: Stream<F>> {
 }
 
 impl<F: Field, S: Stream<F>> BlendyProductProver<F, S> {
+    /*
+     * How many consecutive b a state computation reads at once, as many as fit in a
+     * chunk next to the 2^t values of b', but no more than 2^t so x_table and y_table
+     * never outgrow j_prime_table
+     */
+    pub fn run_len(table_len: usize, b_num_vars: usize) -> usize {
+        let num_b = 1_usize.checked_shl(b_num_vars as u32).unwrap_or(usize::MAX);
+        (EVALUATIONS_CHUNK_SIZE / table_len).clamp(1, num_b.min(table_len))
+    }
+
+    // the tables the prover keeps between rounds, in bytes
+    pub fn table_memory(&self, element_size: usize) -> usize {
+        let j_prime_table_len: usize = self.j_prime_table.iter().map(|row| row.len()).sum();
+        let evaluations_len: usize = self
+            .vsbw_prover
+            .evaluations
+            .iter()
+            .flatten()
+            .map(|evaluations| evaluations.len())
+            .sum();
+        (j_prime_table_len + self.x_table.len() + self.y_table.len() + evaluations_len)
+            * element_size
+    }
+
     pub fn is_initial_round(&self) -> bool {
         self.current_round == 0
     }
@@ -59,69 +90,36 @@ impl<F: Field, S: Stream<F>> BlendyProductProver<F, S> {
     }
 
     pub fn compute_round(&mut self) -> (F, F, F) {
-        let mut sum_0 = F::ZERO;
-        let mut sum_1 = F::ZERO;
-        let mut sum_half = F::ZERO;
-
         // in the last rounds, we switch to the memory intensive prover
         if self.switched_to_vsbw {
-            (sum_0, sum_1, sum_half) = self.vsbw_prover.vsbw_evaluate();
+            self.vsbw_prover.vsbw_evaluate()
         }
         // if first few rounds, then no table is computed, need to compute sums from the streams
         else if self.current_round + 1 <= self.last_round_phase1 {
-            // let time1 = std::time::Instant::now();
-
             // Lag Poly
             let mut sequential_lag_poly: LagrangePolynomial<F, SignificantBitOrder> =
                 LagrangePolynomial::new(&self.verifier_messages_round_comp);
-            let lag_polys_len = Hypercube::<SignificantBitOrder>::stop_value(self.current_round);
-            let mut lag_polys: Vec<F> = vec![F::ONE; lag_polys_len];
-
-            // reset the streams
-            self.stream_iterators
-                .iter_mut()
-                .for_each(|stream_it| stream_it.reset());
-
-            for (x_index, _) in
-                Hypercube::<SignificantBitOrder>::new(self.num_variables - self.current_round - 1)
-            {
-                // can avoid unnecessary additions for first round since there is no lag poly: gives a small speedup
-                if self.is_initial_round() {
-                    let p0 = self.stream_iterators[0].next().unwrap();
-                    let p1 = self.stream_iterators[0].next().unwrap();
-                    let q0 = self.stream_iterators[1].next().unwrap();
-                    let q1 = self.stream_iterators[1].next().unwrap();
-                    sum_0 += p0 * q0;
-                    sum_1 += p1 * q1;
-                    sum_half += (p0 + p1) * (q0 + q1);
-                } else {
-                    let mut partial_sum_p_0 = F::ZERO;
-                    let mut partial_sum_p_1 = F::ZERO;
-                    let mut partial_sum_q_0 = F::ZERO;
-                    let mut partial_sum_q_1 = F::ZERO;
-                    for (b_index, _) in Hypercube::<SignificantBitOrder>::new(self.current_round) {
-                        if x_index == 0 {
-                            lag_polys[b_index] = sequential_lag_poly.next().unwrap();
-                        }
-                        let lag_poly = lag_polys[b_index];
-                        partial_sum_p_0 += self.stream_iterators[0].next().unwrap() * lag_poly;
-                        partial_sum_q_0 += self.stream_iterators[1].next().unwrap() * lag_poly;
-                    }
-                    for (b_index, _) in Hypercube::<SignificantBitOrder>::new(self.current_round) {
-                        let lag_poly = lag_polys[b_index];
-                        partial_sum_p_1 += self.stream_iterators[0].next().unwrap() * lag_poly;
-                        partial_sum_q_1 += self.stream_iterators[1].next().unwrap() * lag_poly;
-                    }
+            let lag_polys: Vec<F> =
+                (0..Hypercube::<SignificantBitOrder>::stop_value(self.current_round))
+                    .map(|_| sequential_lag_poly.next().unwrap())
+                    .collect();
 
-                    sum_0 += partial_sum_p_0 * partial_sum_q_0;
-                    sum_1 += partial_sum_p_1 * partial_sum_q_1;
-                    sum_half +=
-                        (partial_sum_p_0 + partial_sum_p_1) * (partial_sum_q_0 + partial_sum_q_1);
-                }
-            }
-            sum_half = sum_half * self.inverse_four;
-            // let time2 = std::time::Instant::now();
-            // println!("round computation from stream took: {:?}", time2 - time1);
+            // the blocks of both factors are read a chunk at a time
+            let (sum_0, sum_1, sum_half) = fold_partial_sums(
+                &self.streams,
+                self.current_round,
+                || lag_polys.iter().copied(),
+                || (F::ZERO, F::ZERO, F::ZERO),
+                |(sum_0, sum_1, sum_half), zeros, ones| {
+                    (
+                        sum_0 + zeros[0] * zeros[1],
+                        sum_1 + ones[0] * ones[1],
+                        sum_half + (zeros[0] + ones[0]) * (zeros[1] + ones[1]),
+                    )
+                },
+                |a, b| (a.0 + b.0, a.1 + b.1, a.2 + b.2),
+            );
+            (sum_0, sum_1, sum_half * self.inverse_four)
         }
         // computing evaluations from the cross product tables
         else {
@@ -136,43 +134,50 @@ impl<F: Field, S: Stream<F>> BlendyProductProver<F, S> {
                 LagrangePolynomial::new(&self.verifier_messages_round_comp);
             let lag_polys_len = Hypercube::<GraycodeOrder>::stop_value(b_prime_num_vars);
             let mut lag_polys: Vec<F> = vec![F::ONE; lag_polys_len];
-
-            // Sums
             for (b_prime_index, _) in Hypercube::<GraycodeOrder>::new(b_prime_num_vars) {
-                for (b_prime_prime_index, _) in Hypercube::<GraycodeOrder>::new(b_prime_num_vars) {
-                    // doing it like this, for each hypercube member lag_poly is computed exactly once
-                    if b_prime_index == 0 {
-                        lag_polys[b_prime_prime_index] = sequential_lag_poly.next().unwrap();
-                    }
+                lag_polys[b_prime_index] = sequential_lag_poly.next().unwrap();
+            }
 
-                    let lag_poly_1 = lag_polys[b_prime_index];
-                    let lag_poly_2 = lag_polys[b_prime_prime_index];
-                    let lag_poly = lag_poly_1 * lag_poly_2;
-                    for (v_index, _) in Hypercube::<GraycodeOrder>::new(v_num_vars) {
-                        let b_prime_0_v =
-                            b_prime_index << b_prime_index_left_shift | 0 << v_num_vars | v_index;
-                        let b_prime_prime_0_v = b_prime_prime_index << b_prime_index_left_shift
-                            | 0 << v_num_vars
-                            | v_index;
-                        let b_prime_1_v =
-                            b_prime_index << b_prime_index_left_shift | 1 << v_num_vars | v_index;
-                        let b_prime_prime_1_v = b_prime_prime_index << b_prime_index_left_shift
-                            | 1 << v_num_vars
-                            | v_index;
+            // Sums
+            let (sum_0, sum_1, sum_half) = fold_range(
+                lag_polys_len,
+                || (F::ZERO, F::ZERO, F::ZERO),
+                |(mut sum_0, mut sum_1, mut sum_half), b_prime_index| {
+                    for (b_prime_prime_index, _) in
+                        Hypercube::<GraycodeOrder>::new(b_prime_num_vars)
+                    {
+                        let lag_poly_1 = lag_polys[b_prime_index];
+                        let lag_poly_2 = lag_polys[b_prime_prime_index];
+                        let lag_poly = lag_poly_1 * lag_poly_2;
+                        for (v_index, _) in Hypercube::<GraycodeOrder>::new(v_num_vars) {
+                            let b_prime_0_v = b_prime_index << b_prime_index_left_shift
+                                | 0 << v_num_vars
+                                | v_index;
+                            let b_prime_prime_0_v = b_prime_prime_index << b_prime_index_left_shift
+                                | 0 << v_num_vars
+                                | v_index;
+                            let b_prime_1_v = b_prime_index << b_prime_index_left_shift
+                                | 1 << v_num_vars
+                                | v_index;
+                            let b_prime_prime_1_v = b_prime_prime_index << b_prime_index_left_shift
+                                | 1 << v_num_vars
+                                | v_index;
 
-                        sum_0 += lag_poly * self.j_prime_table[b_prime_0_v][b_prime_prime_0_v];
-                        sum_1 += lag_poly * self.j_prime_table[b_prime_1_v][b_prime_prime_1_v];
-                        sum_half += lag_poly
-                            * (self.j_prime_table[b_prime_0_v][b_prime_prime_0_v]
-                                + self.j_prime_table[b_prime_0_v][b_prime_prime_1_v]
-                                + self.j_prime_table[b_prime_1_v][b_prime_prime_0_v]
-                                + self.j_prime_table[b_prime_1_v][b_prime_prime_1_v]);
+                            sum_0 += lag_poly * self.j_prime_table[b_prime_0_v][b_prime_prime_0_v];
+                            sum_1 += lag_poly * self.j_prime_table[b_prime_1_v][b_prime_prime_1_v];
+                            sum_half += lag_poly
+                                * (self.j_prime_table[b_prime_0_v][b_prime_prime_0_v]
+                                    + self.j_prime_table[b_prime_0_v][b_prime_prime_1_v]
+                                    + self.j_prime_table[b_prime_1_v][b_prime_prime_0_v]
+                                    + self.j_prime_table[b_prime_1_v][b_prime_prime_1_v]);
+                        }
                     }
-                }
-            }
-            sum_half = sum_half * self.inverse_four;
+                    (sum_0, sum_1, sum_half)
+                },
+                |a, b| (a.0 + b.0, a.1 + b.1, a.2 + b.2),
+            );
+            (sum_0, sum_1, sum_half * self.inverse_four)
         }
-        (sum_0, sum_1, sum_half)
     }
 
     pub fn compute_state(&mut self) {
@@ -189,9 +194,21 @@ impl<F: Field, S: Stream<F>> BlendyProductProver<F, S> {
             //     j, j_prime, t
             // );
 
-            // zero out the table
+            // some stuff for iterating
             let table_len = Hypercube::<SignificantBitOrder>::stop_value(t);
+            let b_num_vars: usize = self.num_variables + 1 - j_prime - t;
+            let x_num_vars = j_prime - 1;
+            let run_len = Self::run_len(table_len, b_num_vars);
+
+            // zero out the tables, the previous ones are dropped first so they're never held together
+            self.j_prime_table = vec![];
             self.j_prime_table = vec![vec![F::ZERO; table_len]; table_len];
+            let mut x_table = vec![];
+            let mut y_table = vec![];
+            self.x_table = vec![];
+            self.y_table = vec![];
+            x_table.resize(table_len * run_len, F::ZERO);
+            y_table.resize(table_len * run_len, F::ZERO);
 
             // basically, this needs to get "zeroed" out at the beginning of state computation
             self.verifier_messages_round_comp = VerifierMessages::new_from_self(
@@ -200,82 +217,106 @@ impl<F: Field, S: Stream<F>> BlendyProductProver<F, S> {
                 self.verifier_messages.messages.len(),
             );
 
-            // some stuff for iterating
-            let b_num_vars: usize = self.num_variables + 1 - j_prime - t;
-            let x_num_vars = j_prime - 1;
-
             // Lag Poly
             let mut sequential_lag_poly: LagrangePolynomial<F, SignificantBitOrder> =
                 LagrangePolynomial::new(&self.verifier_messages);
 
             assert!(x_num_vars == self.verifier_messages.messages.len());
             let lag_polys_len = Hypercube::<SignificantBitOrder>::stop_value(x_num_vars);
-            let mut lag_polys: Vec<F> = vec![F::ONE; lag_polys_len];
-
-            for (x_index, _) in Hypercube::<SignificantBitOrder>::new(x_num_vars) {
-                lag_polys[x_index] = sequential_lag_poly.next().unwrap();
-            }
-
-            // reset the streams
-            self.stream_iterators
-                .iter_mut()
-                .for_each(|stream_it| stream_it.reset());
+            let lag_polys: Vec<F> = (0..lag_polys_len)
+                .map(|_| sequential_lag_poly.next().unwrap())
+                .collect();
 
-            // Ensure x_table and y_table are initialized with the correct size
-            self.x_table = vec![F::ZERO; Hypercube::<SignificantBitOrder>::stop_value(t)];
-            self.y_table = vec![F::ZERO; Hypercube::<SignificantBitOrder>::stop_value(t)];
-
-            for (_, _) in Hypercube::<SignificantBitOrder>::new(b_num_vars) {
-                for (b_prime_index, _) in Hypercube::<SignificantBitOrder>::new(t) {
-                    self.x_table[b_prime_index] = F::ZERO;
-                    self.y_table[b_prime_index] = F::ZERO;
-
-                    for (x_index, _) in Hypercube::<SignificantBitOrder>::new(x_num_vars) {
-                        self.x_table[b_prime_index] +=
-                            lag_polys[x_index] * self.stream_iterators[0].next().unwrap();
-                        self.y_table[b_prime_index] +=
-                            lag_polys[x_index] * self.stream_iterators[1].next().unwrap();
-                    }
-                }
-                for (b_prime_index, _) in Hypercube::<SignificantBitOrder>::new(t) {
-                    for (b_prime_prime_index, _) in Hypercube::<SignificantBitOrder>::new(t) {
-                        self.j_prime_table[b_prime_index][b_prime_prime_index] +=
-                            self.x_table[b_prime_index] * self.y_table[b_prime_prime_index];
-                    }
+            /*
+             * In significant bit order the evaluations for b' and b are at b' || b after the
+             * bound variables, so a run of consecutive b is contiguous. x_table and y_table
+             * hold a row of run_len b for every b', recomputed in place for every run
+             */
+            for run_start in
+                (0..Hypercube::<SignificantBitOrder>::stop_value(b_num_vars)).step_by(run_len)
+            {
+                let fill_rows =
+                    |buffer: &mut Vec<F>,
+                     (b_prime_index, (x, y)): (usize, (&mut [F], &mut [F]))| {
+                        let (sums, buffer) = buffer.split_at_mut(2 * run_len);
+                        sums.fill(F::ZERO);
+                        add_weighted_evaluations(
+                            &self.streams,
+                            x_num_vars,
+                            lag_polys.iter().copied(),
+                            b_prime_index << b_num_vars | run_start,
+                            buffer,
+                            sums,
+                        );
+                        x.copy_from_slice(&sums[..run_len]);
+                        y.copy_from_slice(&sums[run_len..]);
+                    };
+                let rows = cfg_chunks_mut!(x_table, run_len)
+                    .zip(cfg_chunks_mut!(y_table, run_len))
+                    .enumerate();
+                // the sums and the read buffer are kept per thread
+                #[cfg(feature = "parallel")]
+                rows.for_each_init(|| vec![F::ZERO; 4 * run_len], fill_rows);
+                #[cfg(not(feature = "parallel"))]
+                {
+                    let mut buffer = vec![F::ZERO; 4 * run_len];
+                    rows.for_each(|row| fill_rows(&mut buffer, row));
                 }
+                cfg_iter_mut!(self.j_prime_table)
+                    .zip(cfg_chunks!(x_table, run_len))
+                    .for_each(|(j_prime_row, x)| {
+                        for (entry, y) in j_prime_row.iter_mut().zip(y_table.chunks(run_len)) {
+                            *entry += x.iter().zip(y).map(|(x, y)| *x * y).sum::<F>();
+                        }
+                    });
             }
+            self.x_table = x_table;
+            self.y_table = y_table;
             // let time2 = std::time::Instant::now();
             // println!("table computation took: {:?}", time2 - time1);
         } else if p && is_largest {
             // switch to the memory intensive sumcheck on the last round computation
             let num_variables_new = self.num_variables - j + 1;
             self.switched_to_vsbw = true;
+            // the tables aren't used anymore
+            self.j_prime_table = vec![];
+            self.x_table = vec![];
+            self.y_table = vec![];
 
             // println!(
             //     "switched to vsbw on round: {}, num_vars_new: {}",
             //     j, num_variables_new
             // );
 
-            // reset the streams
-            self.stream_iterators
-                .iter_mut()
-                .for_each(|stream_it| stream_it.reset());
-
             // initialize the evaluations for the memory-intensive implementation for the final rounds of the protocol
-            let mut evaluations_p = vec![F::ZERO; 1 << num_variables_new];
-            let mut evaluations_q = vec![F::ZERO; 1 << num_variables_new];
-
-            for (b_prime_index, _) in Hypercube::<SignificantBitOrder>::new(num_variables_new) {
-                let mut sequential_lag_poly: LagrangePolynomial<F, SignificantBitOrder> =
-                    LagrangePolynomial::new(&self.verifier_messages);
-                for (_, _) in Hypercube::<SignificantBitOrder>::new(j - 1) {
-                    let lag_poly = sequential_lag_poly.next().unwrap();
-                    evaluations_p[b_prime_index] +=
-                        lag_poly * self.stream_iterators[0].next().unwrap();
-                    evaluations_q[b_prime_index] +=
-                        lag_poly * self.stream_iterators[1].next().unwrap();
-                }
-            }
+            let mut sequential_lag_poly: LagrangePolynomial<F, SignificantBitOrder> =
+                LagrangePolynomial::new(&self.verifier_messages);
+            let lag_polys: Vec<F> = (0..Hypercube::<SignificantBitOrder>::stop_value(j - 1))
+                .map(|_| sequential_lag_poly.next().unwrap())
+                .collect();
+            // in significant bit order the remaining variables are contiguous after the bound ones, so they're read a chunk at a time
+            let evaluations_len = Hypercube::<SignificantBitOrder>::stop_value(num_variables_new);
+            let chunk_len = evaluations_len.min(EVALUATIONS_CHUNK_SIZE);
+            let (evaluations_p, evaluations_q): (Vec<F>, Vec<F>) =
+                cfg_into_iter!(0..evaluations_len / chunk_len)
+                    .flat_map(|chunk| {
+                        let mut buffer = vec![F::ZERO; 2 * chunk_len];
+                        let mut sums = vec![F::ZERO; 2 * chunk_len];
+                        add_weighted_evaluations(
+                            &self.streams,
+                            j - 1,
+                            lag_polys.iter().copied(),
+                            chunk * chunk_len,
+                            &mut buffer,
+                            &mut sums,
+                        );
+                        let (p, q) = sums.split_at(chunk_len);
+                        p.iter()
+                            .copied()
+                            .zip(q.iter().copied())
+                            .collect::<Vec<(F, F)>>()
+                    })
+                    .unzip();
             self.vsbw_prover.evaluations[0] = Some(evaluations_p);
             self.vsbw_prover.evaluations[1] = Some(evaluations_q);
         } else if self.switched_to_vsbw {
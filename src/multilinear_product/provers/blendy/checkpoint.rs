@@ -0,0 +1,138 @@
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    vec::Vec,
+};
+use std::collections::BTreeSet;
+
+use crate::{
+    error::SumcheckError,
+    messages::VerifierMessages,
+    multilinear_product::{BlendyProductProver, BlendyProductProverConfig, TimeProductProver},
+    prover::{read_header, write_header, Checkpoint},
+    streams::{check_streams, Stream, StreamIterator},
+};
+
+/*
+ * Between rounds the state is the j_prime_table of the last state computation,
+ * or once switched the reduced tables of the vsbw prover. The x and y tables are
+ * recomputed for every b and prev_table_* at the start of every round, so they
+ * aren't saved
+ */
+impl<F: Field, S: Stream<F>> Checkpoint for BlendyProductProver<F, S> {
+    const KIND: u8 = 2;
+    type Streams = Vec<S>;
+
+    fn checkpoint<W: Write>(&self, mut writer: W) -> Result<(), SumcheckError> {
+        write_header(&mut writer, Self::KIND)?;
+        self.claim.serialize_uncompressed(&mut writer)?;
+        self.current_round.serialize_uncompressed(&mut writer)?;
+        self.num_stages.serialize_uncompressed(&mut writer)?;
+        self.num_variables.serialize_uncompressed(&mut writer)?;
+        self.state_comp_set.serialize_uncompressed(&mut writer)?;
+        self.verifier_messages
+            .messages
+            .serialize_uncompressed(&mut writer)?;
+        self.verifier_messages_round_comp
+            .messages
+            .serialize_uncompressed(&mut writer)?;
+        self.j_prime_table.serialize_uncompressed(&mut writer)?;
+        self.switched_to_vsbw.serialize_uncompressed(&mut writer)?;
+        self.vsbw_prover
+            .current_round
+            .serialize_uncompressed(&mut writer)?;
+        self.vsbw_prover
+            .num_variables
+            .serialize_uncompressed(&mut writer)?;
+        self.vsbw_prover
+            .evaluations
+            .serialize_uncompressed(&mut writer)?;
+        Ok(())
+    }
+
+    fn resume<R: Read>(mut reader: R, streams: Vec<S>) -> Result<Self, SumcheckError> {
+        read_header(&mut reader, Self::KIND)?;
+        let claim = F::deserialize_uncompressed(&mut reader)?;
+        let current_round = usize::deserialize_uncompressed(&mut reader)?;
+        let num_stages = usize::deserialize_uncompressed(&mut reader)?;
+        let num_variables = usize::deserialize_uncompressed(&mut reader)?;
+        let state_comp_set = BTreeSet::<usize>::deserialize_uncompressed(&mut reader)?;
+        let verifier_messages = Vec::<F>::deserialize_uncompressed(&mut reader)?;
+        let verifier_messages_round_comp = Vec::<F>::deserialize_uncompressed(&mut reader)?;
+        let j_prime_table = Vec::<Vec<F>>::deserialize_uncompressed(&mut reader)?;
+        let switched_to_vsbw = bool::deserialize_uncompressed(&mut reader)?;
+        let vsbw_current_round = usize::deserialize_uncompressed(&mut reader)?;
+        let vsbw_num_variables = usize::deserialize_uncompressed(&mut reader)?;
+        let vsbw_evaluations = Vec::<Option<Vec<F>>>::deserialize_uncompressed(&mut reader)?;
+        if num_stages == 0 || state_comp_set.is_empty() || vsbw_evaluations.len() != 2 {
+            return Err(SerializationError::InvalidData.into());
+        }
+        check_streams(&streams, num_variables)?;
+        if streams.len() != 2 {
+            return Err(SumcheckError::NumStreamsMismatch {
+                expected: 2,
+                found: streams.len(),
+            });
+        }
+        Ok(Self {
+            claim,
+            current_round,
+            stream_iterators: streams.iter().cloned().map(StreamIterator::new).collect(),
+            streams,
+            num_stages,
+            num_variables,
+            last_round_phase1: BlendyProductProverConfig::<F, S>::LAST_ROUND_PHASE1,
+            verifier_messages: VerifierMessages::new(&verifier_messages),
+            verifier_messages_round_comp: VerifierMessages::new(&verifier_messages_round_comp),
+            x_table: vec![],
+            y_table: vec![],
+            j_prime_table,
+            stage_size: num_variables / num_stages,
+            inverse_four: F::from(4_u32).inverse().unwrap(),
+            prev_table_round_num: 0,
+            prev_table_size: 0,
+            state_comp_set,
+            switched_to_vsbw,
+            vsbw_prover: TimeProductProver {
+                claim,
+                current_round: vsbw_current_round,
+                evaluations: vsbw_evaluations,
+                streams: None,
+                num_variables: vsbw_num_variables,
+                inverse_four: F::from(4_u32).inverse().unwrap(),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        multilinear_product::{BlendyProductProver, BlendyProductProverConfig},
+        prover::Prover,
+        streams::multivariate_product_claim,
+        tests::{checkpoint_test, BenchStream, F64},
+    };
+
+    #[test]
+    fn resume() {
+        let streams: Vec<BenchStream<F64>> = vec![BenchStream::new(12); 2];
+        let claim = multivariate_product_claim(streams.clone());
+        // snapshots in the first rounds, between state computations and after the switch to vsbw
+        for num_stages in [2, 3] {
+            checkpoint_test::<F64, BlendyProductProver<F64, BenchStream<F64>>>(
+                12,
+                || {
+                    BlendyProductProver::new(BlendyProductProverConfig::new(
+                        claim,
+                        num_stages,
+                        12,
+                        streams.clone(),
+                    ))
+                },
+                || streams.clone(),
+            );
+        }
+    }
+}
@@ -1,6 +1,12 @@
 use ark_ff::Field;
+use std::collections::BTreeSet;
 
-use crate::{prover::ProductProverConfig, streams::Stream};
+use crate::{
+    error::SumcheckError,
+    multilinear_product::BlendyProductProver,
+    prover::ProductProverConfig,
+    streams::{check_streams, Stream},
+};
 
 const DEFAULT_NUM_STAGES: usize = 2;
 
@@ -12,6 +18,7 @@ where
     pub num_stages: usize,
     pub num_variables: usize,
     pub claim: F,
+    pub state_comp_set: BTreeSet<usize>,
     pub streams: Vec<S>,
 }
 
@@ -20,23 +27,181 @@ where
     F: Field,
     S: Stream<F>,
 {
+    // the first rounds are computed straight from the streams, before any table
+    pub const LAST_ROUND_PHASE1: usize = 2;
+
     pub fn new(claim: F, num_stages: usize, num_variables: usize, streams: Vec<S>) -> Self {
         Self {
             claim,
             num_stages,
             num_variables,
+            state_comp_set: Self::compute_state_comp_set(num_variables, num_stages),
             streams,
         }
     }
-}
-
-impl<F: Field, S: Stream<F>> ProductProverConfig<F, S> for BlendyProductProverConfig<F, S> {
-    fn default(claim: F, num_variables: usize, streams: Vec<S>) -> Self {
-        Self {
+    pub fn try_new(
+        claim: F,
+        num_stages: usize,
+        num_variables: usize,
+        streams: Vec<S>,
+    ) -> Result<Self, SumcheckError> {
+        check_streams(&streams, num_variables)?;
+        // the product is of exactly two streams
+        if streams.len() != 2 {
+            return Err(SumcheckError::NumStreamsMismatch {
+                expected: 2,
+                found: streams.len(),
+            });
+        }
+        // the schedule needs at least one state computation
+        if num_stages == 0 || Self::compute_state_comp_set(num_variables, num_stages).is_empty() {
+            return Err(SumcheckError::InvalidNumStages {
+                num_stages,
+                num_variables,
+            });
+        }
+        Ok(Self::new(claim, num_stages, num_variables, streams))
+    }
+    /*
+     * Every state computation is one pass over the streams, so the fastest schedule that
+     * fits is the one with the fewest state computations (and then the least memory), or
+     * None if none fit. The schedule is searched over all the valid ones, not only those
+     * compute_state_comp_set gives for some number of stages
+     */
+    pub fn new_with_memory_budget(
+        claim: F,
+        num_variables: usize,
+        streams: Vec<S>,
+        memory_budget: usize,
+        element_size: usize,
+    ) -> Option<Self> {
+        let state_comp_set =
+            Self::budget_state_comp_set(num_variables, memory_budget / element_size.max(1))?;
+        Some(Self {
             claim,
-            num_stages: DEFAULT_NUM_STAGES,
+            // only descriptive, the prover follows the schedule
+            num_stages: state_comp_set.len(),
             num_variables,
+            state_comp_set,
             streams,
+        })
+    }
+    // the cheapest schedule whose stages each take at most memory_budget elements
+    fn budget_state_comp_set(
+        num_variables: usize,
+        memory_budget: usize,
+    ) -> Option<BTreeSet<usize>> {
+        let first_round = Self::LAST_ROUND_PHASE1 + 1;
+        if num_variables < first_round {
+            return None;
+        }
+        // for a state computation at each round, the fewest passes to get there, the peak memory so far and the previous one
+        let mut best: Vec<Option<(usize, usize, usize)>> = vec![None; num_variables + 1];
+        best[first_round] = Some((1, 0, first_round));
+        let mut last: Option<(usize, usize, usize)> = None;
+        for round in first_round..=num_variables {
+            let Some((num_state_comps, peak, _)) = best[round] else {
+                continue;
+            };
+            // switch to vsbw here
+            let memory = Self::stage_memory(num_variables, round, None);
+            if memory <= memory_budget {
+                let candidate = (num_state_comps, peak.max(memory), round);
+                if last.is_none_or(|last| (candidate.0, candidate.1) < (last.0, last.1)) {
+                    last = Some(candidate);
+                }
+            }
+            // or fill a table for the rounds up to the next one, at most round - 1 of them to stay linear time
+            for next_round in round + 1..=num_variables.min(2 * round - 1) {
+                let memory = Self::stage_memory(num_variables, round, Some(next_round));
+                if memory > memory_budget {
+                    break;
+                }
+                let candidate = (num_state_comps + 1, peak.max(memory), round);
+                if best[next_round].is_none_or(|best| (candidate.0, candidate.1) < (best.0, best.1))
+                {
+                    best[next_round] = Some(candidate);
+                }
+            }
+        }
+
+        // walk the schedule back from the switch to vsbw
+        let (_, _, mut round) = last?;
+        let mut state_comp_set = BTreeSet::from([round]);
+        while round != first_round {
+            round = best[round].unwrap().2;
+            state_comp_set.insert(round);
+        }
+        Some(state_comp_set)
+    }
+    pub fn compute_state_comp_set(num_variables: usize, num_stages: usize) -> BTreeSet<usize> {
+        let max_rounds_phase2: usize = num_variables.div_ceil(2 * num_stages);
+        let last_round_phase3: usize = num_variables - num_variables.div_ceil(num_stages);
+
+        let mut current_round: usize = Self::LAST_ROUND_PHASE1 + 1;
+        let mut state_comp_set: BTreeSet<usize> = BTreeSet::new();
+        while current_round <= last_round_phase3 {
+            state_comp_set.insert(current_round);
+            current_round = std::cmp::min(current_round + max_rounds_phase2, current_round * 2 - 1); // the minus one is a time-efficiency optimization
+            current_round = std::cmp::max(current_round, 2);
+        }
+        state_comp_set
+    }
+    pub fn num_passes(&self) -> usize {
+        Self::LAST_ROUND_PHASE1 + self.state_comp_set.len()
+    }
+    // the most memory the prover takes at once, in bytes
+    pub fn peak_memory(&self, element_size: usize) -> usize {
+        Self::stages(&self.state_comp_set)
+            .map(|(round, next_round)| Self::stage_memory(self.num_variables, round, next_round))
+            .max()
+            .unwrap_or(0)
+            .saturating_mul(element_size)
+    }
+    // the same for only the tables the prover keeps between rounds, see BlendyProductProver::table_memory
+    pub fn peak_table_memory(&self, element_size: usize) -> usize {
+        Self::stages(&self.state_comp_set)
+            .map(|(round, next_round)| Self::table_len(self.num_variables, round, next_round))
+            .max()
+            .unwrap_or(0)
+            .saturating_mul(element_size)
+    }
+    // every state computation with the next one, None for the switch to vsbw
+    fn stages(
+        state_comp_set: &BTreeSet<usize>,
+    ) -> impl Iterator<Item = (usize, Option<usize>)> + '_ {
+        state_comp_set
+            .iter()
+            .map(|round| (*round, state_comp_set.range(round + 1..).next().copied()))
+    }
+    /*
+     * The tables from a state computation at round until the next one: j_prime_table has
+     * 2^t * 2^t entries and x_table and y_table a run of b for each of the 2^t values of b'
+     * for the t rounds it covers, see BlendyProductProver::run_len. On the
+     * switch to vsbw they're dropped for two evaluation tables over the remaining variables,
+     * which later rounds fold in place
+     */
+    fn table_len(num_variables: usize, round: usize, next_round: Option<usize>) -> usize {
+        let pow2 = |bits: usize| 1_usize.checked_shl(bits as u32).unwrap_or(usize::MAX);
+        match next_round {
+            Some(next_round) => {
+                let t = next_round - round;
+                let run_len =
+                    BlendyProductProver::<F, S>::run_len(pow2(t), num_variables + 1 - next_round);
+                pow2(2 * t).saturating_add(2 * pow2(t).saturating_mul(run_len))
+            }
+            None => pow2(num_variables - round + 1).saturating_mul(2),
         }
     }
+    // the tables, and the Lagrange polynomials of the previous rounds, which the state computation holds too
+    fn stage_memory(num_variables: usize, round: usize, next_round: Option<usize>) -> usize {
+        let lag_polys_len = 1_usize.checked_shl(round as u32 - 1).unwrap_or(usize::MAX);
+        Self::table_len(num_variables, round, next_round).saturating_add(lag_polys_len)
+    }
+}
+
+impl<F: Field, S: Stream<F>> ProductProverConfig<F, S> for BlendyProductProverConfig<F, S> {
+    fn default(claim: F, num_variables: usize, streams: Vec<S>) -> Self {
+        Self::new(claim, DEFAULT_NUM_STAGES, num_variables, streams)
+    }
 }
@@ -4,7 +4,6 @@ use std::collections::BTreeSet;
 use crate::{
     messages::VerifierMessages,
     multilinear_product::{BlendyProductProver, BlendyProductProverConfig, TimeProductProver},
-    order_strategy::SignificantBitOrder,
     prover::Prover,
     streams::{Stream, StreamIterator},
 };
@@ -22,23 +21,8 @@ impl<F: Field, S: Stream<F>> Prover<F> for BlendyProductProver<F, S> {
         let num_variables: usize = prover_config.num_variables;
         let num_stages: usize = prover_config.num_stages;
         let stage_size: usize = num_variables / num_stages;
-        let max_rounds_phase2: usize = num_variables.div_ceil(2 * num_stages);
-
-        let last_round_phase1: usize = 2;
-        let last_round_phase3: usize = num_variables - num_variables.div_ceil(num_stages);
-
-        let state_comp_set: BTreeSet<usize> = {
-            let mut current_round: usize = last_round_phase1 + 1;
-            let mut state_comp_set: BTreeSet<usize> = BTreeSet::new();
-            while current_round <= last_round_phase3 {
-                state_comp_set.insert(current_round);
-                current_round =
-                    std::cmp::min(current_round + max_rounds_phase2, current_round * 2 - 1); // the minus one is a time-efficiency optimization
-                current_round = std::cmp::max(current_round, 2);
-            }
-            // println!("state_comp_set: {:?}", state_comp_set);
-            state_comp_set
-        };
+        let last_round_phase1: usize = BlendyProductProverConfig::<F, S>::LAST_ROUND_PHASE1;
+        let state_comp_set: BTreeSet<usize> = prover_config.state_comp_set;
         assert!(state_comp_set.len() > 0);
 
         let last_round: usize = *state_comp_set.iter().max().unwrap();
@@ -51,18 +35,17 @@ impl<F: Field, S: Stream<F>> Prover<F> for BlendyProductProver<F, S> {
             inverse_four: F::from(4_u32).inverse().unwrap(),
         };
 
-        let stream_iterators = prover_config
-            .streams
-            .iter()
-            .cloned()
-            .map(|s| StreamIterator::<F, S, SignificantBitOrder>::new(s))
-            .collect();
         // return the BlendyProver instance
         Self {
             claim: prover_config.claim,
             current_round: 0,
+            stream_iterators: prover_config
+                .streams
+                .iter()
+                .cloned()
+                .map(StreamIterator::new)
+                .collect(),
             streams: prover_config.streams,
-            stream_iterators,
             num_stages,
             num_variables,
             last_round_phase1,
@@ -114,10 +97,12 @@ impl<F: Field, S: Stream<F>> Prover<F> for BlendyProductProver<F, S> {
 
 #[cfg(test)]
 mod tests {
+    use ark_ff::UniformRand;
     use ark_poly::multivariate::{SparsePolynomial, SparseTerm};
 
     use crate::{
-        multilinear_product::{BlendyProductProver, BlendyProductProverConfig},
+        error::SumcheckError,
+        multilinear_product::{BlendyProductProver, BlendyProductProverConfig, TimeProductProver},
         order_strategy::SignificantBitOrder,
         prover::{ProductProverConfig, Prover},
         streams::{multivariate_product_claim, MemoryStream, Stream},
@@ -129,6 +114,108 @@ mod tests {
         ProductSumcheck,
     };
 
+    #[test]
+    fn memory_budget() {
+        let num_variables = 12;
+        let element_size = 8;
+        let mut rng = ark_std::test_rng();
+        let streams: Vec<MemoryStream<F64>> = (0..2)
+            .map(|_| {
+                MemoryStream::new(
+                    (0..1 << num_variables)
+                        .map(|_| F64::rand(&mut rng))
+                        .collect(),
+                )
+            })
+            .collect();
+        let claim: F64 = multivariate_product_claim(streams.clone());
+        let config = |memory_budget: usize| {
+            BlendyProductProverConfig::new_with_memory_budget(
+                claim,
+                num_variables,
+                streams.clone(),
+                memory_budget,
+                element_size,
+            )
+        };
+        // a large budget takes fewer passes than a small one, which stays within the budget
+        let large = config(1 << 20).unwrap();
+        let small = config(1 << 11).unwrap();
+        assert!(large.num_passes() < small.num_passes());
+        assert!(small.peak_memory(element_size) <= 1 << 11);
+        // and nothing fits in too small a budget
+        assert!(config(1).is_none());
+        // no schedule of compute_state_comp_set that fits takes fewer passes
+        for num_stages in 1..=num_variables {
+            let fixed =
+                BlendyProductProverConfig::new(claim, num_stages, num_variables, streams.clone());
+            if !fixed.state_comp_set.is_empty() && fixed.peak_memory(element_size) <= 1 << 11 {
+                assert!(small.num_passes() <= fixed.num_passes());
+            }
+        }
+        // the tables the prover holds peak where the config says they do
+        for config in [config(1 << 20).unwrap(), config(1 << 11).unwrap()] {
+            let expected = config.peak_table_memory(element_size);
+            let mut prover = BlendyProductProver::new(config);
+            let mut peak = 0;
+            let mut verifier_message = None;
+            while prover.next_message(verifier_message).is_some() {
+                peak = peak.max(prover.table_memory(element_size));
+                verifier_message = Some(F64::rand(&mut rng));
+            }
+            assert_eq!(peak, expected);
+        }
+        // the transcript doesn't depend on the configuration
+        let large_transcript = ProductSumcheck::<F64>::prove::<
+            MemoryStream<F64>,
+            BlendyProductProver<F64, MemoryStream<F64>>,
+        >(
+            &mut BlendyProductProver::new(large),
+            &mut ark_std::test_rng(),
+        );
+        let small_transcript = ProductSumcheck::<F64>::prove::<
+            MemoryStream<F64>,
+            BlendyProductProver<F64, MemoryStream<F64>>,
+        >(
+            &mut BlendyProductProver::new(small),
+            &mut ark_std::test_rng(),
+        );
+        let time_transcript = ProductSumcheck::<F64>::prove::<
+            MemoryStream<F64>,
+            TimeProductProver<F64, MemoryStream<F64>>,
+        >(
+            &mut TimeProductProver::new(ProductProverConfig::default(
+                claim,
+                num_variables,
+                streams,
+            )),
+            &mut ark_std::test_rng(),
+        );
+        assert!(large_transcript.is_accepted);
+        assert_eq!(large_transcript, time_transcript);
+        assert_eq!(small_transcript, time_transcript);
+    }
+
+    #[test]
+    fn invalid_configs() {
+        let s: MemoryStream<F64> = MemoryStream::new(vec![F64::from(1_u32); 1 << 8]);
+        assert!(matches!(
+            BlendyProductProverConfig::try_new(F64::from(0_u32), 2, 8, vec![s.clone()]),
+            Err(SumcheckError::NumStreamsMismatch {
+                expected: 2,
+                found: 1
+            })
+        ));
+        // a schedule needs at least one stage
+        assert!(matches!(
+            BlendyProductProverConfig::try_new(F64::from(0_u32), 0, 8, vec![s.clone(), s.clone()]),
+            Err(SumcheckError::InvalidNumStages { .. })
+        ));
+        assert!(
+            BlendyProductProverConfig::try_new(F64::from(0_u32), 2, 8, vec![s.clone(), s]).is_ok()
+        );
+    }
+
     // the stream has to be in SigBit order for this to work
     // #[test]
     // fn parity_with_basic_prover() {
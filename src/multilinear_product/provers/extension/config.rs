@@ -0,0 +1,47 @@
+use ark_ff::Field;
+
+use crate::{
+    error::SumcheckError,
+    streams::{check_streams, Stream},
+};
+
+pub struct ExtensionProductProverConfig<F, S>
+where
+    F: Field,
+    S: Stream<F::BasePrimeField>,
+{
+    pub num_variables: usize,
+    pub claim: F,
+    pub streams: Vec<S>,
+}
+
+impl<F, S> ExtensionProductProverConfig<F, S>
+where
+    F: Field,
+    S: Stream<F::BasePrimeField>,
+{
+    pub fn new(claim: F, num_variables: usize, streams: Vec<S>) -> Self {
+        // the prover reads streams[0] and streams[1], try_new checks the rest
+        assert_eq!(
+            streams.len(),
+            2,
+            "the extension product prover takes exactly two streams"
+        );
+        Self {
+            claim,
+            num_variables,
+            streams,
+        }
+    }
+    pub fn try_new(claim: F, num_variables: usize, streams: Vec<S>) -> Result<Self, SumcheckError> {
+        check_streams(&streams, num_variables)?;
+        // the product is of exactly two streams
+        if streams.len() != 2 {
+            return Err(SumcheckError::NumStreamsMismatch {
+                expected: 2,
+                found: streams.len(),
+            });
+        }
+        Ok(Self::new(claim, num_variables, streams))
+    }
+}
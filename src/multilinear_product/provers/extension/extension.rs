@@ -0,0 +1,122 @@
+use ark_ff::{AdditiveGroup, Field};
+use ark_std::{cfg_into_iter, vec::Vec};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{
+    parallel::fold_range,
+    streams::{map_stream_halves, Stream, EVALUATIONS_CHUNK_SIZE},
+};
+
+/*
+ * Like TimeProductProver, but the streams are over the base field of the challenges:
+ * the first round multiplies base field elements, and binding the first challenge
+ * lifts both tables to the extension, where the remaining rounds happen. There's
+ * only this time variant: the lifted tables hold 2^(n - 1) extension elements per
+ * stream, so memory is linear in the size of the hypercube
+ */
+pub struct ExtensionProductProver<F: Field, S: Stream<F::BasePrimeField>> {
+    pub claim: F,
+    pub current_round: usize,
+    pub evaluations: Option<(Vec<F>, Vec<F>)>,
+    pub streams: Vec<S>,
+    pub num_variables: usize,
+    pub inverse_four: F,
+}
+
+impl<F: Field, S: Stream<F::BasePrimeField>> ExtensionProductProver<F, S> {
+    pub fn total_rounds(&self) -> usize {
+        self.num_variables
+    }
+    fn num_free_variables(&self) -> usize {
+        self.num_variables - self.current_round
+    }
+    // g(0), g(1) and 4 * g(1/2) from the values of p and q at 0 and 1
+    fn accumulate<T: Field>(sums: (T, T, T), p: (T, T), q: (T, T)) -> (T, T, T) {
+        (
+            sums.0 + p.0 * q.0,
+            sums.1 + p.1 * q.1,
+            sums.2 + (p.0 + p.1) * (q.0 + q.1),
+        )
+    }
+    pub fn base_evaluate(&self) -> (F, F, F) {
+        let bitmask: usize = 1 << (self.num_free_variables() - 1);
+        // a chunk of each half of both streams at a time
+        let (sum_0, sum_1, sum_half) = fold_range(
+            bitmask.div_ceil(EVALUATIONS_CHUNK_SIZE),
+            || {
+                (
+                    F::BasePrimeField::ZERO,
+                    F::BasePrimeField::ZERO,
+                    F::BasePrimeField::ZERO,
+                )
+            },
+            |mut sums, chunk| {
+                let start = chunk * EVALUATIONS_CHUNK_SIZE;
+                let chunk_len = (bitmask - start).min(EVALUATIONS_CHUNK_SIZE);
+                let mut p_zero = vec![F::BasePrimeField::ZERO; chunk_len];
+                let mut p_one = vec![F::BasePrimeField::ZERO; chunk_len];
+                let mut q_zero = vec![F::BasePrimeField::ZERO; chunk_len];
+                let mut q_one = vec![F::BasePrimeField::ZERO; chunk_len];
+                self.streams[0].evaluations_into(start, &mut p_zero);
+                self.streams[0].evaluations_into(start | bitmask, &mut p_one);
+                self.streams[1].evaluations_into(start, &mut q_zero);
+                self.streams[1].evaluations_into(start | bitmask, &mut q_one);
+                for (((p_0, p_1), q_0), q_1) in p_zero.into_iter().zip(p_one).zip(q_zero).zip(q_one)
+                {
+                    sums = Self::accumulate(sums, (p_0, p_1), (q_0, q_1));
+                }
+                sums
+            },
+            |a, b| (a.0 + b.0, a.1 + b.1, a.2 + b.2),
+        );
+        (
+            F::from_base_prime_field(sum_0),
+            F::from_base_prime_field(sum_1),
+            F::from_base_prime_field(sum_half) * self.inverse_four,
+        )
+    }
+    pub fn vsbw_evaluate(&self) -> (F, F, F) {
+        let (evaluations_p, evaluations_q) = self.evaluations.as_ref().unwrap();
+        let bitmask: usize = 1 << (self.num_free_variables() - 1);
+        let (sum_0, sum_1, sum_half) = fold_range(
+            bitmask,
+            || (F::ZERO, F::ZERO, F::ZERO),
+            |sums, i| {
+                let p = (evaluations_p[i], evaluations_p[i | bitmask]);
+                let q = (evaluations_q[i], evaluations_q[i | bitmask]);
+                Self::accumulate(sums, p, q)
+            },
+            |a, b| (a.0 + b.0, a.1 + b.1, a.2 + b.2),
+        );
+        (sum_0, sum_1, sum_half * self.inverse_four)
+    }
+    fn lift_stream(&self, stream: &S, verifier_message: F) -> Vec<F> {
+        // p(r) = p(0) + r * (p(1) - p(0)), where only the multiplication is in the extension
+        map_stream_halves(stream, |zero, one| {
+            F::from_base_prime_field(zero) + verifier_message.mul_by_base_prime_field(&(one - zero))
+        })
+    }
+    pub fn lift_evaluations(&mut self, verifier_message: F) {
+        self.evaluations = Some((
+            self.lift_stream(&self.streams[0], verifier_message),
+            self.lift_stream(&self.streams[1], verifier_message),
+        ));
+    }
+    fn reduce(&self, evaluations: &[F], verifier_message: F) -> Vec<F> {
+        let setbit: usize = 1 << self.num_free_variables();
+        cfg_into_iter!(0..setbit)
+            .map(|i0| {
+                let zero = evaluations[i0];
+                zero + verifier_message * (evaluations[i0 | setbit] - zero)
+            })
+            .collect()
+    }
+    pub fn vsbw_reduce_evaluations(&mut self, verifier_message: F) {
+        let (evaluations_p, evaluations_q) = self.evaluations.as_ref().unwrap();
+        self.evaluations = Some((
+            self.reduce(evaluations_p, verifier_message),
+            self.reduce(evaluations_q, verifier_message),
+        ));
+    }
+}
@@ -0,0 +1,161 @@
+use ark_ff::Field;
+
+use crate::{
+    multilinear_product::{ExtensionProductProver, ExtensionProductProverConfig},
+    prover::Prover,
+    streams::Stream,
+};
+
+impl<F: Field, S: Stream<F::BasePrimeField>> Prover<F> for ExtensionProductProver<F, S> {
+    type ProverConfig = ExtensionProductProverConfig<F, S>;
+    type ProverMessage = Option<(F, F, F)>;
+    type VerifierMessage = Option<F>;
+
+    fn claim(&self) -> F {
+        self.claim
+    }
+
+    fn new(prover_config: Self::ProverConfig) -> Self {
+        Self {
+            claim: prover_config.claim,
+            current_round: 0,
+            evaluations: None,
+            streams: prover_config.streams,
+            num_variables: prover_config.num_variables,
+            inverse_four: F::from(4_u32).inverse().unwrap(),
+        }
+    }
+
+    fn next_message(&mut self, verifier_message: Option<F>) -> Option<(F, F, F)> {
+        // Ensure the current round is within bounds
+        if self.current_round >= self.total_rounds() {
+            return None;
+        }
+
+        // the first round stays in the base field, the first challenge moves everything to the extension
+        let sums = match self.current_round {
+            0 => self.base_evaluate(),
+            1 => {
+                self.lift_evaluations(verifier_message.unwrap());
+                self.vsbw_evaluate()
+            }
+            _ => {
+                self.vsbw_reduce_evaluations(verifier_message.unwrap());
+                self.vsbw_evaluate()
+            }
+        };
+
+        // Increment the round counter
+        self.current_round += 1;
+
+        Some(sums)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{Field, UniformRand};
+
+    use crate::{
+        error::SumcheckError,
+        multilinear_product::{
+            ExtensionProductProver, ExtensionProductProverConfig, TimeProductProver,
+            TimeProductProverConfig,
+        },
+        prover::Prover,
+        streams::{multivariate_product_claim, MemoryStream},
+        tests::{F64Ext2, F64},
+        ProductSumcheck,
+    };
+
+    #[test]
+    fn parity_with_time_prover() {
+        let num_variables = 8;
+        let mut rng = ark_std::test_rng();
+        let base_streams: Vec<MemoryStream<F64>> = (0..2)
+            .map(|_| {
+                MemoryStream::new(
+                    (0..1 << num_variables)
+                        .map(|_| F64::rand(&mut rng))
+                        .collect(),
+                )
+            })
+            .collect();
+        let claim =
+            F64Ext2::from_base_prime_field(multivariate_product_claim(base_streams.clone()));
+
+        // the same evaluations lifted to the extension
+        let lifted_streams: Vec<MemoryStream<F64Ext2>> = base_streams
+            .iter()
+            .map(|stream| {
+                MemoryStream::new(
+                    stream
+                        .evaluations
+                        .iter()
+                        .map(|evaluation| F64Ext2::from_base_prime_field(*evaluation))
+                        .collect(),
+                )
+            })
+            .collect();
+        let extension_transcript = ProductSumcheck::<F64Ext2>::prove::<
+            MemoryStream<F64Ext2>,
+            ExtensionProductProver<F64Ext2, MemoryStream<F64>>,
+        >(
+            &mut ExtensionProductProver::new(ExtensionProductProverConfig::new(
+                claim,
+                num_variables,
+                base_streams,
+            )),
+            &mut ark_std::test_rng(),
+        );
+        let time_transcript = ProductSumcheck::<F64Ext2>::prove::<
+            MemoryStream<F64Ext2>,
+            TimeProductProver<F64Ext2, MemoryStream<F64Ext2>>,
+        >(
+            &mut TimeProductProver::new(TimeProductProverConfig::new(
+                claim,
+                num_variables,
+                lifted_streams,
+            )),
+            &mut ark_std::test_rng(),
+        );
+
+        assert!(extension_transcript.is_accepted);
+        assert_eq!(extension_transcript, time_transcript);
+    }
+
+    #[test]
+    fn invalid_configs() {
+        let s: MemoryStream<F64> = MemoryStream::new(vec![F64::from(1_u32); 1 << 4]);
+        let claim = F64Ext2::from(0_u32);
+        assert!(matches!(
+            ExtensionProductProverConfig::<F64Ext2, _>::try_new(claim, 4, vec![s.clone()]),
+            Err(SumcheckError::NumStreamsMismatch {
+                expected: 2,
+                found: 1
+            })
+        ));
+        assert!(matches!(
+            ExtensionProductProverConfig::<F64Ext2, _>::try_new(claim, 4, vec![s.clone(); 3]),
+            Err(SumcheckError::NumStreamsMismatch {
+                expected: 2,
+                found: 3
+            })
+        ));
+        assert!(matches!(
+            ExtensionProductProverConfig::<F64Ext2, _>::try_new(claim, 5, vec![s.clone(); 2]),
+            Err(SumcheckError::NumVariablesMismatch {
+                expected: 5,
+                found: 4
+            })
+        ));
+        assert!(ExtensionProductProverConfig::<F64Ext2, _>::try_new(claim, 4, vec![s; 2]).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "exactly two streams")]
+    fn one_stream() {
+        let s: MemoryStream<F64> = MemoryStream::new(vec![F64::from(1_u32); 1 << 4]);
+        ExtensionProductProverConfig::<F64Ext2, _>::new(F64Ext2::from(0_u32), 4, vec![s]);
+    }
+}
@@ -1,3 +1,4 @@
 pub mod blendy;
+pub mod extension;
 pub mod space;
 pub mod time;
@@ -1,6 +1,10 @@
 use ark_ff::Field;
 
-use crate::{prover::ProductProverConfig, streams::Stream};
+use crate::{
+    error::SumcheckError,
+    prover::ProductProverConfig,
+    streams::{check_streams, Stream},
+};
 
 pub struct SpaceProductProverConfig<F, S>
 where
@@ -24,6 +28,17 @@ where
             streams,
         }
     }
+    pub fn try_new(claim: F, num_variables: usize, streams: Vec<S>) -> Result<Self, SumcheckError> {
+        check_streams(&streams, num_variables)?;
+        // the product is of exactly two streams
+        if streams.len() != 2 {
+            return Err(SumcheckError::NumStreamsMismatch {
+                expected: 2,
+                found: streams.len(),
+            });
+        }
+        Ok(Self::new(claim, num_variables, streams))
+    }
 }
 
 impl<F: Field, S: Stream<F>> ProductProverConfig<F, S> for SpaceProductProverConfig<F, S> {
@@ -3,7 +3,6 @@ use ark_ff::Field;
 use crate::{
     messages::VerifierMessages,
     multilinear_product::{SpaceProductProver, SpaceProductProverConfig},
-    order_strategy::SignificantBitOrder,
     prover::Prover,
     streams::{Stream, StreamIterator},
 };
@@ -18,16 +17,15 @@ impl<F: Field, S: Stream<F>> Prover<F> for SpaceProductProver<F, S> {
     }
 
     fn new(prover_config: Self::ProverConfig) -> Self {
-        let stream_iterators = prover_config
-            .streams
-            .iter()
-            .cloned()
-            .map(|s| StreamIterator::<F, S, SignificantBitOrder>::new(s))
-            .collect();
-
         Self {
             claim: prover_config.claim,
-            stream_iterators: stream_iterators,
+            stream_iterators: prover_config
+                .streams
+                .iter()
+                .cloned()
+                .map(StreamIterator::new)
+                .collect(),
+            streams: prover_config.streams,
             verifier_messages: VerifierMessages::new(&vec![]),
             current_round: 0,
             num_variables: prover_config.num_variables,
@@ -1,7 +1,12 @@
 use ark_ff::Field;
-use ark_std::vec::Vec;
+use ark_std::{cfg_iter, cfg_iter_mut, vec::Vec};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
-use crate::streams::Stream;
+use crate::{
+    parallel::fold_range,
+    streams::{reduce_stream_evaluations, Stream, EVALUATIONS_CHUNK_SIZE},
+};
 
 pub struct TimeProductProver<F: Field, S: Stream<F>> {
     pub claim: F,
@@ -26,68 +31,54 @@ impl<'a, F: Field, S: Stream<F>> TimeProductProver<F, S> {
     pub fn vsbw_evaluate(&self) -> (F, F, F) {
         // Initialize accumulators
         let mut sum_half = F::ZERO;
-        let mut j_prime_table: ((F, F), (F, F)) = ((F::ZERO, F::ZERO), (F::ZERO, F::ZERO));
 
         // Calculate the bitmask for the number of free variables
         let bitmask: usize = 1 << (self.num_free_variables() - 1);
 
-        // Determine the length of evaluations to iterate through
-        let evaluations_len = match &self.evaluations[0] {
-            Some(evaluations) => evaluations.len(),
-            None => match &self.streams {
-                Some(streams) => 2usize.pow(streams[0].num_variables() as u32),
-                None => panic!("Both streams and evaluations cannot be None"),
+        // Iterate through evaluations, a chunk of each half of each factor at a time
+        let j_prime_table: ((F, F), (F, F)) = fold_range(
+            bitmask.div_ceil(EVALUATIONS_CHUNK_SIZE),
+            || ((F::ZERO, F::ZERO), (F::ZERO, F::ZERO)),
+            |mut j_prime_table, chunk| {
+                let start = chunk * EVALUATIONS_CHUNK_SIZE;
+                let chunk_len = (bitmask - start).min(EVALUATIONS_CHUNK_SIZE);
+
+                // get all the values
+                let mut p_zero = vec![F::ZERO; chunk_len];
+                let mut q_zero = vec![F::ZERO; chunk_len];
+                let mut p_one = vec![F::ZERO; chunk_len];
+                let mut q_one = vec![F::ZERO; chunk_len];
+                self.point_evaluations(0, start, &mut p_zero);
+                self.point_evaluations(1, start, &mut q_zero);
+                self.point_evaluations(0, start | bitmask, &mut p_one);
+                self.point_evaluations(1, start | bitmask, &mut q_one);
+
+                for offset in 0..chunk_len {
+                    // these must be zeroed out
+                    let mut x_table: (F, F) = (F::ZERO, F::ZERO);
+                    let mut y_table: (F, F) = (F::ZERO, F::ZERO);
+
+                    // update tables
+                    x_table.0 += p_zero[offset];
+                    y_table.0 += q_zero[offset];
+                    y_table.1 += q_one[offset];
+                    x_table.1 += p_one[offset];
+
+                    // update j_prime
+                    j_prime_table.0 .0 = j_prime_table.0 .0 + x_table.0 * y_table.0;
+                    j_prime_table.1 .1 = j_prime_table.1 .1 + x_table.1 * y_table.1;
+                    j_prime_table.0 .1 = j_prime_table.0 .1 + x_table.0 * y_table.1;
+                    j_prime_table.1 .0 = j_prime_table.1 .0 + x_table.1 * y_table.0;
+                }
+                j_prime_table
             },
-        };
-
-        // Iterate through evaluations
-        for i in 0..(evaluations_len / 2) {
-            // these must be zeroed out
-            let mut x_table: (F, F) = (F::ZERO, F::ZERO);
-            let mut y_table: (F, F) = (F::ZERO, F::ZERO);
-
-            // get all the values
-            let p_zero = match &self.evaluations[0] {
-                None => match &self.streams {
-                    Some(streams) => streams[0].evaluation(i),
-                    None => panic!("Both streams and evaluations cannot be None"),
-                },
-                Some(evaluations_p) => evaluations_p[i],
-            };
-            let q_zero = match &self.evaluations[1] {
-                None => match &self.streams {
-                    Some(streams) => streams[1].evaluation(i),
-                    None => panic!("Both streams and evaluations cannot be None"),
-                },
-                Some(evaluations_q) => evaluations_q[i],
-            };
-            let p_one = match &self.evaluations[0] {
-                None => match &self.streams {
-                    Some(streams) => streams[0].evaluation(i | bitmask),
-                    None => panic!("Both streams and evaluations cannot be None"),
-                },
-                Some(evaluations_p) => evaluations_p[i | bitmask],
-            };
-            let q_one = match &self.evaluations[1] {
-                None => match &self.streams {
-                    Some(streams) => streams[1].evaluation(i | bitmask),
-                    None => panic!("Both streams and evaluations cannot be None"),
-                },
-                Some(evaluations_q) => evaluations_q[i | bitmask],
-            };
-
-            // update tables
-            x_table.0 += p_zero;
-            y_table.0 += q_zero;
-            y_table.1 += q_one;
-            x_table.1 += p_one;
-
-            // update j_prime
-            j_prime_table.0 .0 = j_prime_table.0 .0 + x_table.0 * y_table.0;
-            j_prime_table.1 .1 = j_prime_table.1 .1 + x_table.1 * y_table.1;
-            j_prime_table.0 .1 = j_prime_table.0 .1 + x_table.0 * y_table.1;
-            j_prime_table.1 .0 = j_prime_table.1 .0 + x_table.1 * y_table.0;
-        }
+            |a, b| {
+                (
+                    (a.0 .0 + b.0 .0, a.0 .1 + b.0 .1),
+                    (a.1 .0 + b.1 .0, a.1 .1 + b.1 .1),
+                )
+            },
+        );
 
         // update
         let sum_0 = j_prime_table.0 .0;
@@ -98,59 +89,43 @@ impl<'a, F: Field, S: Stream<F>> TimeProductProver<F, S> {
 
         (sum_0, sum_1, sum_half)
     }
+    fn point_evaluations(&self, factor: usize, start: usize, evaluations: &mut [F]) {
+        match &self.evaluations[factor] {
+            None => match &self.streams {
+                Some(streams) => streams[factor].evaluations_into(start, evaluations),
+                // every config has streams, and the blendy provers fill in the evaluations before switching
+                None => unreachable!("Both streams and evaluations cannot be None"),
+            },
+            Some(table) => evaluations.copy_from_slice(&table[start..start + evaluations.len()]),
+        }
+    }
     pub fn vsbw_reduce_evaluations(&mut self, verifier_message: F, verifier_message_hat: F) {
         for i in 0..self.evaluations.len() {
-            // Clone or initialize the evaluations vector
-            let mut evaluations = match &self.evaluations[i] {
-                Some(evaluations) => evaluations.clone(),
-                None => match &self.streams {
-                    Some(streams) => vec![
-                        F::ZERO;
-                        2usize.pow(streams[i].num_variables().try_into().unwrap())
-                            / 2
-                    ],
-                    None => panic!("Both streams and evaluations cannot be None"),
-                },
-            };
-
-            // Determine the length of evaluations to iterate through
-            let evaluations_len = match &self.evaluations[i] {
-                Some(evaluations) => evaluations.len() / 2,
-                None => evaluations.len(),
-            };
-
             // Calculate what bit needs to be set to index the second half of the last round's evaluations
             let setbit: usize = 1 << self.num_free_variables();
 
-            // Iterate through pairs of evaluations
-            for i0 in 0..evaluations_len {
-                let i1 = i0 | setbit;
-
-                // Get point evaluations for indices i0 and i1
-                let point_evaluation_i0 = match &self.evaluations[i] {
-                    None => match &self.streams {
-                        Some(streams) => streams[i].evaluation(i0),
-                        None => panic!("Both streams and evaluations cannot be None"),
-                    },
-                    Some(evaluations) => evaluations[i0],
-                };
-                let point_evaluation_i1 = match &self.evaluations[i] {
-                    None => match &self.streams {
-                        Some(streams) => streams[i].evaluation(i1),
-                        None => panic!("Both streams and evaluations cannot be None"),
-                    },
-                    Some(evaluations) => evaluations[i1],
-                };
-                // Update the i0-th evaluation based on the reduction operation
-                evaluations[i0] = point_evaluation_i0 * verifier_message_hat
-                    + point_evaluation_i1 * verifier_message;
-            }
-
-            // Truncate the evaluations vector to the correct length
-            evaluations.truncate(evaluations_len);
+            // Iterate through pairs of evaluations, the first fold reads the stream a chunk at a time
+            let evaluations: Vec<F> = match (self.evaluations[i].take(), &self.streams) {
+                // later folds write the first half in place, so the table never grows
+                (Some(mut evaluations), _) => {
+                    let (zeros, ones) = evaluations.split_at_mut(setbit);
+                    cfg_iter_mut!(zeros)
+                        .zip(cfg_iter!(ones))
+                        .for_each(|(zero, one)| {
+                            *zero = *zero * verifier_message_hat + *one * verifier_message
+                        });
+                    evaluations.truncate(setbit);
+                    evaluations.shrink_to_fit();
+                    evaluations
+                }
+                (None, Some(streams)) => {
+                    reduce_stream_evaluations(&streams[i], verifier_message, verifier_message_hat)
+                }
+                (None, None) => unreachable!("Both streams and evaluations cannot be None"),
+            };
 
             // Update the internal state with the new evaluations vector
-            self.evaluations[i] = Some(evaluations.clone());
+            self.evaluations[i] = Some(evaluations);
         }
     }
 }
@@ -2,14 +2,23 @@ use ark_ff::Field;
 use ark_std::{rand::Rng, vec::Vec};
 
 use crate::{
-    interpolation::LagrangePolynomial, order_strategy::GraycodeOrder, prover::Prover,
+    error::SumcheckError,
+    interpolation::LagrangePolynomial,
+    messages::{CompressedProof, Nodes, RoundPolynomial},
+    multilinear_product::ProductSumcheckVerifier,
+    order_strategy::GraycodeOrder,
+    prover::Prover,
     streams::Stream,
+    transcript::Transcript,
+    verifier::Verifier,
 };
 
 #[derive(Debug, PartialEq)]
 pub struct ProductSumcheck<F: Field> {
     pub prover_messages: Vec<(F, F, F)>,
     pub verifier_messages: Vec<F>,
+    // None when the transcript was rejected
+    pub reduced_claim: Option<F>,
     pub is_accepted: bool,
 }
 
@@ -18,6 +27,68 @@ impl<F: Field> ProductSumcheck<F> {
     where
         S: Stream<F>,
         P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F, F)>>,
+    {
+        Self::run(prover, |_| F::rand(rng))
+    }
+
+    pub fn prove_non_interactive<S, P, T>(prover: &mut P, transcript: &mut T) -> Self
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F, F)>>,
+        T: Transcript<F>,
+    {
+        // Fiat-Shamir: each verifier message is derived from the claim and all prover messages so far
+        transcript.absorb(&[prover.claim()]);
+        Self::run(prover, |message| {
+            ProductSumcheckVerifier::<F>::absorb_prover_message(transcript, message);
+            transcript.challenge()
+        })
+    }
+
+    // like prove, but a rejected transcript is an error
+    pub fn try_prove<S, P>(prover: &mut P, rng: &mut impl Rng) -> Result<Self, SumcheckError>
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F, F)>>,
+    {
+        Self::prove::<S, P>(prover, rng).accepted()
+    }
+
+    pub fn try_prove_non_interactive<S, P, T>(
+        prover: &mut P,
+        transcript: &mut T,
+    ) -> Result<Self, SumcheckError>
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F, F)>>,
+        T: Transcript<F>,
+    {
+        Self::prove_non_interactive::<S, P, T>(prover, transcript).accepted()
+    }
+
+    // the prover messages in coefficient form, e.g. for a verifier with another message format
+    pub fn round_polynomials(&self) -> Vec<RoundPolynomial<F>> {
+        self.prover_messages
+            .iter()
+            .map(|message| RoundPolynomial::from(*message))
+            .collect()
+    }
+    // the proof as the compressed round polynomials at the given nodes
+    pub fn compressed_proof(&self, nodes: Nodes<F>) -> Result<CompressedProof<F>, SumcheckError> {
+        CompressedProof::new(&self.round_polynomials(), nodes)
+    }
+
+    fn accepted(self) -> Result<Self, SumcheckError> {
+        match self.is_accepted {
+            true => Ok(self),
+            // the rejected message is the last one
+            false => Err(SumcheckError::Rejected(self.prover_messages.len() - 1)),
+        }
+    }
+
+    fn run<P>(prover: &mut P, mut next_verifier_message: impl FnMut(&(F, F, F)) -> F) -> Self
+    where
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F, F)>>,
     {
         // Initialize vectors to store prover and verifier messages
         let mut prover_messages: Vec<(F, F, F)> = vec![];
@@ -49,13 +120,31 @@ impl<F: Field> ProductSumcheck<F> {
                 break;
             }
 
-            verifier_message = Some(F::rand(rng));
+            verifier_message = Some(next_verifier_message(prover_messages.last().unwrap()));
         }
 
+        // The last verifier message reduces the claim to the value f(r_1, ..., r_n) should take
+        let reduced_claim = match (is_accepted, prover_messages.last(), verifier_message) {
+            (true, Some(last_prover_message), Some(last_verifier_message)) => {
+                verifier_messages.push(last_verifier_message);
+                Some(
+                    LagrangePolynomial::<F, GraycodeOrder>::evaluate_from_three_points(
+                        last_verifier_message,
+                        *last_prover_message,
+                    ),
+                )
+            }
+            // there were no rounds at all
+            (true, _, _) => Some(prover.claim()),
+            // rejected, so there is nothing to reduce
+            (false, _, _) => None,
+        };
+
         // Return a Sumcheck struct with the collected messages and acceptance status
         ProductSumcheck {
             prover_messages,
             verifier_messages,
+            reduced_claim,
             is_accepted,
         }
     }
@@ -63,10 +152,20 @@ impl<F: Field> ProductSumcheck<F> {
 
 #[cfg(test)]
 mod tests {
+    use super::ProductSumcheck;
     use crate::{
-        multilinear_product::TimeProductProver,
+        error::SumcheckError,
+        multilinear_product::{
+            BlendyProductProver, BlendyProductProverConfig, ProductSumcheckVerifier,
+            ProductSumcheckVerifierConfig, TimeProductProver, TimeProductProverConfig,
+        },
+        prover::{ProductProverConfig, Prover},
+        streams::{multivariate_product_claim, multivariate_product_evaluation},
         tests::{multilinear_product::consistency_test, BenchStream, F64},
+        transcript::HashTranscript,
+        verifier::Verifier,
     };
+    use sha2::Sha256;
 
     #[test]
     fn algorithm_consistency() {
@@ -74,4 +173,112 @@ mod tests {
         // should take ordering of the stream
         // consistency_test::<F64, BenchStream<F64>, BlendyProductProver<F64, BenchStream<F64>>>();
     }
+
+    #[test]
+    fn final_oracle_check() {
+        // take two evaluation streams
+        let s: BenchStream<F64> = BenchStream::new(10);
+        let claim = multivariate_product_claim(vec![s.clone(), s.clone()]);
+        // run the protocol
+        let transcript = ProductSumcheck::<F64>::prove::<
+            BenchStream<F64>,
+            BlendyProductProver<F64, BenchStream<F64>>,
+        >(
+            &mut BlendyProductProver::new(BlendyProductProverConfig::default(
+                claim,
+                10,
+                vec![s.clone(), s.clone()],
+            )),
+            &mut ark_std::test_rng(),
+        );
+        assert!(transcript.is_accepted);
+        assert_eq!(transcript.verifier_messages.len(), 10);
+        // the reduced claim should be f(r_1, ..., r_n) * g(r_1, ..., r_n)
+        assert_eq!(
+            transcript.reduced_claim,
+            Some(multivariate_product_evaluation(
+                vec![s.clone(), s],
+                &transcript.verifier_messages
+            ))
+        );
+        // in coefficient form each round polynomial sums to the previous one at the challenge
+        let round_polynomials = transcript.round_polynomials();
+        assert_eq!(round_polynomials[0].sum_over_boolean(), claim);
+        for (i, r) in transcript.verifier_messages.iter().enumerate() {
+            let evaluation = round_polynomials[i].evaluate(*r);
+            match round_polynomials.get(i + 1) {
+                Some(next) => assert_eq!(next.sum_over_boolean(), evaluation),
+                None => assert_eq!(transcript.reduced_claim, Some(evaluation)),
+            }
+        }
+        // and the standalone verifier should agree
+        assert_eq!(
+            ProductSumcheckVerifier::new(ProductSumcheckVerifierConfig::new(claim, 10))
+                .verify(&transcript.prover_messages, &transcript.verifier_messages),
+            Some((
+                transcript.verifier_messages.clone(),
+                transcript.reduced_claim.unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn non_interactive() {
+        // take two evaluation streams
+        let s: BenchStream<F64> = BenchStream::new(10);
+        let claim = multivariate_product_claim(vec![s.clone(), s.clone()]);
+        // run the protocol with two different provers
+        let time_prover_transcript = ProductSumcheck::<F64>::prove_non_interactive::<
+            BenchStream<F64>,
+            TimeProductProver<F64, BenchStream<F64>>,
+            HashTranscript<F64, Sha256>,
+        >(
+            &mut TimeProductProver::new(ProductProverConfig::default(
+                claim,
+                10,
+                vec![s.clone(), s.clone()],
+            )),
+            &mut HashTranscript::new(b"product sumcheck"),
+        );
+        let blendy_prover_transcript = ProductSumcheck::<F64>::prove_non_interactive::<
+            BenchStream<F64>,
+            BlendyProductProver<F64, BenchStream<F64>>,
+            HashTranscript<F64, Sha256>,
+        >(
+            &mut BlendyProductProver::new(BlendyProductProverConfig::default(
+                claim,
+                10,
+                vec![s.clone(), s.clone()],
+            )),
+            &mut HashTranscript::new(b"product sumcheck"),
+        );
+        assert!(time_prover_transcript.is_accepted);
+        // the transcript only depends on the messages, so should be identical
+        assert_eq!(time_prover_transcript, blendy_prover_transcript);
+        // the verifier can rederive the verifier messages
+        assert_eq!(
+            ProductSumcheckVerifier::new(ProductSumcheckVerifierConfig::new(claim, 10))
+                .verify_non_interactive(
+                    &time_prover_transcript.prover_messages,
+                    &mut HashTranscript::<F64, Sha256>::new(b"product sumcheck"),
+                ),
+            Some((
+                time_prover_transcript.verifier_messages.clone(),
+                time_prover_transcript.reduced_claim.unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn extra_streams() {
+        // a third factor isn't silently dropped
+        let s: BenchStream<F64> = BenchStream::new(4);
+        assert!(matches!(
+            TimeProductProverConfig::try_new(F64::from(0), 4, vec![s.clone(), s.clone(), s]),
+            Err(SumcheckError::NumStreamsMismatch {
+                expected: 2,
+                found: 3
+            })
+        ));
+    }
 }
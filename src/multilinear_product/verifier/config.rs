@@ -0,0 +1,30 @@
+use ark_ff::Field;
+
+use crate::{batched::batched_claim, verifier::VerifierConfig};
+
+pub struct ProductSumcheckVerifierConfig<F: Field> {
+    pub num_variables: usize,
+    pub claim: F,
+}
+
+impl<F: Field> ProductSumcheckVerifierConfig<F> {
+    pub fn new(claim: F, num_variables: usize) -> Self {
+        Self {
+            claim,
+            num_variables,
+        }
+    }
+    // a batch of claims is checked as the one claim sum_i c_i * claim_i
+    pub fn new_batched(claims: &[F], coefficients: &[F], num_variables: usize) -> Self {
+        Self::new(batched_claim(claims, coefficients), num_variables)
+    }
+}
+
+impl<F: Field> VerifierConfig<F> for ProductSumcheckVerifierConfig<F> {
+    fn default(claim: F, num_variables: usize) -> Self {
+        Self {
+            claim,
+            num_variables,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod config;
+mod verifier;
+
+pub use config::ProductSumcheckVerifierConfig;
+pub use verifier::ProductSumcheckVerifier;
@@ -0,0 +1,141 @@
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::{
+    interpolation::LagrangePolynomial,
+    messages::{Nodes, RoundPolynomial},
+    multilinear_product::ProductSumcheckVerifierConfig,
+    order_strategy::GraycodeOrder,
+    transcript::Transcript,
+    verifier::Verifier,
+};
+
+pub struct ProductSumcheckVerifier<F: Field> {
+    pub claim: F,
+    pub current_round: usize,
+    pub num_variables: usize,
+    pub running_claim: F,
+    pub verifier_messages: Vec<F>,
+}
+
+impl<F: Field> Verifier<F> for ProductSumcheckVerifier<F> {
+    type VerifierConfig = ProductSumcheckVerifierConfig<F>;
+    type ProverMessage = (F, F, F);
+
+    fn claim(&self) -> F {
+        self.claim
+    }
+
+    fn new(verifier_config: Self::VerifierConfig) -> Self {
+        Self {
+            claim: verifier_config.claim,
+            current_round: 0,
+            num_variables: verifier_config.num_variables,
+            running_claim: verifier_config.claim,
+            verifier_messages: Vec::with_capacity(verifier_config.num_variables),
+        }
+    }
+
+    fn next_message(&mut self, prover_message: &(F, F, F), verifier_message: F) -> Option<F> {
+        // Ensure the current round is within bounds
+        if self.current_round >= self.num_variables {
+            return None;
+        }
+
+        // g(0) + g(1) should equal the claim carried over from the last round
+        if prover_message.0 + prover_message.1 != self.running_claim {
+            return None;
+        }
+
+        // reduce the claim to g(r), interpolating the quadratic from g(0), g(1) and g(1/2)
+        self.running_claim = LagrangePolynomial::<F, GraycodeOrder>::evaluate_from_three_points(
+            verifier_message,
+            *prover_message,
+        );
+        self.verifier_messages.push(verifier_message);
+
+        // don't forget to increment the round
+        self.current_round += 1;
+
+        Some(self.running_claim)
+    }
+
+    fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+
+    fn degree(&self) -> usize {
+        2
+    }
+
+    // the product messages are at the nodes of Nodes::half()
+    fn prover_message(&self, round_polynomial: &RoundPolynomial<F>) -> Option<(F, F, F)> {
+        if round_polynomial.degree() > 2 {
+            return None;
+        }
+        let evaluations: Vec<F> = Nodes::half()
+            .points(3)
+            .into_iter()
+            .map(|point| round_polynomial.evaluate(point))
+            .collect();
+        Some((evaluations[0], evaluations[1], evaluations[2]))
+    }
+
+    fn absorb_prover_message<T: Transcript<F>>(
+        transcript: &mut T,
+        prover_message: &Self::ProverMessage,
+    ) {
+        transcript.absorb(&[prover_message.0, prover_message.1, prover_message.2]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+
+    use crate::{
+        multilinear_product::{
+            ProductSumcheckVerifier, ProductSumcheckVerifierConfig, TimeProductProver,
+            TimeProductProverConfig,
+        },
+        prover::Prover,
+        streams::{multivariate_product_claim, MemoryStream},
+        tests::{polynomials::four_variable_polynomial_evaluations, F19},
+        verifier::Verifier,
+    };
+
+    #[test]
+    fn verify() {
+        // run the prover on its own, as if somebody else produced the transcript
+        let mut rng = ark_std::test_rng();
+        let s: MemoryStream<F19> = MemoryStream::new(four_variable_polynomial_evaluations());
+        let claim = multivariate_product_claim(vec![s.clone(), s.clone()]);
+        let mut prover = TimeProductProver::<F19, MemoryStream<F19>>::new(
+            TimeProductProverConfig::new(claim, 4, vec![s.clone(), s]),
+        );
+        let mut prover_messages: Vec<(F19, F19, F19)> = vec![];
+        let mut verifier_messages: Vec<F19> = vec![];
+        let mut verifier_message: Option<F19> = None;
+        while let Some(message) = prover.next_message(verifier_message) {
+            prover_messages.push(message);
+            verifier_messages.push(F19::rand(&mut rng));
+            verifier_message = verifier_messages.last().copied();
+        }
+
+        // an honest transcript is accepted
+        let mut verifier =
+            ProductSumcheckVerifier::new(ProductSumcheckVerifierConfig::new(claim, 4));
+        let (point, _) = verifier
+            .verify(&prover_messages, &verifier_messages)
+            .unwrap();
+        assert_eq!(point, verifier_messages);
+
+        // a tampered one is not
+        prover_messages[2].1 += F19::from(1);
+        let mut verifier =
+            ProductSumcheckVerifier::new(ProductSumcheckVerifierConfig::new(claim, 4));
+        assert!(verifier
+            .verify(&prover_messages, &verifier_messages)
+            .is_none());
+    }
+}
@@ -17,6 +17,8 @@ impl GraycodeOrder {
 }
 
 impl OrderStrategy for GraycodeOrder {
+    const ID: u8 = 1;
+
     fn new(num_vars: usize) -> Self {
         Self {
             current_index: 0,
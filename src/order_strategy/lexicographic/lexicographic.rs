@@ -7,6 +7,8 @@ pub struct LexicographicOrder {
 }
 
 impl OrderStrategy for LexicographicOrder {
+    const ID: u8 = 0;
+
     fn new(num_vars: usize) -> Self {
         Self {
             current_index: 0,
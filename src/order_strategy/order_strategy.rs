@@ -1,4 +1,6 @@
 pub trait OrderStrategy: Iterator<Item = usize> {
+    // how a file records that its evaluations are in this order
+    const ID: u8;
     fn new(num_variables: usize) -> Self;
     fn next_index(&mut self) -> Option<usize>;
     fn num_vars(&self) -> usize;
@@ -18,9 +18,18 @@ impl SignificantBitOrder {
         }
         result
     }
+    // the value at a given position of the sequence, which is the position with its bits reversed
+    pub fn index_at(position: usize, num_vars: usize) -> usize {
+        match num_vars {
+            0 => 0,
+            _ => position.reverse_bits() >> (usize::BITS as usize - num_vars),
+        }
+    }
 }
 
 impl OrderStrategy for SignificantBitOrder {
+    const ID: u8 = 2;
+
     fn new(num_vars: usize) -> Self {
         Self {
             current_index: 0,
@@ -58,3 +67,17 @@ impl Iterator for SignificantBitOrder {
         self.next_index()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::order_strategy::{OrderStrategy, SignificantBitOrder};
+
+    #[test]
+    fn index_at() {
+        for num_vars in 0..6 {
+            for (position, index) in SignificantBitOrder::new(num_vars).enumerate() {
+                assert_eq!(SignificantBitOrder::index_at(position, num_vars), index);
+            }
+        }
+    }
+}
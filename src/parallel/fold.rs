@@ -0,0 +1,51 @@
+use ark_ff::Field;
+use ark_std::vec::Vec;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::streams::MaybeSync;
+
+/*
+ * Folds over 0..len, with the parallel feature the range is split across rayon threads
+ * and the partial results are combined with reduce. Provers only ever add field elements
+ * here, which is exact, so the result (and so the transcript) doesn't depend on the split
+ */
+pub(crate) fn fold_range<T, I, G, R>(len: usize, identity: I, fold: G, reduce: R) -> T
+where
+    T: Send,
+    I: Fn() -> T + MaybeSync,
+    G: Fn(T, usize) -> T + MaybeSync,
+    R: Fn(T, T) -> T + MaybeSync,
+{
+    #[cfg(feature = "parallel")]
+    {
+        (0..len)
+            .into_par_iter()
+            .fold(&identity, &fold)
+            .reduce(&identity, &reduce)
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        let _ = reduce;
+        (0..len).fold(identity(), fold)
+    }
+}
+
+// reduce for folds that accumulate one sum per evaluation point
+pub(crate) fn add_sums<F: Field>(mut sums: Vec<F>, other_sums: Vec<F>) -> Vec<F> {
+    for (sum, other_sum) in sums.iter_mut().zip(other_sums) {
+        *sum += other_sum;
+    }
+    sums
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fold_range;
+
+    #[test]
+    fn fold_range_sums() {
+        let sum = fold_range(1000, || 0usize, |sum, i| sum + i, |a, b| a + b);
+        assert_eq!(sum, 999 * 1000 / 2);
+    }
+}
@@ -0,0 +1,3 @@
+mod fold;
+
+pub(crate) use fold::{add_sums, fold_range};
@@ -0,0 +1,47 @@
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{BufReader, BufWriter, Read, Write};
+use std::{fs::File, path::Path};
+
+use crate::error::SumcheckError;
+
+// bump this whenever the encoding of any checkpoint changes, 2 added the sparse table of TimeProver
+pub const CHECKPOINT_VERSION: u8 = 2;
+
+/*
+ * A prover that can be stopped between rounds and picked up again later. The
+ * snapshot holds the state and the verifier messages so far but not the streams,
+ * which are passed back in on resume and have to be the same ones.
+ */
+pub trait Checkpoint: Sized {
+    // tells the snapshots of different provers apart
+    const KIND: u8;
+    type Streams;
+    fn checkpoint<W: Write>(&self, writer: W) -> Result<(), SumcheckError>;
+    fn resume<R: Read>(reader: R, streams: Self::Streams) -> Result<Self, SumcheckError>;
+
+    fn save(&self, path: impl AsRef<Path>) -> Result<(), SumcheckError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.checkpoint(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+    fn load(path: impl AsRef<Path>, streams: Self::Streams) -> Result<Self, SumcheckError> {
+        Self::resume(BufReader::new(File::open(path)?), streams)
+    }
+}
+
+pub(crate) fn write_header<W: Write>(mut writer: W, kind: u8) -> Result<(), SumcheckError> {
+    CHECKPOINT_VERSION.serialize_uncompressed(&mut writer)?;
+    kind.serialize_uncompressed(&mut writer)?;
+    Ok(())
+}
+
+pub(crate) fn read_header<R: Read>(mut reader: R, kind: u8) -> Result<(), SumcheckError> {
+    // refuse a snapshot in an unknown encoding or of another prover
+    let version = u8::deserialize_uncompressed(&mut reader)?;
+    let found = u8::deserialize_uncompressed(&mut reader)?;
+    if version != CHECKPOINT_VERSION || found != kind {
+        return Err(SerializationError::InvalidData.into());
+    }
+    Ok(())
+}
@@ -1,2 +1,5 @@
+mod checkpoint;
 mod prover;
-pub use prover::{ProductProverConfig, Prover, ProverConfig};
+pub(crate) use checkpoint::{read_header, write_header};
+pub use checkpoint::{Checkpoint, CHECKPOINT_VERSION};
+pub use prover::{ProductProverConfig, Prover, ProverConfig, VirtualProverConfig};
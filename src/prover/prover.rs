@@ -1,6 +1,6 @@
 use ark_ff::Field;
 
-use crate::streams::Stream;
+use crate::{streams::Stream, virtual_polynomial::VirtualPolynomial};
 pub trait ProverConfig<F: Field, S: Stream<F>> {
     fn default(claim: F, num_variables: usize, stream: S) -> Self;
 }
@@ -9,6 +9,10 @@ pub trait ProductProverConfig<F: Field, S: Stream<F>> {
     fn default(claim: F, num_variables: usize, steams: Vec<S>) -> Self;
 }
 
+pub trait VirtualProverConfig<F: Field, S: Stream<F>> {
+    fn default(claim: F, num_variables: usize, polynomial: VirtualPolynomial<F, S>) -> Self;
+}
+
 pub trait Prover<F: Field> {
     type ProverConfig;
     type ProverMessage;
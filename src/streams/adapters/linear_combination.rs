@@ -0,0 +1,142 @@
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::{
+    error::SumcheckError,
+    streams::{check_streams, Stream},
+};
+
+// sum_i c_i * s_i, evaluated on the fly
+#[derive(Debug, Clone)]
+pub struct LinearCombinationStream<F: Field, S: Stream<F>> {
+    pub streams: Vec<S>,
+    pub coefficients: Vec<F>,
+}
+
+impl<F: Field, S: Stream<F>> LinearCombinationStream<F, S> {
+    pub fn new(streams: Vec<S>, coefficients: Vec<F>) -> Self {
+        Self::try_new(streams, coefficients).unwrap()
+    }
+    pub fn try_new(streams: Vec<S>, coefficients: Vec<F>) -> Result<Self, SumcheckError> {
+        // one coefficient per stream, all of them over the same variables
+        let num_variables = streams
+            .first()
+            .ok_or(SumcheckError::NoStreams)?
+            .num_variables();
+        check_streams(&streams, num_variables)?;
+        if coefficients.len() != streams.len() {
+            return Err(SumcheckError::NumStreamsMismatch {
+                expected: coefficients.len(),
+                found: streams.len(),
+            });
+        }
+        Ok(Self {
+            streams,
+            coefficients,
+        })
+    }
+}
+
+impl<F: Field, S: Stream<F>> Stream<F> for LinearCombinationStream<F, S> {
+    fn evaluation(&self, point: usize) -> F {
+        self.streams
+            .iter()
+            .zip(self.coefficients.iter())
+            .map(|(stream, coefficient)| *coefficient * stream.evaluation(point))
+            .sum()
+    }
+    fn num_variables(&self) -> usize {
+        self.streams[0].num_variables()
+    }
+    fn evaluations_into(&self, start: usize, evaluations: &mut [F]) {
+        // the inner streams are read in bulk too
+        let mut inner_evaluations = vec![F::ZERO; evaluations.len()];
+        evaluations.fill(F::ZERO);
+        for (stream, coefficient) in self.streams.iter().zip(self.coefficients.iter()) {
+            stream.evaluations_into(start, &mut inner_evaluations);
+            for (evaluation, inner_evaluation) in evaluations.iter_mut().zip(&inner_evaluations) {
+                *evaluation += *coefficient * inner_evaluation;
+            }
+        }
+    }
+}
+
+// c * s
+#[derive(Debug, Clone)]
+pub struct ScaledStream<F: Field, S: Stream<F>> {
+    pub stream: S,
+    pub scalar: F,
+}
+
+impl<F: Field, S: Stream<F>> ScaledStream<F, S> {
+    pub fn new(stream: S, scalar: F) -> Self {
+        Self { stream, scalar }
+    }
+}
+
+impl<F: Field, S: Stream<F>> Stream<F> for ScaledStream<F, S> {
+    fn evaluation(&self, point: usize) -> F {
+        self.scalar * self.stream.evaluation(point)
+    }
+    fn num_variables(&self) -> usize {
+        self.stream.num_variables()
+    }
+    fn evaluations_into(&self, start: usize, evaluations: &mut [F]) {
+        self.stream.evaluations_into(start, evaluations);
+        for evaluation in evaluations.iter_mut() {
+            *evaluation *= self.scalar;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+
+    use crate::{
+        error::SumcheckError,
+        streams::{
+            multivariate_evaluation, LinearCombinationStream, MemoryStream, ScaledStream, Stream,
+        },
+        tests::{BenchStream, F64},
+    };
+
+    #[test]
+    fn evaluation() {
+        let mut rng = ark_std::test_rng();
+        let streams: Vec<MemoryStream<F64>> = (0..3)
+            .map(|_| MemoryStream::new((0..1 << 5).map(|_| F64::rand(&mut rng)).collect()))
+            .collect();
+        let coefficients: Vec<F64> = (0..3).map(|_| F64::rand(&mut rng)).collect();
+        let point: Vec<F64> = (0..5).map(|_| F64::rand(&mut rng)).collect();
+
+        // the extension of a linear combination is the linear combination of the extensions
+        let combination = LinearCombinationStream::new(streams.clone(), coefficients.clone());
+        assert_eq!(
+            multivariate_evaluation(combination, &point),
+            streams
+                .iter()
+                .zip(coefficients.iter())
+                .map(|(stream, coefficient)| *coefficient
+                    * multivariate_evaluation(stream.clone(), &point))
+                .sum::<F64>()
+        );
+        let scaled = ScaledStream::new(streams[0].clone(), coefficients[0]);
+        assert_eq!(
+            scaled.evaluation(7),
+            coefficients[0] * streams[0].evaluation(7)
+        );
+
+        // streams over different variables don't combine
+        assert!(matches!(
+            LinearCombinationStream::try_new(
+                vec![BenchStream::<F64>::new(4), BenchStream::new(5)],
+                vec![F64::from(1); 2]
+            ),
+            Err(SumcheckError::NumVariablesMismatch {
+                expected: 4,
+                found: 5
+            })
+        ));
+    }
+}
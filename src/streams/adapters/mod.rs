@@ -0,0 +1,7 @@
+mod linear_combination;
+mod partial_eval;
+mod permuted;
+
+pub use linear_combination::{LinearCombinationStream, ScaledStream};
+pub use partial_eval::{FixedVariables, PartialEvalStream};
+pub use permuted::PermutedVariablesStream;
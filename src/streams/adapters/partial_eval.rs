@@ -0,0 +1,108 @@
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::{
+    hypercube::Hypercube, interpolation::LagrangePolynomial, messages::VerifierMessages,
+    order_strategy::GraycodeOrder, streams::Stream,
+};
+
+// which end of the variables is fixed, the prefix is the most significant bits
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FixedVariables {
+    Prefix,
+    Suffix,
+}
+
+/*
+ * f with some of its variables fixed to field values r, e.g. for the prefix
+ * f'(b) = sum_x eq(r, x) * f(x, b). Nothing is materialized, every evaluation walks
+ * the 2^k points x with a sequential Lagrange polynomial
+ */
+#[derive(Debug, Clone)]
+pub struct PartialEvalStream<F: Field, S: Stream<F>> {
+    pub stream: S,
+    pub point: VerifierMessages<F>,
+    pub fixed: FixedVariables,
+}
+
+impl<F: Field, S: Stream<F>> PartialEvalStream<F, S> {
+    pub fn new(stream: S, point: Vec<F>, fixed: FixedVariables) -> Self {
+        assert!(point.len() <= stream.num_variables());
+        Self {
+            stream,
+            point: VerifierMessages::new(&point),
+            fixed,
+        }
+    }
+    pub fn prefix(stream: S, point: Vec<F>) -> Self {
+        Self::new(stream, point, FixedVariables::Prefix)
+    }
+    pub fn suffix(stream: S, point: Vec<F>) -> Self {
+        Self::new(stream, point, FixedVariables::Suffix)
+    }
+    fn num_fixed_variables(&self) -> usize {
+        self.point.messages.len()
+    }
+}
+
+impl<F: Field, S: Stream<F>> Stream<F> for PartialEvalStream<F, S> {
+    fn evaluation(&self, point: usize) -> F {
+        let num_fixed_variables = self.num_fixed_variables();
+        let num_variables = self.num_variables();
+        let mut sequential_lag_poly: LagrangePolynomial<F, GraycodeOrder> =
+            LagrangePolynomial::new(&self.point);
+        let mut evaluation = F::ZERO;
+        for (x_index, _) in Hypercube::<GraycodeOrder>::new(num_fixed_variables) {
+            let lag_poly = sequential_lag_poly.next().unwrap();
+            if lag_poly != F::ZERO {
+                let index = match self.fixed {
+                    FixedVariables::Prefix => x_index << num_variables | point,
+                    FixedVariables::Suffix => point << num_fixed_variables | x_index,
+                };
+                evaluation += lag_poly * self.stream.evaluation(index);
+            }
+        }
+        evaluation
+    }
+    fn num_variables(&self) -> usize {
+        self.stream.num_variables() - self.num_fixed_variables()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+
+    use crate::{
+        streams::{multivariate_evaluation, MemoryStream, PartialEvalStream, Stream},
+        tests::F64,
+    };
+
+    #[test]
+    fn evaluation() {
+        let mut rng = ark_std::test_rng();
+        let stream = MemoryStream::new((0..1 << 6).map(|_| F64::rand(&mut rng)).collect());
+        let point: Vec<F64> = (0..6).map(|_| F64::rand(&mut rng)).collect();
+        let expected = multivariate_evaluation(stream.clone(), &point);
+
+        // fixing either end and then the rest gives f(r)
+        for num_fixed in [0, 2, 6] {
+            let prefix = PartialEvalStream::prefix(stream.clone(), point[..num_fixed].to_vec());
+            assert_eq!(prefix.num_variables(), 6 - num_fixed);
+            assert_eq!(
+                multivariate_evaluation(prefix, &point[num_fixed..]),
+                expected
+            );
+
+            let suffix = PartialEvalStream::suffix(stream.clone(), point[6 - num_fixed..].to_vec());
+            assert_eq!(
+                multivariate_evaluation(suffix, &point[..6 - num_fixed]),
+                expected
+            );
+        }
+
+        // fixed to boolean values it's just a slice of the table
+        let prefix = PartialEvalStream::prefix(stream.clone(), vec![F64::from(1), F64::from(0)]);
+        assert_eq!(prefix.evaluation(5), stream.evaluation(0b10 << 4 | 5));
+    }
+}
@@ -0,0 +1,98 @@
+use ark_ff::Field;
+use ark_std::{marker::PhantomData, vec::Vec};
+
+use crate::{error::SumcheckError, streams::Stream};
+
+/*
+ * f with its variables reordered: variable i of this stream is variable
+ * permutation[i] of the original, counting from the most significant bit
+ */
+#[derive(Debug, Clone)]
+pub struct PermutedVariablesStream<F: Field, S: Stream<F>> {
+    pub stream: S,
+    pub permutation: Vec<usize>,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field, S: Stream<F>> PermutedVariablesStream<F, S> {
+    pub fn new(stream: S, permutation: Vec<usize>) -> Self {
+        Self::try_new(stream, permutation).unwrap()
+    }
+    pub fn try_new(stream: S, permutation: Vec<usize>) -> Result<Self, SumcheckError> {
+        let num_variables = stream.num_variables();
+        if permutation.len() != num_variables {
+            return Err(SumcheckError::NumVariablesMismatch {
+                expected: num_variables,
+                found: permutation.len(),
+            });
+        }
+        // every variable should appear exactly once
+        let mut seen = vec![false; num_variables];
+        for variable in permutation.iter() {
+            if *variable >= num_variables || seen[*variable] {
+                return Err(SumcheckError::InvalidPermutation(permutation));
+            }
+            seen[*variable] = true;
+        }
+        Ok(Self {
+            stream,
+            permutation,
+            _field: PhantomData,
+        })
+    }
+}
+
+impl<F: Field, S: Stream<F>> Stream<F> for PermutedVariablesStream<F, S> {
+    fn evaluation(&self, point: usize) -> F {
+        let num_variables = self.num_variables();
+        let index = self
+            .permutation
+            .iter()
+            .enumerate()
+            .fold(0, |index, (variable, original)| {
+                let bit = point >> (num_variables - 1 - variable) & 1;
+                index | bit << (num_variables - 1 - original)
+            });
+        self.stream.evaluation(index)
+    }
+    fn num_variables(&self) -> usize {
+        self.stream.num_variables()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+
+    use crate::{
+        error::SumcheckError,
+        streams::{multivariate_evaluation, MemoryStream, PermutedVariablesStream, Stream},
+        tests::F64,
+    };
+
+    #[test]
+    fn evaluation() {
+        let mut rng = ark_std::test_rng();
+        let stream = MemoryStream::new((0..1 << 4).map(|_| F64::rand(&mut rng)).collect());
+        let permutation = vec![2, 0, 3, 1];
+        let permuted = PermutedVariablesStream::new(stream.clone(), permutation.clone());
+
+        // g(x) = f(y) where y[permutation[i]] = x[i]
+        let x: Vec<F64> = (0..4).map(|_| F64::rand(&mut rng)).collect();
+        let mut y = vec![F64::from(0); 4];
+        for (variable, original) in permutation.iter().enumerate() {
+            y[*original] = x[variable];
+        }
+        assert_eq!(
+            multivariate_evaluation(permuted.clone(), &x),
+            multivariate_evaluation(stream.clone(), &y)
+        );
+        // x = 0b1000 sets variable 0, which is variable 2 of the original
+        assert_eq!(permuted.evaluation(0b1000), stream.evaluation(0b0010));
+
+        assert!(matches!(
+            PermutedVariablesStream::try_new(stream, vec![0, 1, 1, 2]),
+            Err(SumcheckError::InvalidPermutation(_))
+        ));
+    }
+}
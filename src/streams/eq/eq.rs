@@ -0,0 +1,152 @@
+use ark_ff::Field;
+
+use crate::{
+    hypercube::HypercubeMember, interpolation::LagrangePolynomial, messages::VerifierMessages,
+    order_strategy::SignificantBitOrder, streams::Stream,
+};
+
+/*
+ * eq(r, x) computed on the fly from the point r, so it doesn't cost any memory
+ * beyond the point itself, the first variable is the most significant bit of x
+ */
+
+#[derive(Debug, Clone)]
+pub struct EqStream<F: Field> {
+    pub point: VerifierMessages<F>,
+}
+
+impl<F: Field> EqStream<F> {
+    pub fn new(point: Vec<F>) -> Self {
+        Self {
+            point: VerifierMessages::new(&point),
+        }
+    }
+}
+
+impl<F: Field> Stream<F> for EqStream<F> {
+    fn evaluation(&self, point: usize) -> F {
+        // product of r_i where the bit is set and 1 - r_i where it isn't
+        HypercubeMember::new(self.num_variables(), point)
+            .zip(
+                self.point
+                    .messages
+                    .iter()
+                    .zip(self.point.message_hats.iter()),
+            )
+            .fold(F::ONE, |acc, (b_i, (r_i, r_hat_i))| {
+                acc * match b_i {
+                    true => r_i,
+                    false => r_hat_i,
+                }
+            })
+    }
+    fn num_variables(&self) -> usize {
+        self.point.messages.len()
+    }
+    /*
+     * A run covers at most two blocks of the last k variables, where 2^k is the run
+     * length. eq over those is one table from the incremental update of
+     * LagrangePolynomial, and eq over the rest once per block, so it's O(len + n)
+     * rather than n per evaluation
+     */
+    fn evaluations_into(&self, start: usize, evaluations: &mut [F]) {
+        let num_variables = self.num_variables();
+        let num_low = (evaluations.len().next_power_of_two().ilog2() as usize).min(num_variables);
+        let num_high = num_variables - num_low;
+        let low_messages = VerifierMessages::new(&self.point.messages[num_high..].to_vec());
+        let mut low_table = vec![F::ZERO; 1 << num_low];
+        match low_messages.zero_ones_mask {
+            0 => {
+                for (position, value) in
+                    LagrangePolynomial::<F, SignificantBitOrder>::new(&low_messages)
+                        .take(1 << num_low)
+                        .enumerate()
+                {
+                    low_table[SignificantBitOrder::index_at(position, num_low)] = value;
+                }
+            }
+            // the update divides by r_i and 1 - r_i, so with zeros or ones each entry is its own product
+            _ => {
+                for (index, value) in low_table.iter_mut().enumerate() {
+                    *value = LagrangePolynomial::<F, SignificantBitOrder>::lag_poly(
+                        &low_messages.messages,
+                        &low_messages.message_hats,
+                        HypercubeMember::new(num_low, index),
+                    );
+                }
+            }
+        }
+        let mask = (1 << num_low) - 1;
+        let mut block = None;
+        for (offset, evaluation) in evaluations.iter_mut().enumerate() {
+            let point = start + offset;
+            let high = point >> num_low;
+            let high_value = match block {
+                Some((index, value)) if index == high => value,
+                _ => {
+                    let value = LagrangePolynomial::<F, SignificantBitOrder>::lag_poly(
+                        &self.point.messages[..num_high],
+                        &self.point.message_hats[..num_high],
+                        HypercubeMember::new(num_high, high),
+                    );
+                    block = Some((high, value));
+                    value
+                }
+            };
+            *evaluation = high_value * low_table[point & mask];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{Field, UniformRand};
+
+    use crate::{
+        hypercube::Hypercube,
+        interpolation::LagrangePolynomial,
+        messages::VerifierMessages,
+        order_strategy::GraycodeOrder,
+        streams::{multivariate_evaluation, EqStream, Stream},
+        tests::F64,
+    };
+
+    #[test]
+    fn evaluation() {
+        let mut rng = ark_std::test_rng();
+        let r: Vec<F64> = (0..6).map(|_| F64::rand(&mut rng)).collect();
+        let s = EqStream::new(r.clone());
+
+        // should agree with the sequential lagrange polynomial
+        let verifier_messages = VerifierMessages::new(&r);
+        let mut sequential_lag_poly: LagrangePolynomial<F64, GraycodeOrder> =
+            LagrangePolynomial::new(&verifier_messages);
+        for (index, _) in Hypercube::<GraycodeOrder>::new(6) {
+            assert_eq!(s.evaluation(index), sequential_lag_poly.next().unwrap());
+        }
+
+        // runs read at once agree with it too, also where r has zeros and ones
+        let mut with_booleans = r.clone();
+        with_booleans[1] = F64::from(0);
+        with_booleans[4] = F64::ONE;
+        for stream in [s.clone(), EqStream::new(with_booleans)] {
+            for (start, len) in [(0, 64), (3, 17), (30, 5), (63, 1), (8, 0)] {
+                let mut evaluations = vec![F64::from(0); len];
+                stream.evaluations_into(start, &mut evaluations);
+                for (offset, evaluation) in evaluations.into_iter().enumerate() {
+                    assert_eq!(evaluation, stream.evaluation(start + offset));
+                }
+            }
+        }
+
+        // and its multilinear extension is eq(r, ·)
+        let point: Vec<F64> = (0..6).map(|_| F64::rand(&mut rng)).collect();
+        assert_eq!(
+            multivariate_evaluation(s, &point),
+            r.iter()
+                .zip(point.iter())
+                .map(|(r_i, x_i)| *r_i * x_i + (F64::ONE - r_i) * (F64::ONE - x_i))
+                .product::<F64>()
+        );
+    }
+}
@@ -0,0 +1,3 @@
+mod eq;
+
+pub use eq::EqStream;
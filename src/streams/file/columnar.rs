@@ -0,0 +1,487 @@
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress};
+use ark_std::{
+    fs::File,
+    io::{BufWriter, Seek, SeekFrom, Write},
+    path::Path,
+    vec::Vec,
+};
+use memmap2::Mmap;
+
+use crate::{
+    error::SumcheckError,
+    order_strategy::OrderStrategy,
+    streams::{file::check_elements, InterleavedFileStream},
+};
+
+// bump this whenever the layout below changes
+pub const COLUMNAR_FILE_VERSION: u8 = 2;
+const MAGIC: [u8; 4] = *b"SESC";
+// rows per checksum
+pub const DEFAULT_CHUNK_SIZE: usize = 1 << 16;
+// the header is padded so the rows start at a multiple of this, which lets them be cast to limbs
+const ROWS_ALIGNMENT: usize = 8;
+
+// how the elements are laid out on disk
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Representation {
+    // serialize_uncompressed, which is canonical form
+    Canonical,
+    // the limbs of a prime field element as it is in memory, little endian
+    Montgomery,
+}
+
+impl Representation {
+    const fn id(self) -> u8 {
+        match self {
+            Self::Canonical => 0,
+            Self::Montgomery => 1,
+        }
+    }
+    fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Self::Canonical),
+            1 => Some(Self::Montgomery),
+            _ => None,
+        }
+    }
+}
+
+/*
+ * A columnar file is a header followed by the rows of an InterleavedFileStream.
+ * The header records everything needed to refuse reading the file as something
+ * else: the field (modulus of the base field, extension degree and element size),
+ * the representation of the elements, the number of variables, the order of the
+ * evaluations and the number of columns, and one checksum per chunk of rows.
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnarHeader {
+    pub modulus: Vec<u8>,
+    pub extension_degree: u64,
+    pub element_size: u64,
+    pub representation: Representation,
+    pub num_variables: u64,
+    pub order: u8,
+    pub num_columns: u64,
+    pub chunk_size: u64,
+    pub checksums: Vec<u64>,
+}
+
+impl ColumnarHeader {
+    pub fn new<F: Field, O: OrderStrategy>(
+        num_variables: usize,
+        num_columns: usize,
+        chunk_size: usize,
+    ) -> Self {
+        Self {
+            modulus: F::BasePrimeField::MODULUS.to_bytes_le(),
+            extension_degree: F::extension_degree(),
+            element_size: F::ONE.serialized_size(Compress::No) as u64,
+            representation: Representation::Canonical,
+            num_variables: num_variables as u64,
+            order: O::ID,
+            num_columns: num_columns as u64,
+            chunk_size: chunk_size as u64,
+            checksums: vec![],
+        }
+    }
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<(), SumcheckError> {
+        writer.write_all(&MAGIC)?;
+        COLUMNAR_FILE_VERSION.serialize_uncompressed(&mut writer)?;
+        self.modulus.serialize_uncompressed(&mut writer)?;
+        self.extension_degree.serialize_uncompressed(&mut writer)?;
+        self.element_size.serialize_uncompressed(&mut writer)?;
+        self.representation
+            .id()
+            .serialize_uncompressed(&mut writer)?;
+        self.num_variables.serialize_uncompressed(&mut writer)?;
+        self.order.serialize_uncompressed(&mut writer)?;
+        self.num_columns.serialize_uncompressed(&mut writer)?;
+        self.chunk_size.serialize_uncompressed(&mut writer)?;
+        self.checksums.serialize_uncompressed(&mut writer)?;
+        writer.write_all(&vec![0_u8; self.size() - self.unpadded_size()])?;
+        Ok(())
+    }
+    /*
+     * Reads the header of a file meant to hold elements of F. The lengths of the
+     * modulus and the checksums come from the file, so they're bounded before anything
+     * is allocated for them: the modulus by that of F, and the checksums by the number
+     * of chunks the rows make
+     */
+    pub fn read<F: Field>(mut bytes: &[u8]) -> Result<Self, SumcheckError> {
+        let mut magic = [0_u8; 4];
+        ark_std::io::Read::read_exact(&mut bytes, &mut magic)?;
+        if magic != MAGIC {
+            return Err(SumcheckError::HeaderMismatch("magic"));
+        }
+        if u8::deserialize_uncompressed(&mut bytes)? != COLUMNAR_FILE_VERSION {
+            return Err(SumcheckError::HeaderMismatch("version"));
+        }
+        let modulus_len = u64::deserialize_uncompressed(&mut bytes)?;
+        if modulus_len > F::BasePrimeField::MODULUS.to_bytes_le().len() as u64 {
+            return Err(SumcheckError::HeaderMismatch("field"));
+        }
+        let mut modulus = vec![0_u8; modulus_len as usize];
+        ark_std::io::Read::read_exact(&mut bytes, &mut modulus)?;
+        let mut header = Self {
+            modulus,
+            extension_degree: u64::deserialize_uncompressed(&mut bytes)?,
+            element_size: u64::deserialize_uncompressed(&mut bytes)?,
+            representation: Representation::from_id(u8::deserialize_uncompressed(&mut bytes)?)
+                .ok_or(SumcheckError::HeaderMismatch("representation"))?,
+            num_variables: u64::deserialize_uncompressed(&mut bytes)?,
+            order: u8::deserialize_uncompressed(&mut bytes)?,
+            num_columns: u64::deserialize_uncompressed(&mut bytes)?,
+            chunk_size: u64::deserialize_uncompressed(&mut bytes)?,
+            checksums: vec![],
+        };
+        header.rows_len()?;
+        if u64::deserialize_uncompressed(&mut bytes)? != header.num_chunks() as u64 {
+            return Err(SumcheckError::HeaderMismatch("checksums"));
+        }
+        header.checksums = (0..header.num_chunks())
+            .map(|_| u64::deserialize_uncompressed(&mut bytes))
+            .collect::<Result<Vec<u64>, _>>()?;
+        Ok(header)
+    }
+    // the length of the rows, once the fields it's computed from are checked to be usable
+    fn rows_len(&self) -> Result<usize, SumcheckError> {
+        if self.num_variables >= usize::BITS as u64 {
+            return Err(SumcheckError::HeaderMismatch("num_variables"));
+        }
+        if self.num_columns == 0 || self.chunk_size == 0 {
+            return Err(SumcheckError::HeaderMismatch("columns"));
+        }
+        if self.element_size == 0 {
+            return Err(SumcheckError::HeaderMismatch("element size"));
+        }
+        (1_u64 << self.num_variables)
+            .checked_mul(self.num_columns)
+            .and_then(|len| len.checked_mul(self.element_size))
+            .and_then(|len| usize::try_from(len).ok())
+            .ok_or(SumcheckError::HeaderMismatch("num_variables"))
+    }
+    // where the rows start
+    pub fn size(&self) -> usize {
+        self.unpadded_size().next_multiple_of(ROWS_ALIGNMENT)
+    }
+    fn unpadded_size(&self) -> usize {
+        MAGIC.len()
+            + COLUMNAR_FILE_VERSION.uncompressed_size()
+            + self.modulus.uncompressed_size()
+            + 5 * 0_u64.uncompressed_size()
+            + self.representation.id().uncompressed_size()
+            + self.order.uncompressed_size()
+            + self.checksums.uncompressed_size()
+    }
+    // whether the file can be read as columns over F in order O, in the representation it was written in
+    pub fn check<F: Field, O: OrderStrategy>(&self) -> Result<(), SumcheckError> {
+        let expected = Self::new::<F, O>(0, 0, 0);
+        if self.modulus != expected.modulus || self.extension_degree != expected.extension_degree {
+            return Err(SumcheckError::HeaderMismatch("field"));
+        }
+        let expected_element_size = match self.representation {
+            Representation::Canonical => expected.element_size,
+            Representation::Montgomery => ark_std::mem::size_of::<F>() as u64,
+        };
+        if self.element_size != expected_element_size {
+            return Err(SumcheckError::HeaderMismatch("element size"));
+        }
+        if self.order != expected.order {
+            return Err(SumcheckError::HeaderMismatch("order"));
+        }
+        Ok(())
+    }
+    // a chunk longer than the rows is all of them, so neither of these overflows when rows_len doesn't
+    fn num_chunks(&self) -> usize {
+        let num_rows = 1_usize << self.num_variables;
+        num_rows.div_ceil((self.chunk_size as usize).min(num_rows))
+    }
+    fn size_of_chunk(&self) -> usize {
+        (self.chunk_size.min(1 << self.num_variables) * self.num_columns * self.element_size)
+            as usize
+    }
+    /*
+     * Writes the header with placeholder checksums, then the rows a chunk at a time,
+     * each of them checksummed as it goes, then seeks back to fill in the checksums.
+     * Only one chunk of rows is ever in memory
+     */
+    pub(crate) fn write_file(
+        mut self,
+        path: String,
+        mut write_row: impl FnMut(usize, &mut Vec<u8>) -> Result<(), SumcheckError>,
+    ) -> Result<(), SumcheckError> {
+        if self.chunk_size == 0 {
+            return Err(SumcheckError::InvalidChunkSize);
+        }
+        let num_rows = 1_usize << self.num_variables;
+        let chunk_size = (self.chunk_size as usize).min(num_rows);
+        // as many as there will be, so the header doesn't change size when they're filled in
+        self.checksums = vec![0; self.num_chunks()];
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer)?;
+
+        let mut chunk: Vec<u8> = Vec::new();
+        for (index, chunk_start) in (0..num_rows).step_by(chunk_size).enumerate() {
+            chunk.clear();
+            for row in chunk_start..chunk_start + chunk_size.min(num_rows - chunk_start) {
+                write_row(row, &mut chunk)?;
+            }
+            self.checksums[index] = checksum(&chunk);
+            writer.write_all(&chunk)?;
+        }
+
+        let mut file = writer.into_inner().map_err(|error| error.into_error())?;
+        file.seek(SeekFrom::Start(0))?;
+        let mut writer = BufWriter::new(file);
+        self.write(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+    // the rows of a file with this header, after checking they're exactly what it says
+    pub(crate) fn rows<'a>(&self, file: &'a [u8]) -> Result<&'a [u8], SumcheckError> {
+        let rows = &file[self.size().min(file.len())..];
+        let expected_len = self.rows_len()?;
+        if rows.len() != expected_len {
+            return Err(SumcheckError::TruncatedFile {
+                len: rows.len(),
+                element_size: self.element_size as usize,
+            });
+        }
+        let chunks = rows.chunks(self.size_of_chunk());
+        if chunks.len() != self.checksums.len() {
+            return Err(SumcheckError::HeaderMismatch("checksums"));
+        }
+        for (chunk, (bytes, expected)) in chunks.zip(self.checksums.iter()).enumerate() {
+            if checksum(bytes) != *expected {
+                return Err(SumcheckError::ChecksumMismatch(chunk));
+            }
+        }
+        Ok(rows)
+    }
+}
+
+// FNV-1a, it only has to catch corruption, not an adversary
+pub fn checksum(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/*
+ * Writes the columns, each of them the evaluations of a polynomial in order O (as
+ * they'd be given to MemoryStream::new), as a columnar file
+ */
+pub fn write_columnar_file<F: Field, O: OrderStrategy>(
+    path: String,
+    columns: &[Vec<F>],
+    chunk_size: usize,
+) -> Result<(), SumcheckError> {
+    // all the columns should be over the same number of variables
+    let len = columns.first().ok_or(SumcheckError::NoStreams)?.len();
+    for column in columns {
+        if !column.len().is_power_of_two() {
+            return Err(SumcheckError::NotPowerOfTwo(column.len()));
+        }
+        if column.len() != len {
+            return Err(SumcheckError::NumVariablesMismatch {
+                expected: len.ilog2() as usize,
+                found: column.len().ilog2() as usize,
+            });
+        }
+    }
+
+    // a row is the evaluations of every column at one point
+    ColumnarHeader::new::<F, O>(len.ilog2() as usize, columns.len(), chunk_size).write_file(
+        path,
+        |point, row| {
+            for column in columns {
+                column[point].serialize_uncompressed(&mut *row)?;
+            }
+            Ok(())
+        },
+    )
+}
+
+// opens a columnar file as columns over F in order O, after checking its header, checksums and elements
+pub fn open_columnar_file<F: Field, O: OrderStrategy>(
+    path: String,
+) -> Result<InterleavedFileStream<F>, SumcheckError> {
+    let file = File::open(Path::new(&path))?;
+    let mmap = unsafe { Mmap::map(&file) }?;
+    let header = ColumnarHeader::read::<F>(&mmap)?;
+    header.check::<F, O>()?;
+    if header.representation != Representation::Canonical {
+        return Err(SumcheckError::HeaderMismatch("representation"));
+    }
+    check_elements::<F>(header.rows(&mmap)?)?;
+
+    Ok(InterleavedFileStream::from_mmap(
+        mmap,
+        header.size(),
+        header.num_columns as usize,
+        header.num_variables as usize,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::fs;
+
+    use super::{COLUMNAR_FILE_VERSION, MAGIC};
+    use crate::{
+        error::SumcheckError,
+        order_strategy::{GraycodeOrder, SignificantBitOrder},
+        streams::{open_columnar_file, write_columnar_file, ColumnarHeader, Stream},
+        tests::{F64Ext2, F128, F19, F64},
+    };
+
+    fn path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("columnar_file_{}.bin", name))
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn round_trip() {
+        let path = path("round_trip");
+        let columns: Vec<Vec<F64>> = (0..2)
+            .map(|column| {
+                (0..16)
+                    .map(|point| F64::from(100 * column + point))
+                    .collect()
+            })
+            .collect();
+        // chunks of 3 rows, so the last one is short
+        write_columnar_file::<F64, SignificantBitOrder>(path.clone(), &columns, 3).unwrap();
+
+        let file = open_columnar_file::<F64, SignificantBitOrder>(path.clone()).unwrap();
+        assert_eq!(file.num_columns(), 2);
+        assert_eq!(file.num_variables(), 4);
+        for (stream, column) in file.columns().iter().zip(&columns) {
+            for (point, evaluation) in column.iter().enumerate() {
+                assert_eq!(stream.evaluation(point), *evaluation);
+            }
+        }
+        let header = ColumnarHeader::read::<F64>(&fs::read(&path).unwrap()).unwrap();
+        assert_eq!(header.checksums.len(), 6);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn mismatches() {
+        let path = path("mismatches");
+        write_columnar_file::<F64, GraycodeOrder>(path.clone(), &[vec![F64::from(1); 8]], 4)
+            .unwrap();
+
+        // another field, of the same size or not, another extension, or another order
+        assert!(matches!(
+            open_columnar_file::<F19, GraycodeOrder>(path.clone()),
+            Err(SumcheckError::HeaderMismatch("field"))
+        ));
+        assert!(matches!(
+            open_columnar_file::<F128, GraycodeOrder>(path.clone()),
+            Err(SumcheckError::HeaderMismatch("field"))
+        ));
+        assert!(matches!(
+            open_columnar_file::<F64Ext2, GraycodeOrder>(path.clone()),
+            Err(SumcheckError::HeaderMismatch("field"))
+        ));
+        assert!(matches!(
+            open_columnar_file::<F64, SignificantBitOrder>(path.clone()),
+            Err(SumcheckError::HeaderMismatch("order"))
+        ));
+
+        // a flipped bit in the second chunk
+        let mut bytes = fs::read(&path).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1;
+        fs::write(&path, &bytes).unwrap();
+        assert!(matches!(
+            open_columnar_file::<F64, GraycodeOrder>(path.clone()),
+            Err(SumcheckError::ChecksumMismatch(1))
+        ));
+
+        // a raw file isn't a columnar file
+        fs::write(&path, [0_u8; 64]).unwrap();
+        assert!(matches!(
+            open_columnar_file::<F64, GraycodeOrder>(path.clone()),
+            Err(SumcheckError::HeaderMismatch("magic"))
+        ));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn invalid_headers() {
+        let path = path("invalid_headers");
+        assert!(matches!(
+            write_columnar_file::<F64, GraycodeOrder>(path.clone(), &[vec![F64::from(1); 8]], 0),
+            Err(SumcheckError::InvalidChunkSize)
+        ));
+
+        // headers that would size the rows past what can be addressed are refused before that
+        let header = ColumnarHeader::new::<F64, GraycodeOrder>(3, 1, 4);
+        for (invalid, name) in [
+            (
+                ColumnarHeader {
+                    num_variables: 64,
+                    ..header.clone()
+                },
+                "num_variables",
+            ),
+            (
+                ColumnarHeader {
+                    num_variables: 40,
+                    num_columns: u64::MAX,
+                    ..header.clone()
+                },
+                "num_variables",
+            ),
+            (
+                ColumnarHeader {
+                    chunk_size: 0,
+                    ..header.clone()
+                },
+                "columns",
+            ),
+            (
+                ColumnarHeader {
+                    element_size: 0,
+                    ..header.clone()
+                },
+                "element size",
+            ),
+        ] {
+            let mut bytes = Vec::new();
+            invalid.write(&mut bytes).unwrap();
+            assert!(matches!(
+                ColumnarHeader::read::<F64>(&bytes),
+                Err(SumcheckError::HeaderMismatch(found)) if found == name
+            ));
+        }
+
+        // lengths from the file are bounded before anything is allocated for them
+        let mut bytes = MAGIC.to_vec();
+        bytes.push(COLUMNAR_FILE_VERSION);
+        bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+        assert!(matches!(
+            ColumnarHeader::read::<F64>(&bytes),
+            Err(SumcheckError::HeaderMismatch("field"))
+        ));
+        for checksums in [vec![], vec![0; 3]] {
+            let mut bytes = Vec::new();
+            ColumnarHeader {
+                checksums,
+                ..header.clone()
+            }
+            .write(&mut bytes)
+            .unwrap();
+            assert!(matches!(
+                ColumnarHeader::read::<F64>(&bytes),
+                Err(SumcheckError::HeaderMismatch("checksums"))
+            ));
+        }
+    }
+}
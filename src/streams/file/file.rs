@@ -5,42 +5,63 @@ use ark_std::{
     io::{BufWriter, Write},
     marker::PhantomData,
     path::Path,
+    sync::Arc,
 };
 use memmap2::Mmap;
 
-use crate::streams::Stream;
+use crate::{error::SumcheckError, streams::Stream};
 
 #[derive(Debug)]
 pub struct FileStream<F: Field> {
     num_variables: usize,
     path: String,
-    s: Mmap,
+    s: Arc<Mmap>,
     size_of_serialized: usize,
     _field: PhantomData<F>,
 }
 
 impl<F: Field> Clone for FileStream<F> {
     fn clone(&self) -> Self {
-        Self::new(self.path.clone())
+        // the mapping is shared, so the elements aren't checked again
+        Self {
+            num_variables: self.num_variables,
+            path: self.path.clone(),
+            s: self.s.clone(),
+            size_of_serialized: self.size_of_serialized,
+            _field: PhantomData,
+        }
     }
 }
 
 impl<F: Field> FileStream<F> {
     pub fn new(path: String) -> Self {
-        let file = File::open(Path::new(&path)).unwrap();
-        let mmap = unsafe { Mmap::map(&file) }.unwrap();
+        Self::try_new(path).unwrap()
+    }
+    pub fn try_new(path: String) -> Result<Self, SumcheckError> {
+        let file = File::open(Path::new(&path))?;
+        let mmap = unsafe { Mmap::map(&file) }?;
         let size_of_serialized = F::ONE.serialized_size(Compress::No);
+        if mmap.len() % size_of_serialized != 0 {
+            return Err(SumcheckError::TruncatedFile {
+                len: mmap.len(),
+                element_size: size_of_serialized,
+            });
+        }
         let len = mmap.len() / size_of_serialized;
-        assert!(len.is_power_of_two());
+        if !len.is_power_of_two() {
+            return Err(SumcheckError::NotPowerOfTwo(len));
+        }
+
+        check_elements::<F>(&mmap)?;
 
         let num_variables = len.ilog2() as usize;
-        Self {
+        Ok(Self {
             num_variables,
             path,
-            s: mmap,
+            s: Arc::new(mmap),
             size_of_serialized,
             _field: PhantomData,
-        }
+        })
     }
     pub fn read_point(mmap: &Mmap, point: usize, size_of_serialized: usize) -> F {
         let offset = point * size_of_serialized;
@@ -64,10 +85,33 @@ impl<F: Field> FileStream<F> {
     }
 }
 
+/*
+ * Checks once that every element of a file is canonical, so reading them later
+ * can't fail, and returns the first that isn't as InvalidElement
+ */
+pub(crate) fn check_elements<F: Field>(bytes: &[u8]) -> Result<(), SumcheckError> {
+    let size_of_serialized = F::ONE.serialized_size(Compress::No);
+    for (point, bytes) in bytes.chunks_exact(size_of_serialized).enumerate() {
+        F::deserialize_uncompressed(bytes).map_err(|_| SumcheckError::InvalidElement(point))?;
+    }
+    Ok(())
+}
+
 impl<F: Field> Stream<F> for FileStream<F> {
     fn evaluation(&self, point: usize) -> F {
         Self::read_point(&self.s, point, self.size_of_serialized)
     }
+    fn evaluations_into(&self, start: usize, evaluations: &mut [F]) {
+        // one contiguous slice of the map, decoded element by element
+        let offset = start * self.size_of_serialized;
+        let bytes = &self.s[offset..offset + evaluations.len() * self.size_of_serialized];
+        for (evaluation, bytes) in evaluations
+            .iter_mut()
+            .zip(bytes.chunks_exact(self.size_of_serialized))
+        {
+            *evaluation = F::deserialize_uncompressed(bytes).unwrap();
+        }
+    }
 
     fn num_variables(&self) -> usize {
         self.num_variables
@@ -76,6 +120,47 @@ impl<F: Field> Stream<F> for FileStream<F> {
 
 #[cfg(test)]
 mod tests {
+    use ark_std::fs;
+
+    use crate::{error::SumcheckError, streams::FileStream, tests::F64};
+
+    #[test]
+    fn malformed_files() {
+        // a missing file
+        let path = std::env::temp_dir().join("file_stream_malformed_files_test_evals.bin");
+        let path = path.to_str().unwrap().to_string();
+        let _ = fs::remove_file(&path);
+        assert!(matches!(
+            FileStream::<F64>::try_new(path.clone()),
+            Err(SumcheckError::Io(_))
+        ));
+
+        // a number of evaluations that isn't a power of two
+        FileStream::<F64>::write_to_file(path.clone(), &vec![F64::from(1); 3]);
+        assert!(matches!(
+            FileStream::<F64>::try_new(path.clone()),
+            Err(SumcheckError::NotPowerOfTwo(3))
+        ));
+
+        // a partial evaluation at the end
+        fs::write(&path, [0_u8; 5]).unwrap();
+        assert!(matches!(
+            FileStream::<F64>::try_new(path.clone()),
+            Err(SumcheckError::TruncatedFile { len: 5, .. })
+        ));
+
+        // an element that isn't reduced
+        let mut bytes = vec![0_u8; 4 * 8];
+        bytes[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+        fs::write(&path, &bytes).unwrap();
+        assert!(matches!(
+            FileStream::<F64>::try_new(path.clone()),
+            Err(SumcheckError::InvalidElement(2))
+        ));
+
+        FileStream::<F64>::delete_file(path);
+    }
+
     // use crate::{
     //     multilinear_product::BlendyProductProver,
     //     prover::{ProductProverConfig, Prover},
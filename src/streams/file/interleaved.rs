@@ -0,0 +1,329 @@
+use ark_ff::Field;
+use ark_serialize::Compress;
+use ark_std::{
+    fs::File,
+    io::{BufWriter, Write},
+    marker::PhantomData,
+    path::Path,
+    sync::Arc,
+};
+use memmap2::Mmap;
+
+use crate::{
+    error::SumcheckError,
+    streams::{file::check_elements, Stream},
+};
+
+/*
+ * The factors of a product in one file, as rows of num_columns elements: row i
+ * holds the i-th evaluation of every factor, so reading all of them at a point is
+ * one contiguous read instead of one per file
+ */
+#[derive(Debug)]
+pub struct InterleavedFileStream<F: Field> {
+    num_columns: usize,
+    num_variables: usize,
+    // where the rows start, after the header of a columnar file
+    offset: usize,
+    s: Arc<Mmap>,
+    size_of_serialized: usize,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field> Clone for InterleavedFileStream<F> {
+    fn clone(&self) -> Self {
+        // the mapping is shared, not reopened
+        Self {
+            num_columns: self.num_columns,
+            num_variables: self.num_variables,
+            offset: self.offset,
+            s: self.s.clone(),
+            size_of_serialized: self.size_of_serialized,
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: Field> InterleavedFileStream<F> {
+    pub fn new(path: String, num_columns: usize) -> Self {
+        Self::try_new(path, num_columns).unwrap()
+    }
+    pub fn try_new(path: String, num_columns: usize) -> Result<Self, SumcheckError> {
+        if num_columns == 0 {
+            return Err(SumcheckError::NoStreams);
+        }
+        let file = File::open(Path::new(&path))?;
+        let mmap = unsafe { Mmap::map(&file) }?;
+        let size_of_serialized = F::ONE.serialized_size(Compress::No);
+        let size_of_row = num_columns * size_of_serialized;
+        if mmap.len() % size_of_row != 0 {
+            return Err(SumcheckError::TruncatedFile {
+                len: mmap.len(),
+                element_size: size_of_row,
+            });
+        }
+        let len = mmap.len() / size_of_row;
+        if !len.is_power_of_two() {
+            return Err(SumcheckError::NotPowerOfTwo(len));
+        }
+        check_elements::<F>(&mmap)?;
+        Ok(Self::from_mmap(mmap, 0, num_columns, len.ilog2() as usize))
+    }
+    pub(crate) fn from_mmap(
+        mmap: Mmap,
+        offset: usize,
+        num_columns: usize,
+        num_variables: usize,
+    ) -> Self {
+        Self {
+            num_columns,
+            num_variables,
+            offset,
+            s: Arc::new(mmap),
+            size_of_serialized: F::ONE.serialized_size(Compress::No),
+            _field: PhantomData,
+        }
+    }
+    // all the columns should have the same power of two length
+    pub fn write_to_file(path: String, columns: &[Vec<F>]) {
+        let file = File::create(path).unwrap();
+        let mut writer = BufWriter::new(file);
+        for point in 0..columns[0].len() {
+            for column in columns {
+                column[point].serialize_uncompressed(&mut writer).unwrap();
+            }
+        }
+        writer.flush().unwrap();
+    }
+    pub fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+    pub fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+    fn size_of_row(&self) -> usize {
+        self.num_columns * self.size_of_serialized
+    }
+    fn row(&self, point: usize) -> &[u8] {
+        self.rows(point, 1)
+    }
+    // the len rows from start on, one contiguous slice of the file
+    fn rows(&self, start: usize, len: usize) -> &[u8] {
+        let start = self.offset + start * self.size_of_row();
+        &self.s[start..start + len * self.size_of_row()]
+    }
+    fn read_cell(&self, row: &[u8], column: usize) -> F {
+        let offset = column * self.size_of_serialized;
+        F::deserialize_uncompressed(&row[offset..offset + self.size_of_serialized]).unwrap()
+    }
+    // every column at a point
+    pub fn evaluations(&self, point: usize, evaluations: &mut [F]) {
+        let row = self.row(point);
+        for (column, evaluation) in evaluations.iter_mut().enumerate() {
+            *evaluation = self.read_cell(row, column);
+        }
+    }
+    // one stream per column, for the provers that take a Vec of streams
+    pub fn columns(&self) -> Vec<ColumnStream<F>> {
+        (0..self.num_columns)
+            .map(|column| ColumnStream {
+                file: self.clone(),
+                column,
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ColumnStream<F: Field> {
+    file: InterleavedFileStream<F>,
+    column: usize,
+}
+
+impl<F: Field> Stream<F> for ColumnStream<F> {
+    fn evaluation(&self, point: usize) -> F {
+        self.file.read_cell(self.file.row(point), self.column)
+    }
+
+    fn num_variables(&self) -> usize {
+        self.file.num_variables
+    }
+
+    fn evaluations_into(&self, start: usize, evaluations: &mut [F]) {
+        let rows = self.file.rows(start, evaluations.len());
+        for (evaluation, row) in evaluations
+            .iter_mut()
+            .zip(rows.chunks_exact(self.file.size_of_row()))
+        {
+            *evaluation = self.file.read_cell(row, self.column);
+        }
+    }
+
+    fn fused_evaluations_into(streams: &[Self], start: usize, evaluations: &mut [&mut [F]]) {
+        // columns of different files are read one by one
+        let file = &streams[0].file;
+        if !streams
+            .iter()
+            .all(|stream| Arc::ptr_eq(&stream.file.s, &file.s))
+        {
+            for (evaluations, stream) in evaluations.iter_mut().zip(streams) {
+                stream.evaluations_into(start, evaluations);
+            }
+            return;
+        }
+        let rows = file.rows(start, evaluations[0].len());
+        for (offset, row) in rows.chunks_exact(file.size_of_row()).enumerate() {
+            for (evaluations, stream) in evaluations.iter_mut().zip(streams) {
+                evaluations[offset] = file.read_cell(row, stream.column);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+    use ark_std::fs;
+
+    use crate::{
+        error::SumcheckError,
+        multi_product::{BlendyMultiProductProver, BlendyMultiProductProverConfig},
+        multilinear_product::{
+            BlendyProductProver, BlendyProductProverConfig, SpaceProductProver,
+            SpaceProductProverConfig,
+        },
+        prover::Prover,
+        streams::{
+            multivariate_product_claim, ColumnStream, InterleavedFileStream, MemoryStream, Stream,
+        },
+        tests::F64,
+        MultiProductSumcheck, ProductSumcheck,
+    };
+
+    #[test]
+    fn rows_and_columns() {
+        let path = std::env::temp_dir().join("interleaved_file_stream_rows_and_columns.bin");
+        let path = path.to_str().unwrap().to_string();
+        let columns: Vec<Vec<F64>> = (0..3)
+            .map(|column| (0..8).map(|point| F64::from(10 * column + point)).collect())
+            .collect();
+        InterleavedFileStream::write_to_file(path.clone(), &columns);
+
+        let file = InterleavedFileStream::<F64>::new(path.clone(), 3);
+        assert_eq!(file.num_variables(), 3);
+        let mut row = vec![F64::from(0); 3];
+        file.evaluations(5, &mut row);
+        assert_eq!(row, vec![F64::from(5), F64::from(15), F64::from(25)]);
+
+        // each column is a stream, and reading them together gives the row back
+        let streams = file.columns();
+        for (stream, column) in streams.iter().zip(&columns) {
+            for (point, evaluation) in column.iter().enumerate() {
+                assert_eq!(stream.evaluation(point), *evaluation);
+            }
+            let mut run = vec![F64::from(0); 5];
+            stream.evaluations_into(2, &mut run);
+            assert_eq!(run, column[2..7]);
+        }
+        let (mut first, mut second) = (vec![F64::from(0); 3], vec![F64::from(0); 3]);
+        ColumnStream::fused_evaluations_into(
+            &[streams[2].clone(), streams[0].clone()],
+            4,
+            &mut [&mut first, &mut second],
+        );
+        assert_eq!(first, columns[2][4..7]);
+        assert_eq!(second, columns[0][4..7]);
+
+        // 24 elements are 8 rows of 3 but not 12 rows of 2
+        assert!(matches!(
+            InterleavedFileStream::<F64>::try_new(path.clone(), 2),
+            Err(SumcheckError::NotPowerOfTwo(12))
+        ));
+
+        // an element that isn't reduced, the second cell of the fourth row
+        let mut bytes = fs::read(&path).unwrap();
+        bytes[10 * 8..11 * 8].copy_from_slice(&u64::MAX.to_le_bytes());
+        fs::write(&path, &bytes).unwrap();
+        assert!(matches!(
+            InterleavedFileStream::<F64>::try_new(path.clone(), 3),
+            Err(SumcheckError::InvalidElement(10))
+        ));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn fused_product_provers() {
+        let path = std::env::temp_dir().join("interleaved_file_stream_fused_product_provers.bin");
+        let path = path.to_str().unwrap().to_string();
+        let mut rng = ark_std::test_rng();
+        let columns: Vec<Vec<F64>> = (0..3)
+            .map(|_| (0..1 << 10).map(|_| F64::rand(&mut rng)).collect())
+            .collect();
+        InterleavedFileStream::write_to_file(path.clone(), &columns);
+        let file = InterleavedFileStream::<F64>::new(path.clone(), 3);
+        let fused = file.columns();
+        let memory: Vec<MemoryStream<F64>> = columns.into_iter().map(MemoryStream::new).collect();
+
+        // the same transcripts as with one stream per factor
+        let claim = multivariate_product_claim(memory[..2].to_vec());
+        let expected = ProductSumcheck::<F64>::prove::<
+            MemoryStream<F64>,
+            SpaceProductProver<F64, MemoryStream<F64>>,
+        >(
+            &mut SpaceProductProver::new(SpaceProductProverConfig::new(
+                claim,
+                10,
+                memory[..2].to_vec(),
+            )),
+            &mut ark_std::test_rng(),
+        );
+        let space = ProductSumcheck::<F64>::prove::<
+            ColumnStream<F64>,
+            SpaceProductProver<F64, ColumnStream<F64>>,
+        >(
+            &mut SpaceProductProver::new(SpaceProductProverConfig::new(
+                claim,
+                10,
+                fused[..2].to_vec(),
+            )),
+            &mut ark_std::test_rng(),
+        );
+        let blendy = ProductSumcheck::<F64>::prove::<
+            ColumnStream<F64>,
+            BlendyProductProver<F64, ColumnStream<F64>>,
+        >(
+            &mut BlendyProductProver::new(BlendyProductProverConfig::new(
+                claim,
+                3,
+                10,
+                fused[..2].to_vec(),
+            )),
+            &mut ark_std::test_rng(),
+        );
+        assert_eq!(space.prover_messages, expected.prover_messages);
+        assert_eq!(blendy.prover_messages, expected.prover_messages);
+
+        let claim = multivariate_product_claim(memory.clone());
+        let expected = MultiProductSumcheck::<F64>::prove::<
+            MemoryStream<F64>,
+            BlendyMultiProductProver<F64, MemoryStream<F64>>,
+        >(
+            &mut BlendyMultiProductProver::new(BlendyMultiProductProverConfig::new(
+                claim, 3, 10, memory,
+            )),
+            &mut ark_std::test_rng(),
+        );
+        let blendy = MultiProductSumcheck::<F64>::prove::<
+            ColumnStream<F64>,
+            BlendyMultiProductProver<F64, ColumnStream<F64>>,
+        >(
+            &mut BlendyMultiProductProver::new(BlendyMultiProductProverConfig::new(
+                claim, 3, 10, fused,
+            )),
+            &mut ark_std::test_rng(),
+        );
+        assert!(blendy.is_accepted);
+        assert_eq!(blendy.prover_messages, expected.prover_messages);
+        fs::remove_file(&path).unwrap();
+    }
+}
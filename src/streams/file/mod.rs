@@ -1,3 +1,15 @@
+mod columnar;
 mod file;
+mod interleaved;
+mod montgomery;
+mod sink;
 
+pub use columnar::{
+    checksum, open_columnar_file, write_columnar_file, ColumnarHeader, Representation,
+    COLUMNAR_FILE_VERSION, DEFAULT_CHUNK_SIZE,
+};
+pub(crate) use file::check_elements;
 pub use file::FileStream;
+pub use interleaved::{ColumnStream, InterleavedFileStream};
+pub use montgomery::{open_montgomery_file, write_montgomery_file, MontgomeryFileStream};
+pub use sink::FileSink;
@@ -0,0 +1,256 @@
+use ark_ff::{BigInt, Fp, MontBackend, MontConfig};
+use ark_std::{
+    fs::File,
+    marker::PhantomData,
+    mem::{align_of, size_of},
+    path::Path,
+    slice,
+    sync::Arc,
+};
+use memmap2::Mmap;
+
+use crate::{
+    error::SumcheckError,
+    order_strategy::OrderStrategy,
+    streams::{ColumnarHeader, Representation, Stream},
+};
+
+/*
+ * A columnar file of one column whose elements are stored as they are in memory,
+ * in Montgomery form. Reading it is a cast of the map instead of a conversion from
+ * canonical form per element, which the provers that stream many times would
+ * otherwise pay on every pass
+ */
+pub struct MontgomeryFileStream<P: MontConfig<N>, const N: usize> {
+    num_variables: usize,
+    offset: usize,
+    s: Arc<Mmap>,
+    _config: PhantomData<P>,
+}
+
+// the map is shared, a clone doesn't open the file again
+impl<P: MontConfig<N>, const N: usize> Clone for MontgomeryFileStream<P, N> {
+    fn clone(&self) -> Self {
+        Self {
+            num_variables: self.num_variables,
+            offset: self.offset,
+            s: self.s.clone(),
+            _config: PhantomData,
+        }
+    }
+}
+
+impl<P: MontConfig<N>, const N: usize> MontgomeryFileStream<P, N> {
+    // the evaluations, without copying them out of the map
+    pub fn as_slice(&self) -> &[Fp<MontBackend<P, N>, N>] {
+        // the layout, alignment and every element were checked when the file was opened
+        unsafe {
+            slice::from_raw_parts(
+                self.s[self.offset..].as_ptr() as *const Fp<MontBackend<P, N>, N>,
+                1 << self.num_variables,
+            )
+        }
+    }
+}
+
+impl<P: MontConfig<N>, const N: usize> Stream<Fp<MontBackend<P, N>, N>>
+    for MontgomeryFileStream<P, N>
+{
+    fn evaluation(&self, point: usize) -> Fp<MontBackend<P, N>, N> {
+        self.as_slice()[point]
+    }
+    fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+    fn evaluations_into(&self, start: usize, evaluations: &mut [Fp<MontBackend<P, N>, N>]) {
+        evaluations.copy_from_slice(&self.as_slice()[start..start + evaluations.len()]);
+    }
+}
+
+// the rows can only be cast where an element is exactly its limbs, little endian
+fn check_layout<P: MontConfig<N>, const N: usize>() -> Result<(), SumcheckError> {
+    if cfg!(target_endian = "big")
+        || size_of::<Fp<MontBackend<P, N>, N>>() != size_of::<BigInt<N>>()
+        || align_of::<Fp<MontBackend<P, N>, N>>() != align_of::<u64>()
+    {
+        return Err(SumcheckError::HeaderMismatch("representation"));
+    }
+    Ok(())
+}
+
+/*
+ * Writes the evaluations of a polynomial in order O (as they'd be given to
+ * MemoryStream::new) as a columnar file in Montgomery form
+ */
+pub fn write_montgomery_file<P: MontConfig<N>, O: OrderStrategy, const N: usize>(
+    path: String,
+    evaluations: &[Fp<MontBackend<P, N>, N>],
+    chunk_size: usize,
+) -> Result<(), SumcheckError> {
+    if !evaluations.len().is_power_of_two() {
+        return Err(SumcheckError::NotPowerOfTwo(evaluations.len()));
+    }
+    let header = ColumnarHeader {
+        element_size: size_of::<BigInt<N>>() as u64,
+        representation: Representation::Montgomery,
+        ..ColumnarHeader::new::<Fp<MontBackend<P, N>, N>, O>(
+            evaluations.len().ilog2() as usize,
+            1,
+            chunk_size,
+        )
+    };
+    header.write_file(path, |point, row| {
+        for limb in evaluations[point].0 .0 {
+            row.extend_from_slice(&limb.to_le_bytes());
+        }
+        Ok(())
+    })
+}
+
+/*
+ * Opens a file written by write_montgomery_file after checking its header and
+ * checksums, and that every element is reduced, so the casts later on are sound
+ */
+pub fn open_montgomery_file<P: MontConfig<N>, O: OrderStrategy, const N: usize>(
+    path: String,
+) -> Result<MontgomeryFileStream<P, N>, SumcheckError> {
+    check_layout::<P, N>()?;
+    let file = File::open(Path::new(&path))?;
+    let mmap = unsafe { Mmap::map(&file) }?;
+    let header = ColumnarHeader::read::<Fp<MontBackend<P, N>, N>>(&mmap)?;
+    header.check::<Fp<MontBackend<P, N>, N>, O>()?;
+    if header.representation != Representation::Montgomery || header.num_columns != 1 {
+        return Err(SumcheckError::HeaderMismatch("representation"));
+    }
+    let rows = header.rows(&mmap)?;
+    if !(rows.as_ptr() as usize).is_multiple_of(align_of::<u64>()) {
+        return Err(SumcheckError::HeaderMismatch("representation"));
+    }
+    for (point, bytes) in rows.chunks_exact(size_of::<BigInt<N>>()).enumerate() {
+        let mut limbs = [0_u64; N];
+        for (limb, bytes) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        if BigInt(limbs) >= P::MODULUS {
+            return Err(SumcheckError::InvalidElement(point));
+        }
+    }
+
+    Ok(MontgomeryFileStream {
+        num_variables: header.num_variables as usize,
+        offset: header.size(),
+        s: Arc::new(mmap),
+        _config: PhantomData,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+    use ark_std::fs;
+
+    use crate::{
+        error::SumcheckError,
+        multilinear::{BlendyProver, BlendyProverConfig, TimeProver, TimeProverConfig},
+        order_strategy::{GraycodeOrder, SignificantBitOrder},
+        prover::Prover,
+        streams::{
+            checksum, open_columnar_file, open_montgomery_file, write_columnar_file,
+            write_montgomery_file, ColumnarHeader, MontgomeryFileStream, Stream,
+        },
+        tests::{multilinear::parity_test, F64Config, F128, F64},
+    };
+
+    type MontgomeryStream = MontgomeryFileStream<F64Config, 1>;
+
+    fn path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("montgomery_file_{}.bin", name))
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut rng = ark_std::test_rng();
+        let path = path("round_trip");
+        let evaluations: Vec<F128> = (0..1 << 5).map(|_| F128::rand(&mut rng)).collect();
+        write_montgomery_file::<_, GraycodeOrder, 2>(path.clone(), &evaluations, 7).unwrap();
+
+        let stream = open_montgomery_file::<_, GraycodeOrder, 2>(path.clone()).unwrap();
+        assert_eq!(stream.num_variables(), 5);
+        assert_eq!(stream.as_slice(), &evaluations[..]);
+        for (point, evaluation) in evaluations.iter().enumerate() {
+            assert_eq!(stream.evaluation(point), *evaluation);
+        }
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn provers() {
+        let path = path("provers");
+        let mut rng = ark_std::test_rng();
+        let evaluations: Vec<F64> = (0..1 << 10).map(|_| F64::rand(&mut rng)).collect();
+        let claim: F64 = evaluations.iter().sum();
+        write_montgomery_file::<F64Config, GraycodeOrder, 1>(path.clone(), &evaluations, 64)
+            .unwrap();
+        let stream = open_montgomery_file::<F64Config, GraycodeOrder, 1>(path.clone()).unwrap();
+
+        // the same transcript as from memory
+        parity_test::<F64, MontgomeryStream, TimeProver<F64, MontgomeryStream>>(
+            evaluations.clone(),
+            &mut TimeProver::new(TimeProverConfig::new(claim, 10, stream.clone())),
+        );
+        parity_test::<F64, MontgomeryStream, BlendyProver<F64, MontgomeryStream>>(
+            evaluations,
+            &mut BlendyProver::new(BlendyProverConfig::new(claim, 3, 10, stream)),
+        );
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn mismatches() {
+        let path = path("mismatches");
+        let evaluations = vec![F64::from(3); 8];
+
+        // either representation refuses to open the other
+        write_columnar_file::<F64, GraycodeOrder>(
+            path.clone(),
+            std::slice::from_ref(&evaluations),
+            4,
+        )
+        .unwrap();
+        assert!(matches!(
+            open_montgomery_file::<F64Config, GraycodeOrder, 1>(path.clone()),
+            Err(SumcheckError::HeaderMismatch("representation"))
+        ));
+        write_montgomery_file::<F64Config, GraycodeOrder, 1>(path.clone(), &evaluations, 4)
+            .unwrap();
+        assert!(matches!(
+            open_columnar_file::<F64, GraycodeOrder>(path.clone()),
+            Err(SumcheckError::HeaderMismatch("representation"))
+        ));
+        assert!(matches!(
+            open_montgomery_file::<F64Config, SignificantBitOrder, 1>(path.clone()),
+            Err(SumcheckError::HeaderMismatch("order"))
+        ));
+
+        // an element that isn't reduced, with its checksum fixed up
+        let mut bytes = fs::read(&path).unwrap();
+        let header = ColumnarHeader::read::<F64>(&bytes).unwrap();
+        let last = bytes.len() - 8;
+        bytes[last..].copy_from_slice(&u64::MAX.to_le_bytes());
+        let mut fixed = header.clone();
+        fixed.checksums[1] = checksum(&bytes[header.size() + 32..]);
+        let mut rewritten = Vec::new();
+        fixed.write(&mut rewritten).unwrap();
+        rewritten.extend_from_slice(&bytes[header.size()..]);
+        fs::write(&path, &rewritten).unwrap();
+        assert!(matches!(
+            open_montgomery_file::<F64Config, GraycodeOrder, 1>(path.clone()),
+            Err(SumcheckError::InvalidElement(7))
+        ));
+        fs::remove_file(&path).unwrap();
+    }
+}
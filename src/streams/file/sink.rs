@@ -0,0 +1,96 @@
+use ark_ff::Field;
+use ark_serialize::Compress;
+use ark_std::{
+    fs::{self, OpenOptions},
+    marker::PhantomData,
+    path::Path,
+};
+use memmap2::MmapMut;
+
+use crate::error::SumcheckError;
+
+/*
+ * A FileStream that can be written to: a scratch table of 2^num_variables elements
+ * in a memory mapped file, for tables that don't fit in memory. It can only shrink,
+ * the file is sized once and the tail is just ignored, and it's deleted on drop.
+ * It isn't a Stream because it can't be cloned
+ */
+#[derive(Debug)]
+pub struct FileSink<F: Field> {
+    num_variables: usize,
+    path: String,
+    s: MmapMut,
+    size_of_serialized: usize,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field> FileSink<F> {
+    pub fn new(path: String, num_variables: usize) -> Self {
+        Self::try_new(path, num_variables).unwrap()
+    }
+    pub fn try_new(path: String, num_variables: usize) -> Result<Self, SumcheckError> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(Path::new(&path))?;
+        let size_of_serialized = F::ONE.serialized_size(Compress::No);
+        file.set_len(((1 << num_variables) * size_of_serialized) as u64)?;
+        let mmap = unsafe { MmapMut::map_mut(&file) }?;
+        Ok(Self {
+            num_variables,
+            path,
+            s: mmap,
+            size_of_serialized,
+            _field: PhantomData,
+        })
+    }
+    pub fn evaluation(&self, point: usize) -> F {
+        let offset = point * self.size_of_serialized;
+        F::deserialize_uncompressed(&self.s[offset..offset + self.size_of_serialized]).unwrap()
+    }
+    pub fn set_evaluation(&mut self, point: usize, evaluation: F) {
+        let offset = point * self.size_of_serialized;
+        evaluation
+            .serialize_uncompressed(&mut self.s[offset..offset + self.size_of_serialized])
+            .unwrap();
+    }
+    pub fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+    // keep only the first 2^num_variables elements
+    pub fn truncate(&mut self, num_variables: usize) {
+        assert!(num_variables <= self.num_variables);
+        self.num_variables = num_variables;
+    }
+}
+
+impl<F: Field> Drop for FileSink<F> {
+    fn drop(&mut self) {
+        // it's scratch space, nothing should outlive the prover
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::path::Path;
+
+    use crate::{streams::FileSink, tests::F64};
+
+    #[test]
+    fn write_read_and_delete() {
+        let path = std::env::temp_dir().join("file_sink_write_read_and_delete.bin");
+        let path = path.to_str().unwrap().to_string();
+        let mut sink = FileSink::<F64>::new(path.clone(), 3);
+        for point in 0..8 {
+            sink.set_evaluation(point, F64::from(point as u64 * 5));
+        }
+        sink.truncate(2);
+        assert_eq!(sink.num_variables(), 2);
+        assert_eq!(sink.evaluation(3), F64::from(15));
+        drop(sink);
+        assert!(!Path::new(&path).exists());
+    }
+}
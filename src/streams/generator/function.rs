@@ -0,0 +1,78 @@
+use ark_ff::Field;
+use ark_std::marker::PhantomData;
+
+use crate::streams::{MaybeSync, Stream};
+
+/*
+ * A stream whose evaluations are computed by a function of the index instead of
+ * being stored, like BenchStream but for any function. The index is most
+ * significant bit first, as in every other stream
+ */
+#[derive(Clone)]
+pub struct FnStream<F, G>
+where
+    F: Field,
+    G: Fn(usize) -> F + Clone + MaybeSync,
+{
+    evaluation: G,
+    num_variables: usize,
+    _field: PhantomData<F>,
+}
+
+impl<F, G> FnStream<F, G>
+where
+    F: Field,
+    G: Fn(usize) -> F + Clone + MaybeSync,
+{
+    pub fn new(num_variables: usize, evaluation: G) -> Self {
+        Self {
+            evaluation,
+            num_variables,
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F, G> Stream<F> for FnStream<F, G>
+where
+    F: Field,
+    G: Fn(usize) -> F + Clone + MaybeSync,
+{
+    fn evaluation(&self, point: usize) -> F {
+        (self.evaluation)(point)
+    }
+    fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        multilinear::{BlendyProver, BlendyProverConfig},
+        prover::Prover,
+        streams::{FnStream, Stream},
+        tests::{multilinear::parity_test, F64},
+    };
+
+    type Squares = FnStream<F64, fn(usize) -> F64>;
+
+    // i^2 + 3, with nothing stored
+    fn square(point: usize) -> F64 {
+        F64::from((point * point + 3) as u64)
+    }
+
+    #[test]
+    fn agrees_with_memory() {
+        let stream: Squares = FnStream::new(10, square);
+        let evaluations: Vec<F64> = (0..1 << 10).map(square).collect();
+        let claim: F64 = evaluations.iter().sum();
+        assert_eq!(stream.num_variables(), 10);
+        assert_eq!(stream.evaluation(17), evaluations[17]);
+
+        parity_test::<F64, Squares, BlendyProver<F64, Squares>>(
+            evaluations,
+            &mut BlendyProver::new(BlendyProverConfig::new(claim, 2, 10, stream)),
+        );
+    }
+}
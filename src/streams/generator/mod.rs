@@ -0,0 +1,5 @@
+mod function;
+mod witness;
+
+pub use function::FnStream;
+pub use witness::{WitnessProgram, WitnessStream};
@@ -0,0 +1,203 @@
+use ark_ff::Field;
+use ark_std::{marker::PhantomData, sync::Arc, vec::Vec};
+
+use crate::streams::{MaybeSync, Stream};
+
+/*
+ * A program that generates an execution trace row by row: a state per row, and
+ * the value of each column of the trace read off the state of its row
+ */
+pub trait WitnessProgram<F: Field>: Clone + MaybeSync {
+    type State: Clone + MaybeSync;
+
+    fn num_columns(&self) -> usize;
+    // the state of row 0
+    fn initial_state(&self) -> Self::State;
+    // from the state of a row to the state of the next one
+    fn step(&self, state: &mut Self::State);
+    fn value(&self, state: &Self::State, column: usize) -> F;
+}
+
+/*
+ * One column of the trace of a program, regenerated every time it's read instead of
+ * being stored. The state of every interval-th row is kept, so a read replays at most
+ * interval - 1 steps, and a run of consecutive reads (as the provers do in chunks)
+ * steps once per row. The checkpoints take 2^num_variables / interval states
+ */
+pub struct WitnessStream<F: Field, P: WitnessProgram<F>> {
+    checkpoints: Arc<Vec<P::State>>,
+    column: usize,
+    interval: usize,
+    num_variables: usize,
+    program: P,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field, P: WitnessProgram<F>> Clone for WitnessStream<F, P> {
+    fn clone(&self) -> Self {
+        Self {
+            checkpoints: self.checkpoints.clone(),
+            column: self.column,
+            interval: self.interval,
+            num_variables: self.num_variables,
+            program: self.program.clone(),
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: Field, P: WitnessProgram<F>> WitnessStream<F, P> {
+    pub fn new(program: P, num_variables: usize, column: usize, interval: usize) -> Self {
+        let mut columns = Self::columns(program, num_variables, interval);
+        columns.swap_remove(column)
+    }
+    // a stream per column, which share one pass of the program to find the checkpoints
+    pub fn columns(program: P, num_variables: usize, interval: usize) -> Vec<Self> {
+        assert!(
+            interval > 0,
+            "the interval between checkpoints can't be zero"
+        );
+        let mut checkpoints = Vec::with_capacity((1_usize << num_variables).div_ceil(interval));
+        let mut state = program.initial_state();
+        for row in 0..1 << num_variables {
+            if row % interval == 0 {
+                checkpoints.push(state.clone());
+            }
+            program.step(&mut state);
+        }
+        let checkpoints = Arc::new(checkpoints);
+        (0..program.num_columns())
+            .map(|column| Self {
+                checkpoints: checkpoints.clone(),
+                column,
+                interval,
+                num_variables,
+                program: program.clone(),
+                _field: PhantomData,
+            })
+            .collect()
+    }
+    // replays the program from the last checkpoint before the row
+    fn state(&self, row: usize) -> P::State {
+        let mut state = self.checkpoints[row / self.interval].clone();
+        for _ in 0..row % self.interval {
+            self.program.step(&mut state);
+        }
+        state
+    }
+}
+
+impl<F: Field, P: WitnessProgram<F>> Stream<F> for WitnessStream<F, P> {
+    fn evaluation(&self, point: usize) -> F {
+        self.program.value(&self.state(point), self.column)
+    }
+    fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+    fn evaluations_into(&self, start: usize, evaluations: &mut [F]) {
+        if evaluations.is_empty() {
+            return;
+        }
+        let mut state = self.state(start);
+        let last = evaluations.len() - 1;
+        for (offset, evaluation) in evaluations.iter_mut().enumerate() {
+            *evaluation = self.program.value(&state, self.column);
+            if offset != last {
+                self.program.step(&mut state);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        multilinear::{BlendyProver, BlendyProverConfig},
+        prover::Prover,
+        streams::{Stream, WitnessProgram, WitnessStream},
+        tests::{multilinear::parity_test, F64},
+    };
+
+    // a Fibonacci machine: the trace columns are a and b, and each step is (a, b) -> (b, a + b)
+    #[derive(Clone)]
+    struct Fibonacci {
+        seed: (F64, F64),
+    }
+
+    impl WitnessProgram<F64> for Fibonacci {
+        type State = (F64, F64);
+
+        fn num_columns(&self) -> usize {
+            2
+        }
+        fn initial_state(&self) -> Self::State {
+            self.seed
+        }
+        fn step(&self, state: &mut Self::State) {
+            *state = (state.1, state.0 + state.1);
+        }
+        fn value(&self, state: &Self::State, column: usize) -> F64 {
+            match column {
+                0 => state.0,
+                _ => state.1,
+            }
+        }
+    }
+
+    fn trace(num_variables: usize) -> Vec<Vec<F64>> {
+        let mut state = (F64::from(2), F64::from(7));
+        let mut columns = vec![vec![], vec![]];
+        for _ in 0..1 << num_variables {
+            columns[0].push(state.0);
+            columns[1].push(state.1);
+            state = (state.1, state.0 + state.1);
+        }
+        columns
+    }
+
+    #[test]
+    fn agrees_with_trace() {
+        let program = Fibonacci {
+            seed: (F64::from(2), F64::from(7)),
+        };
+        let streams = WitnessStream::columns(program.clone(), 6, 5);
+        for (stream, column) in streams.iter().zip(trace(6)) {
+            assert_eq!(stream.num_variables(), 6);
+            for (point, evaluation) in column.iter().enumerate() {
+                assert_eq!(stream.evaluation(point), *evaluation);
+            }
+            // runs that start and end between checkpoints
+            for (start, len) in [(0, 64), (3, 11), (9, 0), (61, 3)] {
+                let mut evaluations = vec![F64::from(0); len];
+                stream.evaluations_into(start, &mut evaluations);
+                assert_eq!(evaluations, column[start..start + len]);
+            }
+        }
+        let second = WitnessStream::new(program, 6, 1, 64);
+        assert_eq!(second.evaluation(40), trace(6)[1][40]);
+    }
+
+    #[test]
+    fn provers() {
+        let program = Fibonacci {
+            seed: (F64::from(2), F64::from(7)),
+        };
+        let evaluations = trace(10).swap_remove(1);
+        let claim: F64 = evaluations.iter().sum();
+
+        // every pass of blendy regenerates the column
+        parity_test::<
+            F64,
+            WitnessStream<F64, Fibonacci>,
+            BlendyProver<F64, WitnessStream<F64, Fibonacci>>,
+        >(
+            evaluations,
+            &mut BlendyProver::new(BlendyProverConfig::new(
+                claim,
+                3,
+                10,
+                WitnessStream::new(program, 10, 1, 32),
+            )),
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{order_strategy::OrderStrategy, streams::Stream};
+use crate::{error::SumcheckError, order_strategy::OrderStrategy, streams::Stream};
 use ark_ff::Field;
 
 /*
@@ -28,21 +28,25 @@ pub fn reorder_vec<F: Field, O: OrderStrategy>(evaluations: Vec<F>) -> Vec<F> {
 
 impl<F: Field> MemoryStream<F> {
     pub fn new(evaluations: Vec<F>) -> Self {
-        // abort if length not a power of two
-        assert_eq!(
-            evaluations.len() != 0 && evaluations.len().count_ones() == 1,
-            true
-        );
+        Self::try_new(evaluations).unwrap()
+    }
+    pub fn try_new(evaluations: Vec<F>) -> Result<Self, SumcheckError> {
+        // error if length not a power of two
+        if !evaluations.len().is_power_of_two() {
+            return Err(SumcheckError::NotPowerOfTwo(evaluations.len()));
+        }
         // return the MemoryStream instance
-        Self { evaluations }
+        Ok(Self { evaluations })
     }
     pub fn new_from_lex<O: OrderStrategy>(evaluations: Vec<F>) -> Self {
-        // abort if length not a power of two
-        assert_eq!(
-            evaluations.len() != 0 && evaluations.len().count_ones() == 1,
-            true
-        );
-        Self::new(reorder_vec::<F, O>(evaluations))
+        Self::try_new_from_lex::<O>(evaluations).unwrap()
+    }
+    pub fn try_new_from_lex<O: OrderStrategy>(evaluations: Vec<F>) -> Result<Self, SumcheckError> {
+        // error if length not a power of two
+        if !evaluations.len().is_power_of_two() {
+            return Err(SumcheckError::NotPowerOfTwo(evaluations.len()));
+        }
+        Self::try_new(reorder_vec::<F, O>(evaluations))
     }
 }
 
@@ -50,6 +54,9 @@ impl<F: Field> Stream<F> for MemoryStream<F> {
     fn evaluation(&self, point: usize) -> F {
         self.evaluations[point]
     }
+    fn evaluations_into(&self, start: usize, evaluations: &mut [F]) {
+        evaluations.copy_from_slice(&self.evaluations[start..start + evaluations.len()]);
+    }
     fn num_variables(&self) -> usize {
         self.evaluations.len().ilog2() as usize
     }
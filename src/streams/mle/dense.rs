@@ -0,0 +1,95 @@
+use ark_ff::Field;
+use ark_poly::DenseMultilinearExtension;
+
+use crate::{
+    order_strategy::SignificantBitOrder,
+    streams::{MemoryStream, Stream},
+};
+
+/*
+ * ark-poly indexes evaluations little endian, the first variable is the least
+ * significant bit, where here it's the most significant one. So the evaluation at
+ * an index is the one at the index with its bits reversed, and a MemoryStream and
+ * a DenseMultilinearExtension of the same polynomial hold their tables reversed
+ */
+impl<F: Field> Stream<F> for DenseMultilinearExtension<F> {
+    fn evaluation(&self, point: usize) -> F {
+        self.evaluations[SignificantBitOrder::index_at(point, self.num_vars)]
+    }
+    fn num_variables(&self) -> usize {
+        self.num_vars
+    }
+}
+
+impl<F: Field> From<MemoryStream<F>> for DenseMultilinearExtension<F> {
+    fn from(stream: MemoryStream<F>) -> Self {
+        let num_vars = stream.num_variables();
+        let evaluations = (0..1 << num_vars)
+            .map(|index| stream.evaluation(SignificantBitOrder::index_at(index, num_vars)))
+            .collect();
+        Self::from_evaluations_vec(num_vars, evaluations)
+    }
+}
+
+impl<F: Field> From<&DenseMultilinearExtension<F>> for MemoryStream<F> {
+    fn from(polynomial: &DenseMultilinearExtension<F>) -> Self {
+        Self::new(
+            (0..1 << polynomial.num_vars)
+                .map(|point| polynomial.evaluation(point))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+    use ark_poly::{DenseMultilinearExtension, MultilinearExtension, Polynomial};
+
+    use crate::{
+        multilinear::{SpaceProver, SpaceProverConfig},
+        prover::Prover,
+        streams::{multivariate_evaluation, MemoryStream, Stream},
+        tests::{multilinear::parity_test, F64},
+    };
+
+    #[test]
+    fn agrees_with_ark_poly() {
+        let mut rng = ark_std::test_rng();
+        let stream = MemoryStream::new((0..1 << 6).map(|_| F64::rand(&mut rng)).collect());
+        let polynomial = DenseMultilinearExtension::from(stream.clone());
+        let point: Vec<F64> = (0..6).map(|_| F64::rand(&mut rng)).collect();
+
+        // the same polynomial, at the same point, whichever side evaluates it
+        assert_eq!(
+            multivariate_evaluation(stream.clone(), &point),
+            polynomial.evaluate(&point)
+        );
+        assert_eq!(
+            multivariate_evaluation(polynomial.clone(), &point),
+            polynomial.evaluate(&point)
+        );
+        // 0b000001 here is x_6 = 1, which ark-poly has at 0b100000
+        assert_eq!(polynomial.evaluation(1), polynomial.evaluations[1 << 5]);
+        assert_eq!(
+            MemoryStream::from(&polynomial).evaluations,
+            stream.evaluations
+        );
+    }
+
+    #[test]
+    fn provers() {
+        let mut rng = ark_std::test_rng();
+        let polynomial = DenseMultilinearExtension::<F64>::rand(8, &mut rng);
+        let claim: F64 = polynomial.evaluations.iter().sum();
+
+        parity_test::<
+            F64,
+            DenseMultilinearExtension<F64>,
+            SpaceProver<F64, DenseMultilinearExtension<F64>>,
+        >(
+            MemoryStream::from(&polynomial).evaluations,
+            &mut SpaceProver::new(SpaceProverConfig::new(claim, 8, polynomial)),
+        );
+    }
+}
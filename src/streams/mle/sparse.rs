@@ -0,0 +1,100 @@
+use ark_ff::Field;
+use ark_poly::SparseMultilinearExtension;
+
+use crate::{
+    order_strategy::SignificantBitOrder,
+    streams::{MemoryStream, SparseStream, Stream},
+};
+
+/*
+ * Little endian like the dense one, so indices are bit reversed both ways. Its
+ * non-zeros are sorted in ark-poly's order, not this crate's, so it doesn't list them
+ * through sparse_entry and the provers read it as a dense stream of 2^n lookups.
+ * Convert it with SparseStream::from(&polynomial) for the provers to only visit
+ * the non-zeros
+ */
+impl<F: Field> Stream<F> for SparseMultilinearExtension<F> {
+    fn evaluation(&self, point: usize) -> F {
+        self.evaluations
+            .get(&SignificantBitOrder::index_at(point, self.num_vars))
+            .copied()
+            .unwrap_or(F::ZERO)
+    }
+    fn num_variables(&self) -> usize {
+        self.num_vars
+    }
+}
+
+// the non-zeros of the stream
+impl<F: Field> From<MemoryStream<F>> for SparseMultilinearExtension<F> {
+    fn from(stream: MemoryStream<F>) -> Self {
+        let num_vars = stream.num_variables();
+        let entries: Vec<(usize, F)> = stream
+            .evaluations
+            .into_iter()
+            .enumerate()
+            .filter(|(_, evaluation)| !evaluation.is_zero())
+            .map(|(index, evaluation)| (SignificantBitOrder::index_at(index, num_vars), evaluation))
+            .collect();
+        Self::from_evaluations(num_vars, &entries)
+    }
+}
+
+impl<F: Field> From<&SparseMultilinearExtension<F>> for MemoryStream<F> {
+    fn from(polynomial: &SparseMultilinearExtension<F>) -> Self {
+        let mut evaluations = vec![F::ZERO; 1 << polynomial.num_vars];
+        for (index, evaluation) in &polynomial.evaluations {
+            evaluations[SignificantBitOrder::index_at(*index, polynomial.num_vars)] = *evaluation;
+        }
+        Self::new(evaluations)
+    }
+}
+
+// so the provers only visit the non-zeros, which have to be sorted in this crate's order
+impl<F: Field> From<&SparseMultilinearExtension<F>> for SparseStream<F> {
+    fn from(polynomial: &SparseMultilinearExtension<F>) -> Self {
+        let mut entries: Vec<(usize, F)> = polynomial
+            .evaluations
+            .iter()
+            .filter(|(_, evaluation)| !evaluation.is_zero())
+            .map(|(index, evaluation)| {
+                (
+                    SignificantBitOrder::index_at(*index, polynomial.num_vars),
+                    *evaluation,
+                )
+            })
+            .collect();
+        entries.sort_unstable_by_key(|(index, _)| *index);
+        Self::new(polynomial.num_vars, entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+    use ark_poly::{Polynomial, SparseMultilinearExtension};
+
+    use crate::{
+        streams::{multivariate_evaluation, MemoryStream, SparseStream, Stream},
+        tests::F64,
+    };
+
+    #[test]
+    fn agrees_with_ark_poly() {
+        let mut rng = ark_std::test_rng();
+        let polynomial = SparseMultilinearExtension::<F64>::rand_with_config(7, 1 << 4, &mut rng);
+        let point: Vec<F64> = (0..7).map(|_| F64::rand(&mut rng)).collect();
+        let expected = polynomial.evaluate(&point);
+
+        let stream = MemoryStream::from(&polynomial);
+        let sparse = SparseStream::from(&polynomial);
+        assert_eq!(
+            multivariate_evaluation(polynomial.clone(), &point),
+            expected
+        );
+        assert_eq!(multivariate_evaluation(stream.clone(), &point), expected);
+        assert_eq!(multivariate_evaluation(sparse.clone(), &point), expected);
+        assert_eq!(sparse.sparse_len(), Some(polynomial.evaluations.len()));
+        assert_eq!(SparseMultilinearExtension::from(stream), polynomial);
+    }
+}
@@ -1,9 +1,35 @@
+mod adapters;
+mod eq;
 mod file;
+mod generator;
 mod memory;
+mod mle;
+mod skip;
+mod sparse;
 mod stream;
 mod stream_iterator;
 
-pub use file::FileStream;
+pub use adapters::{
+    FixedVariables, LinearCombinationStream, PartialEvalStream, PermutedVariablesStream,
+    ScaledStream,
+};
+pub use eq::EqStream;
+pub use file::{
+    checksum, open_columnar_file, open_montgomery_file, write_columnar_file, write_montgomery_file,
+    ColumnStream, ColumnarHeader, FileSink, FileStream, InterleavedFileStream,
+    MontgomeryFileStream, Representation, COLUMNAR_FILE_VERSION, DEFAULT_CHUNK_SIZE,
+};
+pub use generator::{FnStream, WitnessProgram, WitnessStream};
 pub use memory::{reorder_vec, MemoryStream};
-pub use stream::{multivariate_claim, multivariate_product_claim, Stream};
+pub use skip::UnivariateSkipStream;
+pub use sparse::{SparseFileStream, SparseStream};
+pub(crate) use stream::{
+    add_weighted_evaluations, fold_evaluations, fold_partial_sums, for_each_chunk,
+    map_stream_halves, reduce_stream_evaluations,
+};
+pub use stream::{
+    check_streams, multivariate_claim, multivariate_evaluation, multivariate_product_claim,
+    multivariate_product_evaluation, try_multivariate_product_claim, MaybeSync, Stream,
+    EVALUATIONS_CHUNK_SIZE,
+};
 pub use stream_iterator::StreamIterator;
@@ -0,0 +1,3 @@
+mod skip;
+
+pub use skip::UnivariateSkipStream;
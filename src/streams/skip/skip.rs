@@ -0,0 +1,58 @@
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::streams::Stream;
+
+/*
+ * What's left of a stream once its first k variables are bound to a point r of the
+ * univariate skip domain: f'(b) = sum_j L_j(r) * f(j, b), where L_j are the Lagrange
+ * polynomials of the domain. A pass over this stream is one pass over the original
+ */
+#[derive(Debug, Clone)]
+pub struct UnivariateSkipStream<F: Field, S: Stream<F>> {
+    pub stream: S,
+    pub weights: Vec<F>,
+    num_variables: usize,
+}
+
+impl<F: Field, S: Stream<F>> UnivariateSkipStream<F, S> {
+    pub fn new(stream: S, weights: Vec<F>) -> Self {
+        // one weight per point of the domain, which covers some of the variables
+        assert!(weights.len().is_power_of_two());
+        let num_skipped_variables = weights.len().ilog2() as usize;
+        assert!(num_skipped_variables <= stream.num_variables());
+        Self {
+            num_variables: stream.num_variables() - num_skipped_variables,
+            stream,
+            weights,
+        }
+    }
+}
+
+impl<F: Field, S: Stream<F>> Stream<F> for UnivariateSkipStream<F, S> {
+    fn evaluation(&self, point: usize) -> F {
+        self.weights
+            .iter()
+            .enumerate()
+            .map(|(j, weight)| *weight * self.stream.evaluation(j << self.num_variables | point))
+            .sum()
+    }
+    fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+    // each block of the domain is contiguous in the original stream, so it's one run per block
+    fn evaluations_into(&self, start: usize, evaluations: &mut [F]) {
+        evaluations.fill(F::ZERO);
+        let mut block = vec![F::ZERO; evaluations.len()];
+        for (j, weight) in self.weights.iter().enumerate() {
+            if weight.is_zero() {
+                continue;
+            }
+            self.stream
+                .evaluations_into(j << self.num_variables | start, &mut block);
+            for (evaluation, value) in evaluations.iter_mut().zip(&block) {
+                *evaluation += *weight * value;
+            }
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use ark_ff::Field;
+use ark_serialize::Compress;
+use ark_std::{
+    fs::File,
+    io::{BufWriter, Write},
+    marker::PhantomData,
+    path::Path,
+    sync::Arc,
+};
+use memmap2::Mmap;
+
+use crate::{
+    error::SumcheckError,
+    streams::{sparse::sparse::check_sparse_indices, Stream},
+};
+
+const SIZE_OF_INDEX: usize = 8;
+
+/*
+ * A SparseStream on disk: the number of variables, then one record per non-zero
+ * evaluation, its index as a little endian u64 followed by the value, sorted by index
+ */
+#[derive(Debug)]
+pub struct SparseFileStream<F: Field> {
+    len: usize,
+    num_variables: usize,
+    s: Arc<Mmap>,
+    size_of_serialized: usize,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field> Clone for SparseFileStream<F> {
+    fn clone(&self) -> Self {
+        Self {
+            len: self.len,
+            num_variables: self.num_variables,
+            s: self.s.clone(),
+            size_of_serialized: self.size_of_serialized,
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: Field> SparseFileStream<F> {
+    pub fn new(path: String) -> Self {
+        Self::try_new(path).unwrap()
+    }
+    pub fn try_new(path: String) -> Result<Self, SumcheckError> {
+        let file = File::open(Path::new(&path))?;
+        let mmap = unsafe { Mmap::map(&file) }?;
+        let size_of_serialized = F::ONE.serialized_size(Compress::No);
+        let size_of_record = SIZE_OF_INDEX + size_of_serialized;
+        if mmap.len() < SIZE_OF_INDEX
+            || !(mmap.len() - SIZE_OF_INDEX).is_multiple_of(size_of_record)
+        {
+            return Err(SumcheckError::TruncatedFile {
+                len: mmap.len(),
+                element_size: size_of_record,
+            });
+        }
+        let num_variables = u64::from_le_bytes(mmap[..SIZE_OF_INDEX].try_into().unwrap());
+        if num_variables >= usize::BITS as u64 {
+            return Err(SumcheckError::HeaderMismatch("num_variables"));
+        }
+        let num_variables = num_variables as usize;
+        let stream = Self {
+            len: (mmap.len() - SIZE_OF_INDEX) / size_of_record,
+            num_variables,
+            s: Arc::new(mmap),
+            size_of_serialized,
+            _field: PhantomData,
+        };
+        check_sparse_indices(
+            (0..stream.len).map(|position| stream.read_index(position)),
+            num_variables,
+        )?;
+        // and every value is canonical, so sparse_entry can't fail in the middle of a proof
+        for position in 0..stream.len {
+            F::deserialize_uncompressed(&stream.record(position)[SIZE_OF_INDEX..])
+                .map_err(|_| SumcheckError::InvalidElement(position))?;
+        }
+        Ok(stream)
+    }
+    pub fn write_to_file(path: String, num_variables: usize, entries: &[(usize, F)]) {
+        let file = File::create(path).unwrap();
+        let mut writer = BufWriter::new(file);
+        writer
+            .write_all(&(num_variables as u64).to_le_bytes())
+            .unwrap();
+        for (index, value) in entries {
+            writer.write_all(&(*index as u64).to_le_bytes()).unwrap();
+            value.serialize_uncompressed(&mut writer).unwrap();
+        }
+        writer.flush().unwrap();
+    }
+    fn record(&self, position: usize) -> &[u8] {
+        let size_of_record = SIZE_OF_INDEX + self.size_of_serialized;
+        let start = SIZE_OF_INDEX + position * size_of_record;
+        &self.s[start..start + size_of_record]
+    }
+    fn read_index(&self, position: usize) -> usize {
+        u64::from_le_bytes(self.record(position)[..SIZE_OF_INDEX].try_into().unwrap()) as usize
+    }
+}
+
+impl<F: Field> Stream<F> for SparseFileStream<F> {
+    fn evaluation(&self, point: usize) -> F {
+        // binary search over the records
+        let (mut low, mut high) = (0, self.len);
+        while low < high {
+            let middle = (low + high) / 2;
+            match self.read_index(middle) {
+                index if index == point => return self.sparse_entry(middle).1,
+                index if index < point => low = middle + 1,
+                _ => high = middle,
+            }
+        }
+        F::ZERO
+    }
+    fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+    fn sparse_len(&self) -> Option<usize> {
+        Some(self.len)
+    }
+    fn sparse_entry(&self, position: usize) -> (usize, F) {
+        let record = self.record(position);
+        (
+            self.read_index(position),
+            F::deserialize_uncompressed(&record[SIZE_OF_INDEX..]).unwrap(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::fs;
+
+    use crate::{
+        error::SumcheckError,
+        streams::{SparseFileStream, SparseStream, Stream},
+        tests::F64,
+    };
+
+    #[test]
+    fn agrees_with_memory() {
+        let path = std::env::temp_dir().join("sparse_file_stream_agrees_with_memory.bin");
+        let path = path.to_str().unwrap().to_string();
+        let entries = vec![(0, F64::from(3)), (5, F64::from(7)), (31, F64::from(11))];
+        SparseFileStream::write_to_file(path.clone(), 5, &entries);
+
+        let file = SparseFileStream::<F64>::new(path.clone());
+        let memory = SparseStream::new(5, entries.clone());
+        assert_eq!(file.sparse_len(), Some(3));
+        for point in 0..32 {
+            assert_eq!(file.evaluation(point), memory.evaluation(point));
+        }
+        assert_eq!(file.sparse_entry(1), (5, F64::from(7)));
+
+        // out of order entries are refused
+        SparseFileStream::write_to_file(path.clone(), 5, &[(5, F64::from(1)), (2, F64::from(1))]);
+        assert!(matches!(
+            SparseFileStream::<F64>::try_new(path.clone()),
+            Err(SumcheckError::InvalidSparseEntry(1))
+        ));
+
+        // a number of variables past usize, and a value that isn't reduced
+        fs::write(&path, 64_u64.to_le_bytes()).unwrap();
+        assert!(matches!(
+            SparseFileStream::<F64>::try_new(path.clone()),
+            Err(SumcheckError::HeaderMismatch("num_variables"))
+        ));
+        SparseFileStream::write_to_file(path.clone(), 5, &entries);
+        let mut bytes = fs::read(&path).unwrap();
+        let last = bytes.len() - 8;
+        bytes[last..].copy_from_slice(&u64::MAX.to_le_bytes());
+        fs::write(&path, &bytes).unwrap();
+        assert!(matches!(
+            SparseFileStream::<F64>::try_new(path.clone()),
+            Err(SumcheckError::InvalidElement(2))
+        ));
+        fs::remove_file(&path).unwrap();
+    }
+}
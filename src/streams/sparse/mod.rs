@@ -0,0 +1,5 @@
+mod file;
+mod sparse;
+
+pub use file::SparseFileStream;
+pub use sparse::SparseStream;
@@ -0,0 +1,183 @@
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::{error::SumcheckError, streams::Stream};
+
+// the indices should be strictly increasing and inside the hypercube
+pub(crate) fn check_sparse_indices(
+    indices: impl Iterator<Item = usize>,
+    num_variables: usize,
+) -> Result<(), SumcheckError> {
+    let mut previous: Option<usize> = None;
+    for (position, index) in indices.enumerate() {
+        if index.checked_shr(num_variables as u32).unwrap_or(0) != 0
+            || previous.is_some_and(|previous| previous >= index)
+        {
+            return Err(SumcheckError::InvalidSparseEntry(position));
+        }
+        previous = Some(index);
+    }
+    Ok(())
+}
+
+/*
+ * The non-zero evaluations of a polynomial as (index, value) pairs sorted by index,
+ * for selectors and the like. Reading a single evaluation is a binary search, but
+ * the provers mostly walk the entries through sparse_entry
+ */
+#[derive(Debug, Clone)]
+pub struct SparseStream<F: Field> {
+    pub entries: Vec<(usize, F)>,
+    num_variables: usize,
+}
+
+impl<F: Field> SparseStream<F> {
+    pub fn new(num_variables: usize, entries: Vec<(usize, F)>) -> Self {
+        Self::try_new(num_variables, entries).unwrap()
+    }
+    pub fn try_new(num_variables: usize, entries: Vec<(usize, F)>) -> Result<Self, SumcheckError> {
+        check_sparse_indices(entries.iter().map(|(index, _)| *index), num_variables)?;
+        Ok(Self {
+            entries,
+            num_variables,
+        })
+    }
+    // drops the zeros of a dense table of evaluations
+    pub fn from_evaluations(evaluations: &[F]) -> Self {
+        assert!(evaluations.len().is_power_of_two());
+        Self {
+            entries: evaluations
+                .iter()
+                .enumerate()
+                .filter(|(_, evaluation)| !evaluation.is_zero())
+                .map(|(index, evaluation)| (index, *evaluation))
+                .collect(),
+            num_variables: evaluations.len().ilog2() as usize,
+        }
+    }
+}
+
+impl<F: Field> Stream<F> for SparseStream<F> {
+    fn evaluation(&self, point: usize) -> F {
+        match self
+            .entries
+            .binary_search_by_key(&point, |(index, _)| *index)
+        {
+            Ok(position) => self.entries[position].1,
+            Err(_) => F::ZERO,
+        }
+    }
+    fn evaluations_into(&self, start: usize, evaluations: &mut [F]) {
+        // zeros, then the entries that fall in the range
+        evaluations.fill(F::ZERO);
+        let first = self.entries.partition_point(|(index, _)| *index < start);
+        for (index, value) in &self.entries[first..] {
+            if *index >= start + evaluations.len() {
+                break;
+            }
+            evaluations[index - start] = *value;
+        }
+    }
+    fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+    fn sparse_len(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+    fn sparse_entry(&self, position: usize) -> (usize, F) {
+        self.entries[position]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+
+    use crate::{
+        error::SumcheckError,
+        multilinear::{
+            BlendyProver, BlendyProverConfig, SpaceProver, SpaceProverConfig, TimeProver,
+            TimeProverConfig,
+        },
+        prover::Prover,
+        streams::{SparseStream, Stream},
+        tests::{multilinear::parity_test, F64},
+    };
+
+    // a sparse stream the provers can only walk entry by entry
+    #[derive(Clone)]
+    struct EntriesOnly(SparseStream<F64>);
+
+    impl Stream<F64> for EntriesOnly {
+        fn evaluation(&self, _point: usize) -> F64 {
+            panic!("a sparse stream was read densely")
+        }
+        fn num_variables(&self) -> usize {
+            self.0.num_variables()
+        }
+        fn sparse_len(&self) -> Option<usize> {
+            self.0.sparse_len()
+        }
+        fn sparse_entry(&self, position: usize) -> (usize, F64) {
+            self.0.sparse_entry(position)
+        }
+    }
+
+    #[test]
+    fn evaluation() {
+        let evaluations: Vec<F64> = (0..16)
+            .map(|i| match i % 5 {
+                0 => F64::from(i as u64 + 1),
+                _ => F64::from(0),
+            })
+            .collect();
+        let stream = SparseStream::from_evaluations(&evaluations);
+        assert_eq!(stream.sparse_len(), Some(4));
+        for (point, evaluation) in evaluations.iter().enumerate() {
+            assert_eq!(stream.evaluation(point), *evaluation);
+        }
+
+        assert!(matches!(
+            SparseStream::try_new(3, vec![(1, F64::from(1)), (1, F64::from(2))]),
+            Err(SumcheckError::InvalidSparseEntry(1))
+        ));
+        assert!(matches!(
+            SparseStream::try_new(3, vec![(8, F64::from(1))]),
+            Err(SumcheckError::InvalidSparseEntry(0))
+        ));
+    }
+
+    #[test]
+    fn sparse_provers() {
+        // a selector like column, one non-zero in every 16
+        let mut rng = ark_std::test_rng();
+        let evaluations: Vec<F64> = (0..1 << 10)
+            .map(|i| match i % 16 == 3 {
+                true => F64::rand(&mut rng),
+                false => F64::from(0),
+            })
+            .collect();
+        let sparse = EntriesOnly(SparseStream::from_evaluations(&evaluations));
+        let claim: F64 = evaluations.iter().sum();
+
+        parity_test::<F64, EntriesOnly, TimeProver<F64, EntriesOnly>>(
+            evaluations.clone(),
+            &mut TimeProver::new(TimeProverConfig::new(claim, 10, sparse.clone())),
+        );
+        parity_test::<F64, EntriesOnly, SpaceProver<F64, EntriesOnly>>(
+            evaluations.clone(),
+            &mut SpaceProver::new(SpaceProverConfig::new(claim, 10, sparse.clone())),
+        );
+        for num_stages in [1, 2, 3] {
+            parity_test::<F64, EntriesOnly, BlendyProver<F64, EntriesOnly>>(
+                evaluations.clone(),
+                &mut BlendyProver::new(BlendyProverConfig::new(
+                    claim,
+                    num_stages,
+                    10,
+                    sparse.clone(),
+                )),
+            );
+        }
+    }
+}
@@ -1,4 +1,16 @@
 use ark_ff::Field;
+use ark_std::{cfg_into_iter, vec::Vec};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{
+    error::SumcheckError,
+    hypercube::Hypercube,
+    interpolation::LagrangePolynomial,
+    messages::VerifierMessages,
+    order_strategy::{GraycodeOrder, SignificantBitOrder},
+    parallel::fold_range,
+};
 
 pub fn multivariate_claim<F: Field, S: Stream<F>>(stream: S) -> F {
     let mut claim = F::zero();
@@ -13,16 +25,38 @@ pub fn multivariate_claim<F: Field, S: Stream<F>>(stream: S) -> F {
     claim
 }
 
-pub fn multivariate_product_claim<F: Field, S: Stream<F>>(streams: Vec<S>) -> F {
-    // should be given at least one stream
-    let number_of_streams = streams.len();
-    assert!(number_of_streams > 0);
-
-    // all streams should have the same number of variables
-    let num_vars = streams[0].num_variables();
+// should be given at least one stream, and all of them should have num_variables variables
+pub fn check_streams<F: Field, S: Stream<F>>(
+    streams: &[S],
+    num_variables: usize,
+) -> Result<(), SumcheckError> {
+    if streams.is_empty() {
+        return Err(SumcheckError::NoStreams);
+    }
     for stream in streams.iter() {
-        assert_eq!(stream.num_variables(), num_vars);
+        if stream.num_variables() != num_variables {
+            return Err(SumcheckError::NumVariablesMismatch {
+                expected: num_variables,
+                found: stream.num_variables(),
+            });
+        }
     }
+    Ok(())
+}
+
+pub fn multivariate_product_claim<F: Field, S: Stream<F>>(streams: Vec<S>) -> F {
+    try_multivariate_product_claim(streams).unwrap()
+}
+
+pub fn try_multivariate_product_claim<F: Field, S: Stream<F>>(
+    streams: Vec<S>,
+) -> Result<F, SumcheckError> {
+    // all streams should have the same number of variables as the first
+    let num_vars = streams
+        .first()
+        .ok_or(SumcheckError::NoStreams)?
+        .num_variables();
+    check_streams(&streams, num_vars)?;
 
     // calculate the claim
     let mut claim = F::zero();
@@ -35,10 +69,587 @@ pub fn multivariate_product_claim<F: Field, S: Stream<F>>(streams: Vec<S>) -> F
         claim += inner_sum;
     }
 
-    claim
+    Ok(claim)
+}
+
+pub fn multivariate_evaluation<F: Field, S: Stream<F>>(stream: S, point: &[F]) -> F {
+    // the point should fix every variable
+    assert_eq!(stream.num_variables(), point.len());
+
+    // f(r) = sum over b of eq(r, b) * f(b), walking the hypercube in graycode order
+    let verifier_messages = VerifierMessages::new(&point.to_vec());
+    let mut sequential_lag_poly: LagrangePolynomial<F, GraycodeOrder> =
+        LagrangePolynomial::new(&verifier_messages);
+    let mut evaluation = F::zero();
+    for (index, _) in Hypercube::<GraycodeOrder>::new(point.len()) {
+        let lag_poly = sequential_lag_poly.next().unwrap();
+        if lag_poly != F::zero() {
+            evaluation += lag_poly * stream.evaluation(index);
+        }
+    }
+
+    evaluation
+}
+
+pub fn multivariate_product_evaluation<F: Field, S: Stream<F>>(streams: Vec<S>, point: &[F]) -> F {
+    // should be given at least one stream
+    assert!(!streams.is_empty());
+
+    // each factor is evaluated on its own
+    streams
+        .into_iter()
+        .map(|stream| multivariate_evaluation(stream, point))
+        .product()
 }
 
-pub trait Stream<F: Field>: Clone {
+/*
+ * With the parallel feature the provers share their streams across rayon threads,
+ * so streams have to be Send and Sync, without it anything is a stream
+ */
+#[cfg(feature = "parallel")]
+pub trait MaybeSync: Send + Sync {}
+#[cfg(feature = "parallel")]
+impl<T: Send + Sync> MaybeSync for T {}
+#[cfg(not(feature = "parallel"))]
+pub trait MaybeSync {}
+#[cfg(not(feature = "parallel"))]
+impl<T> MaybeSync for T {}
+
+pub trait Stream<F: Field>: Clone + MaybeSync {
     fn evaluation(&self, point: usize) -> F;
     fn num_variables(&self) -> usize;
+    /*
+     * A stream that's mostly zeros lists its non-zero evaluations instead, sorted by
+     * index, and the provers then only visit those. Dense streams return None here and
+     * sparse_entry is never called on them
+     */
+    fn sparse_len(&self) -> Option<usize> {
+        None
+    }
+    fn sparse_entry(&self, _position: usize) -> (usize, F) {
+        unreachable!("a dense stream has no sparse entries")
+    }
+    /*
+     * The evaluations at start, start + 1, ... in one call, which streams that can
+     * decode many elements at once (from memory or a file) override
+     */
+    fn evaluations_into(&self, start: usize, evaluations: &mut [F]) {
+        for (offset, evaluation) in evaluations.iter_mut().enumerate() {
+            *evaluation = self.evaluation(start + offset);
+        }
+    }
+    /*
+     * The same run of evaluations of all the factors of a product, one slice per
+     * stream, which the product provers always read together. Streams that share
+     * storage, like the columns of an InterleavedFileStream, override this to read
+     * the run of every factor in one go
+     */
+    fn fused_evaluations_into(streams: &[Self], start: usize, evaluations: &mut [&mut [F]]) {
+        for (evaluations, stream) in evaluations.iter_mut().zip(streams) {
+            stream.evaluations_into(start, evaluations);
+        }
+    }
+}
+
+// how many evaluations the provers read from a stream at once
+pub const EVALUATIONS_CHUNK_SIZE: usize = 1 << 10;
+
+// visits the evaluations at start..start + len, a chunk at a time read into buffer, which grows to a chunk if it's shorter
+pub(crate) fn for_each_chunk<F: Field, S: Stream<F>>(
+    stream: &S,
+    start: usize,
+    len: usize,
+    buffer: &mut Vec<F>,
+    mut visit: impl FnMut(usize, &[F]),
+) {
+    if buffer.len() < len.min(EVALUATIONS_CHUNK_SIZE) {
+        buffer.resize(len.min(EVALUATIONS_CHUNK_SIZE), F::ZERO);
+    }
+    let mut offset = 0;
+    while offset < len {
+        let chunk_len = (len - offset).min(EVALUATIONS_CHUNK_SIZE);
+        stream.evaluations_into(start + offset, &mut buffer[..chunk_len]);
+        visit(start + offset, &buffer[..chunk_len]);
+        offset += chunk_len;
+    }
+}
+
+// like fold_range over the evaluations at start..start + len, split across threads by chunk
+pub(crate) fn fold_evaluations<F, S, T, I, G, R>(
+    stream: &S,
+    start: usize,
+    len: usize,
+    identity: I,
+    fold: G,
+    reduce: R,
+) -> T
+where
+    F: Field,
+    S: Stream<F>,
+    T: Send,
+    I: Fn() -> T + MaybeSync,
+    G: Fn(T, usize, F) -> T + MaybeSync,
+    R: Fn(T, T) -> T + MaybeSync,
+{
+    fold_range(
+        len.div_ceil(EVALUATIONS_CHUNK_SIZE),
+        identity,
+        |acc, chunk| {
+            let chunk_start = start + chunk * EVALUATIONS_CHUNK_SIZE;
+            let mut evaluations =
+                vec![F::ZERO; (start + len - chunk_start).min(EVALUATIONS_CHUNK_SIZE)];
+            stream.evaluations_into(chunk_start, &mut evaluations);
+            evaluations
+                .into_iter()
+                .enumerate()
+                .fold(acc, |acc, (offset, evaluation)| {
+                    fold(acc, chunk_start + offset, evaluation)
+                })
+        },
+        reduce,
+    )
+}
+
+/*
+ * The product provers walk the streams in significant bit order, where the variables
+ * bound so far are the most significant bits of the index and come weighted by their
+ * Lagrange polynomials. This adds to sums, one run per stream, the weighted sums over
+ * those variables of the evaluations at start, start + 1, ... of the rest of the index.
+ * For one position of the bound variables the rest is contiguous, so that's one
+ * fused_evaluations_into per position, into buffer, which has a run per stream too
+ */
+pub(crate) fn add_weighted_evaluations<F: Field, S: Stream<F>>(
+    streams: &[S],
+    num_bound_variables: usize,
+    lag_polys: impl Iterator<Item = F>,
+    start: usize,
+    buffer: &mut [F],
+    sums: &mut [F],
+) {
+    let num_free_variables = streams[0].num_variables() - num_bound_variables;
+    let run_len = sums.len() / streams.len();
+    let mut runs: Vec<&mut [F]> = buffer.chunks_exact_mut(run_len).collect();
+    for (position, lag_poly) in lag_polys.take(1 << num_bound_variables).enumerate() {
+        if lag_poly == F::ZERO {
+            continue;
+        }
+        let prefix =
+            SignificantBitOrder::index_at(position, num_bound_variables) << num_free_variables;
+        S::fused_evaluations_into(streams, prefix | start, &mut runs);
+        for (sums, run) in sums.chunks_exact_mut(run_len).zip(&runs) {
+            for (sum, evaluation) in sums.iter_mut().zip(run.iter()) {
+                *sum += lag_poly * evaluation;
+            }
+        }
+    }
+}
+
+/*
+ * A round of the space product provers: for every block of the variables after this
+ * round's, the weighted sums of each stream with this round's variable at 0 and at 1,
+ * which fold gets as one value per stream each. The blocks are read a chunk at a time,
+ * with the buffers kept per thread. lag_polys gives the Lagrange polynomials of the
+ * bound variables in significant bit order, it's called once per chunk and half
+ */
+pub(crate) fn fold_partial_sums<F, S, T, P, L, I, G, R>(
+    streams: &[S],
+    num_bound_variables: usize,
+    lag_polys: P,
+    identity: I,
+    fold: G,
+    reduce: R,
+) -> T
+where
+    F: Field,
+    S: Stream<F>,
+    T: Send,
+    P: Fn() -> L + MaybeSync,
+    L: Iterator<Item = F>,
+    I: Fn() -> T + MaybeSync,
+    G: Fn(T, &[F], &[F]) -> T + MaybeSync,
+    R: Fn(T, T) -> T + MaybeSync,
+{
+    let num_streams = streams.len();
+    // this round's variable is the bit above the blocks
+    let num_blocks: usize = 1 << (streams[0].num_variables() - num_bound_variables - 1);
+    let chunk_len = num_blocks.min(EVALUATIONS_CHUNK_SIZE);
+    let (result, _) = fold_range(
+        num_blocks / chunk_len,
+        || {
+            (
+                identity(),
+                PartialSums {
+                    buffer: vec![F::ZERO; num_streams * chunk_len],
+                    sums: vec![F::ZERO; 2 * num_streams * chunk_len],
+                    block: vec![F::ZERO; 2 * num_streams],
+                },
+            )
+        },
+        |(mut acc, mut partial_sums), chunk| {
+            let PartialSums {
+                buffer,
+                sums,
+                block,
+            } = &mut partial_sums;
+            sums.fill(F::ZERO);
+            let (zeros, ones) = sums.split_at_mut(num_streams * chunk_len);
+            for (half, sums) in [(0, zeros), (num_blocks, ones)] {
+                add_weighted_evaluations(
+                    streams,
+                    num_bound_variables,
+                    lag_polys(),
+                    half + chunk * chunk_len,
+                    buffer,
+                    sums,
+                );
+            }
+            // the sums are by stream, fold takes them by block
+            for offset in 0..chunk_len {
+                for (value, sum) in block
+                    .iter_mut()
+                    .zip(sums.iter().skip(offset).step_by(chunk_len))
+                {
+                    *value = *sum;
+                }
+                let (zeros, ones) = block.split_at(num_streams);
+                acc = fold(acc, zeros, ones);
+            }
+            (acc, partial_sums)
+        },
+        |(a, partial_sums), (b, _)| (reduce(a, b), partial_sums),
+    );
+    result
+}
+
+// the buffers of fold_partial_sums
+struct PartialSums<F> {
+    buffer: Vec<F>,
+    sums: Vec<F>,
+    block: Vec<F>,
+}
+
+/*
+ * Maps the pairs of evaluations that differ in the leftmost variable, a chunk of the
+ * first half and the matching chunk of the second half at a time, which is how the
+ * provers make the first table out of a stream
+ */
+pub(crate) fn map_stream_halves<F, S, T, M>(stream: &S, map: M) -> Vec<T>
+where
+    F: Field,
+    S: Stream<F>,
+    T: Send,
+    M: Fn(F, F) -> T + MaybeSync,
+{
+    let half: usize = 1 << (stream.num_variables() - 1);
+    cfg_into_iter!(0..half.div_ceil(EVALUATIONS_CHUNK_SIZE))
+        .flat_map(|chunk| {
+            let start = chunk * EVALUATIONS_CHUNK_SIZE;
+            let chunk_len = (half - start).min(EVALUATIONS_CHUNK_SIZE);
+            let mut zeros = vec![F::ZERO; chunk_len];
+            let mut ones = vec![F::ZERO; chunk_len];
+            stream.evaluations_into(start, &mut zeros);
+            stream.evaluations_into(start | half, &mut ones);
+            zeros
+                .into_iter()
+                .zip(ones)
+                .map(|(zero, one)| map(zero, one))
+                .collect::<Vec<T>>()
+        })
+        .collect()
+}
+
+// the first fold of a stream into a table, binding its leftmost variable
+pub(crate) fn reduce_stream_evaluations<F: Field, S: Stream<F>>(
+    stream: &S,
+    verifier_message: F,
+    verifier_message_hat: F,
+) -> Vec<F> {
+    map_stream_halves(stream, |zero, one| {
+        zero * verifier_message_hat + one * verifier_message
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+
+    use crate::{
+        error::SumcheckError,
+        hypercube::HypercubeMember,
+        multi_product::{
+            BlendyMultiProductProver, SpaceMultiProductProver, TimeMultiProductProver,
+        },
+        multilinear_product::{BlendyProductProver, SpaceProductProver, TimeProductProver},
+        order_strategy::{OrderStrategy, SignificantBitOrder},
+        prover::{ProductProverConfig, Prover, VirtualProverConfig},
+        streams::{
+            multivariate_evaluation, multivariate_product_claim, multivariate_product_evaluation,
+            try_multivariate_product_claim, FileStream, LinearCombinationStream, MemoryStream,
+            ScaledStream, SparseStream, Stream, StreamIterator, UnivariateSkipStream,
+            EVALUATIONS_CHUNK_SIZE,
+        },
+        tests::{
+            polynomials::{four_variable_polynomial, Polynomial},
+            ChunkedStream, F19, F64,
+        },
+        virtual_polynomial::{
+            BlendyVirtualProver, SpaceVirtualProver, TimeVirtualProver, VirtualPolynomial,
+        },
+        MultiProductSumcheck, ProductSumcheck,
+    };
+
+    fn check_evaluations_into<S: Stream<F64>>(stream: &S) {
+        let len = 1 << stream.num_variables();
+        for (start, chunk_len) in [(0, len), (3, 17), (len - 5, 5), (7, 0)] {
+            let mut evaluations = vec![F64::from(0); chunk_len];
+            stream.evaluations_into(start, &mut evaluations);
+            for (offset, evaluation) in evaluations.into_iter().enumerate() {
+                assert_eq!(evaluation, stream.evaluation(start + offset));
+            }
+        }
+    }
+
+    #[test]
+    fn evaluations_into() {
+        let mut rng = ark_std::test_rng();
+        let evaluations: Vec<F64> = (0..1 << 6).map(|_| F64::rand(&mut rng)).collect();
+        let memory = MemoryStream::new(evaluations.clone());
+        check_evaluations_into(&memory);
+        check_evaluations_into(&ScaledStream::new(memory.clone(), F64::from(3)));
+        check_evaluations_into(&UnivariateSkipStream::new(
+            memory.clone(),
+            vec![F64::from(2), F64::from(5)],
+        ));
+        check_evaluations_into(&LinearCombinationStream::new(
+            vec![memory.clone(), memory.clone()],
+            vec![F64::from(2), F64::from(5)],
+        ));
+        let mut sparse = evaluations.clone();
+        sparse.iter_mut().step_by(3).for_each(|e| *e = F64::from(0));
+        check_evaluations_into(&SparseStream::from_evaluations(&sparse));
+
+        let path = std::env::temp_dir().join("stream_evaluations_into_test_evals.bin");
+        let path = path.to_str().unwrap().to_string();
+        FileStream::write_to_file(path.clone(), &evaluations);
+        check_evaluations_into(&FileStream::<F64>::new(path.clone()));
+        FileStream::<F64>::delete_file(path);
+    }
+
+    #[test]
+    fn stream_iterator_order() {
+        // more evaluations than one chunk, in an order that isn't contiguous
+        let num_variables = EVALUATIONS_CHUNK_SIZE.ilog2() as usize + 2;
+        let evaluations: Vec<F64> = (0..1 << num_variables).map(F64::from).collect();
+        let mut iterator: StreamIterator<F64, MemoryStream<F64>, SignificantBitOrder> =
+            StreamIterator::new(MemoryStream::new(evaluations.clone()));
+        let expected: Vec<F64> = SignificantBitOrder::new(num_variables)
+            .map(|index| evaluations[index])
+            .collect();
+        assert_eq!((&mut iterator).collect::<Vec<F64>>(), expected);
+
+        // and again after a reset
+        iterator.reset();
+        assert_eq!(iterator.collect::<Vec<F64>>(), expected);
+    }
+
+    fn product_transcript<P>(streams: Vec<MemoryStream<F64>>) -> ProductSumcheck<F64>
+    where
+        P: Prover<F64, VerifierMessage = Option<F64>, ProverMessage = Option<(F64, F64, F64)>>,
+        P::ProverConfig: ProductProverConfig<F64, ChunkedStream<F64>>,
+    {
+        let claim = multivariate_product_claim(streams.clone());
+        let num_variables = streams[0].num_variables();
+        let streams = streams.into_iter().map(ChunkedStream::from).collect();
+        ProductSumcheck::prove::<ChunkedStream<F64>, P>(
+            &mut P::new(ProductProverConfig::default(claim, num_variables, streams)),
+            &mut ark_std::test_rng(),
+        )
+    }
+
+    fn multi_product_transcript<P>(streams: Vec<MemoryStream<F64>>) -> MultiProductSumcheck<F64>
+    where
+        P: Prover<F64, VerifierMessage = Option<F64>, ProverMessage = Option<Vec<F64>>>,
+        P::ProverConfig: ProductProverConfig<F64, ChunkedStream<F64>>,
+    {
+        let claim = multivariate_product_claim(streams.clone());
+        let num_variables = streams[0].num_variables();
+        let streams = streams.into_iter().map(ChunkedStream::from).collect();
+        MultiProductSumcheck::prove::<ChunkedStream<F64>, P>(
+            &mut P::new(ProductProverConfig::default(claim, num_variables, streams)),
+            &mut ark_std::test_rng(),
+        )
+    }
+
+    fn virtual_transcript<P>(
+        polynomial: VirtualPolynomial<F64, MemoryStream<F64>>,
+    ) -> MultiProductSumcheck<F64>
+    where
+        P: Prover<F64, VerifierMessage = Option<F64>, ProverMessage = Option<Vec<F64>>>,
+        P::ProverConfig: VirtualProverConfig<F64, ChunkedStream<F64>>,
+    {
+        let claim = polynomial.claim();
+        let num_variables = polynomial.num_variables();
+        let streams = polynomial
+            .streams
+            .into_iter()
+            .map(ChunkedStream::from)
+            .collect();
+        MultiProductSumcheck::prove::<ChunkedStream<F64>, P>(
+            &mut P::new(VirtualProverConfig::default(
+                claim,
+                num_variables,
+                VirtualPolynomial::new(streams, polynomial.terms),
+            )),
+            &mut ark_std::test_rng(),
+        )
+    }
+
+    #[test]
+    fn provers_read_chunks() {
+        // ChunkedStream panics on evaluation, so these provers only read through evaluations_into
+        let num_variables = 12;
+        let mut rng = ark_std::test_rng();
+        let streams: Vec<MemoryStream<F64>> = (0..3)
+            .map(|_| {
+                MemoryStream::new(
+                    (0..1 << num_variables)
+                        .map(|_| F64::rand(&mut rng))
+                        .collect(),
+                )
+            })
+            .collect();
+
+        let expected =
+            product_transcript::<TimeProductProver<F64, ChunkedStream<F64>>>(streams[..2].to_vec());
+        assert!(expected.is_accepted);
+        assert_eq!(
+            product_transcript::<SpaceProductProver<F64, ChunkedStream<F64>>>(
+                streams[..2].to_vec()
+            ),
+            expected
+        );
+        assert_eq!(
+            product_transcript::<BlendyProductProver<F64, ChunkedStream<F64>>>(
+                streams[..2].to_vec()
+            ),
+            expected
+        );
+
+        let expected = multi_product_transcript::<TimeMultiProductProver<F64, ChunkedStream<F64>>>(
+            streams.clone(),
+        );
+        assert!(expected.is_accepted);
+        assert_eq!(
+            multi_product_transcript::<SpaceMultiProductProver<F64, ChunkedStream<F64>>>(
+                streams.clone()
+            ),
+            expected
+        );
+        assert_eq!(
+            multi_product_transcript::<BlendyMultiProductProver<F64, ChunkedStream<F64>>>(
+                streams.clone()
+            ),
+            expected
+        );
+
+        let polynomial = VirtualPolynomial::new(
+            streams,
+            vec![(F64::from(1), vec![0, 1]), (F64::from(3), vec![2])],
+        );
+        let expected =
+            virtual_transcript::<TimeVirtualProver<F64, ChunkedStream<F64>>>(polynomial.clone());
+        assert!(expected.is_accepted);
+        assert_eq!(
+            virtual_transcript::<SpaceVirtualProver<F64, ChunkedStream<F64>>>(polynomial.clone()),
+            expected
+        );
+        assert_eq!(
+            virtual_transcript::<BlendyVirtualProver<F64, ChunkedStream<F64>>>(polynomial),
+            expected
+        );
+    }
+
+    #[test]
+    fn evaluation_matches_polynomial() {
+        let p = four_variable_polynomial::<F19>();
+        let s: MemoryStream<F19> = MemoryStream::new(p.to_evaluations());
+
+        // on the hypercube it's just a lookup
+        let boolean_point: Vec<F19> = HypercubeMember::new(4, 11)
+            .map(|bit| if bit { F19::from(1) } else { F19::from(0) })
+            .collect();
+        assert_eq!(
+            multivariate_evaluation(s.clone(), &boolean_point),
+            p.to_evaluations()[11]
+        );
+
+        // off the hypercube it's the multilinear extension
+        let point = vec![F19::from(3), F19::from(4), F19::from(7), F19::from(13)];
+        let expected = p.evaluate(point.clone()).unwrap();
+        assert_eq!(multivariate_evaluation(s.clone(), &point), expected);
+        assert_eq!(
+            multivariate_product_evaluation(vec![s.clone(), s], &point),
+            expected * expected
+        );
+    }
+
+    #[test]
+    fn malformed_inputs() {
+        assert!(matches!(
+            MemoryStream::<F19>::try_new(vec![F19::from(1); 3]),
+            Err(SumcheckError::NotPowerOfTwo(3))
+        ));
+        assert!(matches!(
+            try_multivariate_product_claim::<F19, MemoryStream<F19>>(vec![]),
+            Err(SumcheckError::NoStreams)
+        ));
+        let streams = vec![
+            MemoryStream::new(vec![F19::from(1); 4]),
+            MemoryStream::new(vec![F19::from(1); 8]),
+        ];
+        assert!(matches!(
+            try_multivariate_product_claim(streams),
+            Err(SumcheckError::NumVariablesMismatch {
+                expected: 2,
+                found: 3
+            })
+        ));
+    }
+
+    // without the parallel feature a stream doesn't have to be Send or Sync
+    #[cfg(not(feature = "parallel"))]
+    #[test]
+    fn unsync_stream() {
+        use crate::{
+            multilinear::{BlendyProver, BlendyProverConfig},
+            prover::Prover,
+            Sumcheck,
+        };
+        use ark_std::rc::Rc;
+
+        #[derive(Clone)]
+        struct RcStream(Rc<Vec<F64>>);
+        impl Stream<F64> for RcStream {
+            fn evaluation(&self, point: usize) -> F64 {
+                self.0[point]
+            }
+            fn num_variables(&self) -> usize {
+                self.0.len().ilog2() as usize
+            }
+        }
+
+        let mut rng = ark_std::test_rng();
+        let evaluations: Vec<F64> = (0..1 << 8).map(|_| F64::rand(&mut rng)).collect();
+        let claim: F64 = evaluations.iter().sum();
+        let transcript = Sumcheck::<F64>::prove::<RcStream, BlendyProver<F64, RcStream>>(
+            &mut BlendyProver::new(BlendyProverConfig::new(
+                claim,
+                2,
+                8,
+                RcStream(Rc::new(evaluations)),
+            )),
+            &mut rng,
+        );
+        assert!(transcript.is_accepted);
+    }
 }
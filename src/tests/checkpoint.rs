@@ -0,0 +1,44 @@
+use ark_ff::Field;
+use ark_std::{fmt::Debug, vec::Vec};
+
+use crate::prover::{Checkpoint, Prover};
+
+fn run_rounds<F, P>(prover: &mut P, rounds: std::ops::Range<usize>) -> Vec<P::ProverMessage>
+where
+    F: Field,
+    P: Prover<F, VerifierMessage = Option<F>>,
+{
+    // fixed challenges so both runs see the same verifier
+    rounds
+        .map(|round| {
+            let verifier_message = match round {
+                0 => None,
+                _ => Some(F::from(round as u64 + 2)),
+            };
+            prover.next_message(verifier_message)
+        })
+        .collect()
+}
+
+// stopping after any round and resuming gives the same transcript as running straight through
+pub fn checkpoint_test<F, P>(
+    num_rounds: usize,
+    new_prover: impl Fn() -> P,
+    streams: impl Fn() -> P::Streams,
+) where
+    F: Field,
+    P: Prover<F, VerifierMessage = Option<F>> + Checkpoint,
+    P::ProverMessage: PartialEq + Debug,
+{
+    // one more than the number of rounds, so the run ends in a None
+    let expected = run_rounds::<F, P>(&mut new_prover(), 0..num_rounds + 1);
+    for stop in 0..=num_rounds {
+        let mut prover = new_prover();
+        let mut messages = run_rounds::<F, P>(&mut prover, 0..stop);
+        let mut snapshot = Vec::new();
+        prover.checkpoint(&mut snapshot).unwrap();
+        let mut resumed = P::resume(&snapshot[..], streams()).unwrap();
+        messages.extend(run_rounds::<F, P>(&mut resumed, stop..num_rounds + 1));
+        assert_eq!(messages, expected);
+    }
+}
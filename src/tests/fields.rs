@@ -1,4 +1,7 @@
-use ark_ff::fields::{Fp128, Fp64, MontBackend, MontConfig};
+use ark_ff::{
+    fields::{Fp128, Fp2, Fp2Config, Fp64, MontBackend, MontConfig},
+    MontFp,
+};
 
 #[derive(MontConfig)]
 #[modulus = "19"]
@@ -8,10 +11,19 @@ pub type F19 = Fp64<MontBackend<F19Config, 1>>;
 
 #[derive(MontConfig)]
 #[modulus = "18446744069414584321"] // q = 2^64 - 2^32 + 1
-#[generator = "2"]
+#[generator = "7"] // 2 only has order 192, the roots of unity need a generator of the whole group
 pub struct F64Config;
 pub type F64 = Fp64<MontBackend<F64Config, 1>>;
 
+// F64[X] / (X^2 - 7), 7 isn't a square mod q
+pub struct F64Ext2Config;
+impl Fp2Config for F64Ext2Config {
+    type Fp = F64;
+    const NONRESIDUE: F64 = MontFp!("7");
+    const FROBENIUS_COEFF_FP2_C1: &'static [F64] = &[MontFp!("1"), MontFp!("-1")];
+}
+pub type F64Ext2 = Fp2<F64Ext2Config>;
+
 #[derive(MontConfig)]
 #[modulus = "143244528689204659050391023439224324689"] // q = 143244528689204659050391023439224324689
 #[generator = "2"]
@@ -1,8 +1,12 @@
+mod checkpoint;
 mod fields;
 mod streams;
 
+pub mod multi_product;
 pub mod multilinear;
 pub mod multilinear_product;
 pub mod polynomials;
-pub use fields::{F128, F19, F64};
-pub use streams::BenchStream;
+pub mod virtual_polynomial;
+pub use checkpoint::checkpoint_test;
+pub use fields::{F64Config, F64Ext2, F128, F19, F64};
+pub use streams::{BenchStream, ChunkedStream, CountingStream};
@@ -0,0 +1,52 @@
+use ark_ff::Field;
+
+use crate::{
+    multi_product::TimeMultiProductProver,
+    prover::{ProductProverConfig, Prover},
+    streams::{multivariate_product_claim, MemoryStream, Stream},
+    MultiProductSumcheck,
+};
+
+pub fn consistency_test<F, S, P>()
+where
+    F: Field,
+    S: Stream<F> + From<MemoryStream<F>> + Clone,
+    P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<Vec<F>>>,
+    P::ProverConfig: ProductProverConfig<F, S>,
+{
+    let num_variables = 8;
+    let mut rng = ark_std::test_rng();
+    for degree in [1, 3, 4] {
+        // get some distinct random streams
+        let streams: Vec<S> = (0..degree)
+            .map(|_| {
+                MemoryStream::new((0..1 << num_variables).map(|_| F::rand(&mut rng)).collect())
+                    .into()
+            })
+            .collect();
+        let claim = multivariate_product_claim(streams.clone());
+
+        // prove
+        let prover_transcript = MultiProductSumcheck::<F>::prove::<S, P>(
+            &mut P::new(ProductProverConfig::default(
+                claim,
+                num_variables,
+                streams.clone(),
+            )),
+            &mut ark_std::test_rng(),
+        );
+        let time_prover_transcript =
+            MultiProductSumcheck::<F>::prove::<S, TimeMultiProductProver<F, S>>(
+                &mut TimeMultiProductProver::new(ProductProverConfig::default(
+                    claim,
+                    num_variables,
+                    streams,
+                )),
+                &mut ark_std::test_rng(),
+            );
+
+        // ensure the transcript is identical
+        assert!(prover_transcript.is_accepted);
+        assert_eq!(prover_transcript, time_prover_transcript);
+    }
+}
@@ -0,0 +1,57 @@
+use ark_ff::Field;
+
+use crate::{
+    hypercube::Hypercube,
+    order_strategy::SignificantBitOrder,
+    prover::{ProductProverConfig, Prover},
+    streams::{multivariate_product_claim, multivariate_product_evaluation, MemoryStream, Stream},
+    tests::polynomials::four_variable_polynomial_evaluations,
+    MultiProductSumcheck,
+};
+
+pub fn sanity_test<F, S, P>()
+where
+    F: Field,
+    S: Stream<F> + From<MemoryStream<F>> + Clone,
+    P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<Vec<F>>>,
+    P::ProverConfig: ProductProverConfig<F, S>,
+{
+    // three copies of the four variable polynomial, so every round polynomial is a cubic
+    let s: S = MemoryStream::new(four_variable_polynomial_evaluations()).into();
+    let streams: Vec<S> = vec![s.clone(), s.clone(), s];
+    let claim = multivariate_product_claim(streams.clone());
+
+    let transcript = MultiProductSumcheck::<F>::prove::<S, P>(
+        &mut P::new(ProductProverConfig::default(claim, 4, streams.clone())),
+        &mut ark_std::test_rng(),
+    );
+    assert!(transcript.is_accepted);
+    assert!(transcript
+        .prover_messages
+        .iter()
+        .all(|message| message.len() == 4));
+
+    // every evaluation of the first round polynomial, g(s) = Σ_b f(s, b)^3
+    for (point, message) in transcript.prover_messages[0].iter().enumerate() {
+        let point = F::from(point as u64);
+        let mut sum = F::ZERO;
+        for (_, b) in Hypercube::<SignificantBitOrder>::new(3) {
+            let mut partial_point = vec![point];
+            partial_point.extend(b.map(|bit| match bit {
+                true => F::ONE,
+                false => F::ZERO,
+            }));
+            sum += multivariate_product_evaluation(streams.clone(), &partial_point);
+        }
+        assert_eq!(*message, sum);
+    }
+
+    // the reduced claim should be the product evaluated at the verifier's point
+    assert_eq!(
+        transcript.reduced_claim,
+        Some(multivariate_product_evaluation(
+            streams,
+            &transcript.verifier_messages
+        ))
+    );
+}
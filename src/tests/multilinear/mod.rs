@@ -1,9 +1,11 @@
 use ark_ff::Field;
 
 use crate::{
+    multilinear::{TimeProver, TimeProverConfig},
     prover::{Prover, ProverConfig},
     streams::{MemoryStream, Stream},
     tests::polynomials::three_variable_polynomial_evaluations,
+    Sumcheck,
 };
 
 pub fn multilinear_round_sanity<F, S, P>(p: &mut P, message: Option<F>, eval_0: F, eval_1: F)
@@ -84,3 +86,28 @@ where
         F::from(1_u32),
     );
 }
+
+/*
+ * Proves with p, whose stream holds the given evaluations, and checks the prover
+ * messages are those of the time prover over the evaluations in memory
+ */
+pub fn parity_test<F, S, P>(evaluations: Vec<F>, p: &mut P)
+where
+    F: Field,
+    S: Stream<F>,
+    P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F)>>,
+{
+    let claim: F = evaluations.iter().sum();
+    let num_variables = evaluations.len().ilog2() as usize;
+    let expected = Sumcheck::<F>::prove::<MemoryStream<F>, TimeProver<F, MemoryStream<F>>>(
+        &mut TimeProver::new(TimeProverConfig::new(
+            claim,
+            num_variables,
+            MemoryStream::new(evaluations),
+        )),
+        &mut ark_std::test_rng(),
+    );
+    let transcript = Sumcheck::<F>::prove::<S, P>(p, &mut ark_std::test_rng());
+    assert!(transcript.is_accepted);
+    assert_eq!(transcript.prover_messages, expected.prover_messages);
+}
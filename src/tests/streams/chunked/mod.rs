@@ -0,0 +1,28 @@
+use ark_ff::Field;
+
+use crate::streams::{MemoryStream, Stream};
+
+/*
+ * A stream that can only be read in chunks, for tests that check a prover never
+ * falls back to reading one evaluation at a time
+ */
+#[derive(Debug, Clone)]
+pub struct ChunkedStream<F: Field>(pub MemoryStream<F>);
+
+impl<F: Field> From<MemoryStream<F>> for ChunkedStream<F> {
+    fn from(stream: MemoryStream<F>) -> Self {
+        Self(stream)
+    }
+}
+
+impl<F: Field> Stream<F> for ChunkedStream<F> {
+    fn evaluation(&self, point: usize) -> F {
+        panic!("read evaluation {} outside of evaluations_into", point)
+    }
+    fn num_variables(&self) -> usize {
+        self.0.num_variables()
+    }
+    fn evaluations_into(&self, start: usize, evaluations: &mut [F]) {
+        self.0.evaluations_into(start, evaluations)
+    }
+}
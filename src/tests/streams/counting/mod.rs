@@ -0,0 +1,46 @@
+use ark_ff::Field;
+use ark_std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use crate::streams::{MemoryStream, Stream};
+
+/*
+ * Counts the evaluations read from a stream, for tests that check how many passes
+ * a prover makes over it. Clones share the count, as the provers clone their streams
+ */
+#[derive(Debug, Clone)]
+pub struct CountingStream<F: Field> {
+    pub stream: MemoryStream<F>,
+    reads: Arc<AtomicUsize>,
+}
+
+impl<F: Field> CountingStream<F> {
+    pub fn new(stream: MemoryStream<F>) -> Self {
+        Self {
+            stream,
+            reads: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+    pub fn reads(&self) -> usize {
+        self.reads.load(Ordering::Relaxed)
+    }
+}
+
+impl<F: Field> From<MemoryStream<F>> for CountingStream<F> {
+    fn from(stream: MemoryStream<F>) -> Self {
+        Self::new(stream)
+    }
+}
+
+// only evaluation is implemented, so every read goes through it
+impl<F: Field> Stream<F> for CountingStream<F> {
+    fn evaluation(&self, point: usize) -> F {
+        self.reads.fetch_add(1, Ordering::Relaxed);
+        self.stream.evaluation(point)
+    }
+    fn num_variables(&self) -> usize {
+        self.stream.num_variables()
+    }
+}
@@ -1,3 +1,7 @@
 mod bench;
+mod chunked;
+mod counting;
 
 pub use bench::BenchStream;
+pub use chunked::ChunkedStream;
+pub use counting::CountingStream;
@@ -0,0 +1,57 @@
+use ark_ff::Field;
+
+use crate::{
+    prover::{Prover, VirtualProverConfig},
+    streams::{MemoryStream, Stream},
+    virtual_polynomial::{TimeVirtualProver, VirtualPolynomial},
+    MultiProductSumcheck,
+};
+
+pub fn consistency_test<F, S, P>()
+where
+    F: Field,
+    S: Stream<F> + From<MemoryStream<F>> + Clone,
+    P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<Vec<F>>>,
+    P::ProverConfig: VirtualProverConfig<F, S>,
+{
+    let num_variables = 8;
+    let mut rng = ark_std::test_rng();
+
+    // g(e, a, b, c) = e * a * b - e * c + 3 * a, a mix of degrees
+    let streams: Vec<S> = (0..4)
+        .map(|_| {
+            MemoryStream::new((0..1 << num_variables).map(|_| F::rand(&mut rng)).collect()).into()
+        })
+        .collect();
+    let polynomial = VirtualPolynomial::new(
+        streams,
+        vec![
+            (F::ONE, vec![0, 1, 2]),
+            (-F::ONE, vec![0, 3]),
+            (F::from(3_u32), vec![1]),
+        ],
+    );
+    let claim = polynomial.claim();
+
+    // prove
+    let prover_transcript = MultiProductSumcheck::<F>::prove::<S, P>(
+        &mut P::new(VirtualProverConfig::default(
+            claim,
+            num_variables,
+            polynomial.clone(),
+        )),
+        &mut ark_std::test_rng(),
+    );
+    let time_prover_transcript = MultiProductSumcheck::<F>::prove::<S, TimeVirtualProver<F, S>>(
+        &mut TimeVirtualProver::new(VirtualProverConfig::default(
+            claim,
+            num_variables,
+            polynomial,
+        )),
+        &mut ark_std::test_rng(),
+    );
+
+    // ensure the transcript is identical
+    assert!(prover_transcript.is_accepted);
+    assert_eq!(prover_transcript, time_prover_transcript);
+}
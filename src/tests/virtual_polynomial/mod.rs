@@ -0,0 +1,5 @@
+mod consistency;
+mod sanity;
+
+pub use consistency::consistency_test;
+pub use sanity::sanity_test;
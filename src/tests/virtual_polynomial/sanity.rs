@@ -0,0 +1,64 @@
+use ark_ff::Field;
+
+use crate::{
+    hypercube::Hypercube,
+    order_strategy::SignificantBitOrder,
+    prover::{Prover, VirtualProverConfig},
+    streams::{MemoryStream, Stream},
+    tests::polynomials::four_variable_polynomial_evaluations,
+    virtual_polynomial::VirtualPolynomial,
+    MultiProductSumcheck,
+};
+
+pub fn sanity_test<F, S, P>()
+where
+    F: Field,
+    S: Stream<F> + From<MemoryStream<F>> + Clone,
+    P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<Vec<F>>>,
+    P::ProverConfig: VirtualProverConfig<F, S>,
+{
+    // g(p, q) = 2 * p^2 * q - p + q, where q is p with its evaluations reversed
+    let evaluations = four_variable_polynomial_evaluations();
+    let p: S = MemoryStream::new(evaluations.clone()).into();
+    let q: S = MemoryStream::new(evaluations.into_iter().rev().collect()).into();
+    let polynomial = VirtualPolynomial::new(
+        vec![p, q],
+        vec![
+            (F::from(2_u32), vec![0, 0, 1]),
+            (-F::ONE, vec![0]),
+            (F::ONE, vec![1]),
+        ],
+    );
+    let claim = polynomial.claim();
+
+    let transcript = MultiProductSumcheck::<F>::prove::<S, P>(
+        &mut P::new(VirtualProverConfig::default(claim, 4, polynomial.clone())),
+        &mut ark_std::test_rng(),
+    );
+    assert!(transcript.is_accepted);
+    assert!(transcript
+        .prover_messages
+        .iter()
+        .all(|message| message.len() == 4));
+
+    // every evaluation of the first round polynomial, g₀(s) = Σ_b g(p(s, b), q(s, b))
+    for (point, message) in transcript.prover_messages[0].iter().enumerate() {
+        let point = F::from(point as u64);
+        let mut sum = F::ZERO;
+        for (_, b) in Hypercube::<SignificantBitOrder>::new(3) {
+            let mut partial_point = vec![point];
+            partial_point.extend(b.map(|bit| match bit {
+                true => F::ONE,
+                false => F::ZERO,
+            }));
+            sum += polynomial.evaluation(&partial_point);
+        }
+        assert_eq!(*message, sum);
+    }
+
+    // the reduced claim should be g evaluated at the verifier's point
+    assert_eq!(
+        transcript.reduced_claim,
+        Some(polynomial.evaluation(&transcript.verifier_messages))
+    );
+}
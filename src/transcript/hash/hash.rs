@@ -0,0 +1,101 @@
+use ark_ff::Field;
+use ark_serialize::Compress;
+use ark_std::{marker::PhantomData, vec::Vec};
+use digest::Digest;
+
+use crate::transcript::Transcript;
+
+#[derive(Clone, Debug)]
+pub struct HashTranscript<F: Field, D: Digest> {
+    state: Vec<u8>,
+    _field: PhantomData<F>,
+    _digest: PhantomData<D>,
+}
+
+impl<F: Field, D: Digest> HashTranscript<F, D> {
+    pub fn new(domain_separator: &[u8]) -> Self {
+        Self {
+            state: D::digest(domain_separator).to_vec(),
+            _field: PhantomData,
+            _digest: PhantomData,
+        }
+    }
+    fn hash_with_counter(&self, counter: u64) -> Vec<u8> {
+        D::new()
+            .chain_update(&self.state)
+            .chain_update(counter.to_le_bytes())
+            .finalize()
+            .to_vec()
+    }
+}
+
+impl<F: Field, D: Digest> Transcript<F> for HashTranscript<F, D> {
+    fn absorb(&mut self, elements: &[F]) {
+        let mut hasher = D::new();
+        hasher.update(&self.state);
+        for element in elements {
+            let mut buffer = Vec::new();
+            element.serialize_uncompressed(&mut buffer).unwrap();
+            hasher.update(&buffer);
+        }
+        self.state = hasher.finalize().to_vec();
+    }
+
+    fn challenge(&mut self) -> F {
+        let size_of_serialized = F::ONE.serialized_size(Compress::No);
+        let mut counter: u64 = 0;
+        loop {
+            // stretch the state to as many bytes as a field element takes
+            let mut bytes: Vec<u8> = Vec::with_capacity(size_of_serialized);
+            while bytes.len() < size_of_serialized {
+                bytes.extend(self.hash_with_counter(counter));
+                counter += 1;
+            }
+
+            // reject bytes that don't land in the field, otherwise ratchet the state and return
+            if let Some(challenge) = F::from_random_bytes(&bytes[..size_of_serialized]) {
+                self.state = self.hash_with_counter(counter);
+                return challenge;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::Sha256;
+
+    use crate::{
+        tests::{F19, F64},
+        transcript::{HashTranscript, Transcript},
+    };
+
+    #[test]
+    fn deterministic() {
+        let mut t1 = HashTranscript::<F64, Sha256>::new(b"test");
+        let mut t2 = HashTranscript::<F64, Sha256>::new(b"test");
+        t1.absorb(&[F64::from(1), F64::from(2)]);
+        t2.absorb(&[F64::from(1), F64::from(2)]);
+        let c1 = t1.challenge();
+        assert_eq!(c1, t2.challenge());
+        // challenges shouldn't repeat
+        assert_ne!(c1, t1.challenge());
+
+        // a different message or domain gives a different challenge
+        let mut t3 = HashTranscript::<F64, Sha256>::new(b"test");
+        t3.absorb(&[F64::from(1), F64::from(3)]);
+        assert_ne!(c1, t3.challenge());
+        let mut t4 = HashTranscript::<F64, Sha256>::new(b"other");
+        t4.absorb(&[F64::from(1), F64::from(2)]);
+        assert_ne!(c1, t4.challenge());
+    }
+
+    #[test]
+    fn small_field() {
+        // most byte strings are rejected for F19, it should still terminate
+        let mut t = HashTranscript::<F19, Sha256>::new(b"test");
+        for _ in 0..100 {
+            t.challenge();
+        }
+    }
+}
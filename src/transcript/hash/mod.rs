@@ -0,0 +1,3 @@
+mod hash;
+
+pub use hash::HashTranscript;
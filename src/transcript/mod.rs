@@ -0,0 +1,9 @@
+mod hash;
+#[cfg(feature = "sponge")]
+mod sponge;
+mod transcript;
+
+pub use hash::HashTranscript;
+#[cfg(feature = "sponge")]
+pub use sponge::SpongeTranscript;
+pub use transcript::Transcript;
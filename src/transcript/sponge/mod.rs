@@ -0,0 +1,3 @@
+mod sponge;
+
+pub use sponge::SpongeTranscript;
@@ -0,0 +1,57 @@
+use ark_crypto_primitives::sponge::{Absorb, CryptographicSponge};
+use ark_ff::PrimeField;
+
+use crate::transcript::Transcript;
+
+/*
+ * Lets any arkworks sponge (e.g. Poseidon) drive the verifier messages.
+ */
+
+#[derive(Clone)]
+pub struct SpongeTranscript<S: CryptographicSponge> {
+    pub sponge: S,
+}
+
+impl<S: CryptographicSponge> SpongeTranscript<S> {
+    pub fn new(sponge: S) -> Self {
+        Self { sponge }
+    }
+}
+
+impl<F: PrimeField + Absorb, S: CryptographicSponge> Transcript<F> for SpongeTranscript<S> {
+    fn absorb(&mut self, elements: &[F]) {
+        self.sponge.absorb(&elements);
+    }
+
+    fn challenge(&mut self) -> F {
+        self.sponge.squeeze_field_elements::<F>(1)[0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_crypto_primitives::sponge::{
+        poseidon::{find_poseidon_ark_and_mds, PoseidonConfig, PoseidonSponge},
+        CryptographicSponge,
+    };
+    use ark_ff::PrimeField;
+
+    use crate::{
+        tests::F64,
+        transcript::{SpongeTranscript, Transcript},
+    };
+
+    #[test]
+    fn deterministic() {
+        let (ark, mds) =
+            find_poseidon_ark_and_mds::<F64>(F64::MODULUS_BIT_SIZE as u64, 2, 8, 31, 0);
+        let config = PoseidonConfig::new(8, 31, 7, mds, ark, 2, 1);
+        let mut t1 = SpongeTranscript::new(PoseidonSponge::<F64>::new(&config));
+        let mut t2 = SpongeTranscript::new(PoseidonSponge::<F64>::new(&config));
+        t1.absorb(&[F64::from(1), F64::from(2)]);
+        t2.absorb(&[F64::from(1), F64::from(2)]);
+        let c1: F64 = t1.challenge();
+        assert_eq!(c1, t2.challenge());
+        assert_ne!(c1, t1.challenge());
+    }
+}
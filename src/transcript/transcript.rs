@@ -0,0 +1,12 @@
+use ark_ff::Field;
+
+/*
+ * In the non-interactive mode the verifier messages are derived from everything
+ * sent so far, so anything that can absorb field elements and squeeze out a
+ * challenge can stand in for the verifier's randomness.
+ */
+
+pub trait Transcript<F: Field> {
+    fn absorb(&mut self, elements: &[F]);
+    fn challenge(&mut self) -> F;
+}
@@ -0,0 +1,5 @@
+mod sumcheck;
+mod verifier;
+
+pub use sumcheck::{univariate_skip_evaluation, univariate_skip_weights, UnivariateSkipSumcheck};
+pub use verifier::UnivariateSkipVerifier;
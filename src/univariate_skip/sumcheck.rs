@@ -0,0 +1,152 @@
+use ark_ff::FftField;
+use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+use ark_std::{cfg_into_iter, rand::Rng, vec::Vec};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{
+    prover::{Prover, ProverConfig},
+    streams::{for_each_chunk, multivariate_evaluation, Stream, UnivariateSkipStream},
+    transcript::Transcript,
+    Sumcheck,
+};
+
+/*
+ * The first k variables are folded into one round over the multiplicative subgroup H
+ * of size 2^k: the index j of the first k bits is the point w^j of H. The prover sends
+ * g(X) = sum_b f_b(X), where f_b interpolates f(j, b) over H, as its 2^k evaluations
+ * on H, so g has degree 2^k - 1 and sums to the claim over H. After the challenge r
+ * the remaining n - k rounds are a plain sumcheck over sum_j L_j(r) * f(j, b)
+ */
+#[derive(Debug)]
+pub struct UnivariateSkipSumcheck<F: FftField> {
+    pub skip_message: Vec<F>,
+    pub skip_verifier_message: F,
+    pub sumcheck: Sumcheck<F>,
+    pub is_accepted: bool,
+}
+
+// L_j(r) for every point w^j of the subgroup of size 2^num_skipped_variables
+pub fn univariate_skip_weights<F: FftField>(num_skipped_variables: usize, point: F) -> Vec<F> {
+    Radix2EvaluationDomain::<F>::new(1 << num_skipped_variables)
+        .unwrap()
+        .evaluate_all_lagrange_coefficients(point)
+}
+
+// the value the reduced claim should take, f(r, r_1, ..., r_{n-k}) with r on the skip domain
+pub fn univariate_skip_evaluation<F: FftField, S: Stream<F>>(
+    stream: S,
+    num_skipped_variables: usize,
+    skip_verifier_message: F,
+    point: &[F],
+) -> F {
+    let weights = univariate_skip_weights(num_skipped_variables, skip_verifier_message);
+    multivariate_evaluation(UnivariateSkipStream::new(stream, weights), point)
+}
+
+impl<F: FftField> UnivariateSkipSumcheck<F> {
+    // g(w^j) is the sum of f over the j-th block, which is one pass over the stream
+    fn skip_message<S: Stream<F>>(stream: &S, num_skipped_variables: usize) -> Vec<F> {
+        let num_remaining_variables = stream.num_variables() - num_skipped_variables;
+        cfg_into_iter!(0..1_usize << num_skipped_variables)
+            .map(|j| {
+                let mut sum = F::ZERO;
+                for_each_chunk(
+                    stream,
+                    j << num_remaining_variables,
+                    1 << num_remaining_variables,
+                    &mut Vec::new(),
+                    |_, evaluations| sum += evaluations.iter().sum::<F>(),
+                );
+                sum
+            })
+            .collect()
+    }
+
+    fn remaining_prover<S, P>(
+        stream: S,
+        num_skipped_variables: usize,
+        skip_message: &[F],
+        skip_verifier_message: F,
+    ) -> P
+    where
+        S: Stream<F>,
+        P: Prover<F>,
+        P::ProverConfig: ProverConfig<F, UnivariateSkipStream<F, S>>,
+    {
+        let weights = univariate_skip_weights(num_skipped_variables, skip_verifier_message);
+        // g(r) is the claim for the remaining rounds
+        let claim = weights
+            .iter()
+            .zip(skip_message)
+            .map(|(weight, evaluation)| *weight * evaluation)
+            .sum();
+        let stream = UnivariateSkipStream::new(stream, weights);
+        let num_variables = stream.num_variables();
+        P::new(ProverConfig::default(claim, num_variables, stream))
+    }
+
+    pub fn prove<S, P>(
+        claim: F,
+        stream: S,
+        num_skipped_variables: usize,
+        rng: &mut impl Rng,
+    ) -> Self
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F)>>,
+        P::ProverConfig: ProverConfig<F, UnivariateSkipStream<F, S>>,
+    {
+        let skip_message = Self::skip_message(&stream, num_skipped_variables);
+        let skip_verifier_message = F::rand(rng);
+        let mut prover = Self::remaining_prover::<S, P>(
+            stream,
+            num_skipped_variables,
+            &skip_message,
+            skip_verifier_message,
+        );
+        let sumcheck = Sumcheck::prove::<UnivariateSkipStream<F, S>, P>(&mut prover, rng);
+        Self {
+            // g sums to the claim over the domain
+            is_accepted: skip_message.iter().sum::<F>() == claim && sumcheck.is_accepted,
+            skip_message,
+            skip_verifier_message,
+            sumcheck,
+        }
+    }
+
+    pub fn prove_non_interactive<S, P, T>(
+        claim: F,
+        stream: S,
+        num_skipped_variables: usize,
+        transcript: &mut T,
+    ) -> Self
+    where
+        S: Stream<F>,
+        P: Prover<F, VerifierMessage = Option<F>, ProverMessage = Option<(F, F)>>,
+        P::ProverConfig: ProverConfig<F, UnivariateSkipStream<F, S>>,
+        T: Transcript<F>,
+    {
+        let skip_message = Self::skip_message(&stream, num_skipped_variables);
+        transcript.absorb(&[claim]);
+        transcript.absorb(&skip_message);
+        let skip_verifier_message = transcript.challenge();
+        let mut prover = Self::remaining_prover::<S, P>(
+            stream,
+            num_skipped_variables,
+            &skip_message,
+            skip_verifier_message,
+        );
+        let sumcheck = Sumcheck::prove_non_interactive::<UnivariateSkipStream<F, S>, P, T>(
+            &mut prover,
+            transcript,
+        );
+        Self {
+            // g sums to the claim over the domain
+            is_accepted: skip_message.iter().sum::<F>() == claim && sumcheck.is_accepted,
+            skip_message,
+            skip_verifier_message,
+            sumcheck,
+        }
+    }
+}
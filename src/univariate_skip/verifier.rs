@@ -0,0 +1,193 @@
+use ark_ff::FftField;
+use ark_std::vec::Vec;
+
+use crate::{
+    multilinear::{SumcheckVerifier, SumcheckVerifierConfig},
+    transcript::Transcript,
+    univariate_skip::univariate_skip_weights,
+    verifier::Verifier,
+};
+
+pub struct UnivariateSkipVerifier<F: FftField> {
+    pub claim: F,
+    pub num_skipped_variables: usize,
+    pub num_variables: usize,
+}
+
+impl<F: FftField> UnivariateSkipVerifier<F> {
+    pub fn new(claim: F, num_variables: usize, num_skipped_variables: usize) -> Self {
+        Self {
+            claim,
+            num_skipped_variables,
+            num_variables,
+        }
+    }
+
+    // checks the skip round and reduces the claim to g(r), which the remaining rounds start from
+    fn skip_round(&self, skip_message: &[F], skip_verifier_message: F) -> Option<F> {
+        if skip_message.len() != 1 << self.num_skipped_variables
+            || skip_message.iter().sum::<F>() != self.claim
+        {
+            return None;
+        }
+        Some(
+            univariate_skip_weights(self.num_skipped_variables, skip_verifier_message)
+                .iter()
+                .zip(skip_message)
+                .map(|(weight, evaluation)| *weight * evaluation)
+                .sum(),
+        )
+    }
+
+    // gives back the skip challenge, the point of the remaining rounds and the reduced claim
+    pub fn verify(
+        &self,
+        skip_message: &[F],
+        skip_verifier_message: F,
+        prover_messages: &[(F, F)],
+        verifier_messages: &[F],
+    ) -> Option<(F, Vec<F>, F)> {
+        let claim = self.skip_round(skip_message, skip_verifier_message)?;
+        let (point, reduced_claim) = SumcheckVerifier::new(SumcheckVerifierConfig::new(
+            claim,
+            self.num_variables - self.num_skipped_variables,
+        ))
+        .verify(prover_messages, verifier_messages)?;
+        Some((skip_verifier_message, point, reduced_claim))
+    }
+
+    pub fn verify_non_interactive<T: Transcript<F>>(
+        &self,
+        skip_message: &[F],
+        prover_messages: &[(F, F)],
+        transcript: &mut T,
+    ) -> Option<(F, Vec<F>, F)> {
+        transcript.absorb(&[self.claim]);
+        transcript.absorb(skip_message);
+        let skip_verifier_message = transcript.challenge();
+        let claim = self.skip_round(skip_message, skip_verifier_message)?;
+        let (point, reduced_claim) = SumcheckVerifier::new(SumcheckVerifierConfig::new(
+            claim,
+            self.num_variables - self.num_skipped_variables,
+        ))
+        .verify_non_interactive(prover_messages, transcript)?;
+        Some((skip_verifier_message, point, reduced_claim))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::UniformRand;
+    use sha2::Sha256;
+
+    use crate::{
+        multilinear::{BlendyProver, SpaceProver},
+        streams::{MemoryStream, UnivariateSkipStream},
+        tests::F64,
+        transcript::HashTranscript,
+        univariate_skip::{
+            univariate_skip_evaluation, UnivariateSkipSumcheck, UnivariateSkipVerifier,
+        },
+    };
+
+    const NUM_VARIABLES: usize = 10;
+    const NUM_SKIPPED_VARIABLES: usize = 3;
+
+    fn stream() -> (F64, MemoryStream<F64>) {
+        let mut rng = ark_std::test_rng();
+        let evaluations: Vec<F64> = (0..1 << NUM_VARIABLES)
+            .map(|_| F64::rand(&mut rng))
+            .collect();
+        (evaluations.iter().sum(), MemoryStream::new(evaluations))
+    }
+
+    #[test]
+    fn space_prover() {
+        let (claim, stream) = stream();
+        let transcript = UnivariateSkipSumcheck::<F64>::prove::<
+            MemoryStream<F64>,
+            SpaceProver<F64, UnivariateSkipStream<F64, MemoryStream<F64>>>,
+        >(
+            claim,
+            stream.clone(),
+            NUM_SKIPPED_VARIABLES,
+            &mut ark_std::test_rng(),
+        );
+        assert!(transcript.is_accepted);
+        // k variables are traded for one bigger message
+        assert_eq!(transcript.skip_message.len(), 1 << NUM_SKIPPED_VARIABLES);
+        assert_eq!(
+            transcript.sumcheck.prover_messages.len(),
+            NUM_VARIABLES - NUM_SKIPPED_VARIABLES
+        );
+
+        let (skip_verifier_message, point, reduced_claim) =
+            UnivariateSkipVerifier::new(claim, NUM_VARIABLES, NUM_SKIPPED_VARIABLES)
+                .verify(
+                    &transcript.skip_message,
+                    transcript.skip_verifier_message,
+                    &transcript.sumcheck.prover_messages,
+                    &transcript.sumcheck.verifier_messages,
+                )
+                .unwrap();
+        assert_eq!(
+            reduced_claim,
+            univariate_skip_evaluation(
+                stream,
+                NUM_SKIPPED_VARIABLES,
+                skip_verifier_message,
+                &point
+            )
+        );
+
+        // the wrong claim is caught in the skip round
+        assert!(UnivariateSkipVerifier::new(
+            claim + F64::from(1_u32),
+            NUM_VARIABLES,
+            NUM_SKIPPED_VARIABLES
+        )
+        .verify(
+            &transcript.skip_message,
+            transcript.skip_verifier_message,
+            &transcript.sumcheck.prover_messages,
+            &transcript.sumcheck.verifier_messages,
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn blendy_prover_non_interactive() {
+        let (claim, stream) = stream();
+        let transcript = UnivariateSkipSumcheck::<F64>::prove_non_interactive::<
+            MemoryStream<F64>,
+            BlendyProver<F64, UnivariateSkipStream<F64, MemoryStream<F64>>>,
+            HashTranscript<F64, Sha256>,
+        >(
+            claim,
+            stream.clone(),
+            NUM_SKIPPED_VARIABLES,
+            &mut HashTranscript::new(b"skip"),
+        );
+        assert!(transcript.is_accepted);
+
+        let (skip_verifier_message, point, reduced_claim) =
+            UnivariateSkipVerifier::new(claim, NUM_VARIABLES, NUM_SKIPPED_VARIABLES)
+                .verify_non_interactive(
+                    &transcript.skip_message,
+                    &transcript.sumcheck.prover_messages,
+                    &mut HashTranscript::<F64, Sha256>::new(b"skip"),
+                )
+                .unwrap();
+        assert_eq!(skip_verifier_message, transcript.skip_verifier_message);
+        assert_eq!(point, transcript.sumcheck.verifier_messages);
+        assert_eq!(
+            reduced_claim,
+            univariate_skip_evaluation(
+                stream,
+                NUM_SKIPPED_VARIABLES,
+                skip_verifier_message,
+                &point
+            )
+        );
+    }
+}
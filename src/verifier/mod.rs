@@ -0,0 +1,2 @@
+mod verifier;
+pub use verifier::{Verifier, VerifierConfig};
@@ -0,0 +1,128 @@
+use ark_ff::Field;
+
+use crate::{
+    messages::{CompressedProof, RoundPolynomial},
+    transcript::Transcript,
+};
+
+pub trait VerifierConfig<F: Field> {
+    fn default(claim: F, num_variables: usize) -> Self;
+}
+
+pub trait Verifier<F: Field> {
+    type VerifierConfig;
+    type ProverMessage;
+    fn claim(&self) -> F;
+    fn new(verifier_config: Self::VerifierConfig) -> Self;
+    // checks the round relation against the running claim, then reduces it using the verifier message
+    fn next_message(
+        &mut self,
+        prover_message: &Self::ProverMessage,
+        verifier_message: F,
+    ) -> Option<F>;
+    fn num_variables(&self) -> usize;
+    // the highest degree of the round polynomials it takes
+    fn degree(&self) -> usize;
+    // the message this verifier takes for a round polynomial, None if it's of a higher degree
+    fn prover_message(&self, round_polynomial: &RoundPolynomial<F>) -> Option<Self::ProverMessage>;
+    // how a prover message is fed to the transcript, the prover side must do the same
+    fn absorb_prover_message<T: Transcript<F>>(
+        transcript: &mut T,
+        prover_message: &Self::ProverMessage,
+    );
+    // checks a whole transcript and gives back the evaluation point and the reduced claim
+    fn verify(
+        &mut self,
+        prover_messages: &[Self::ProverMessage],
+        verifier_messages: &[F],
+    ) -> Option<(Vec<F>, F)> {
+        // there should be exactly one prover and one verifier message per variable
+        if prover_messages.len() != self.num_variables()
+            || verifier_messages.len() != self.num_variables()
+        {
+            return None;
+        }
+
+        // the reduced claim of the last round is the value f(r_1, ..., r_n) should take
+        let mut reduced_claim = self.claim();
+        for (prover_message, verifier_message) in prover_messages.iter().zip(verifier_messages) {
+            reduced_claim = self.next_message(prover_message, *verifier_message)?;
+        }
+
+        Some((verifier_messages.to_vec(), reduced_claim))
+    }
+    // checks a non-interactive transcript, deriving the verifier messages as the prover did
+    fn verify_non_interactive<T: Transcript<F>>(
+        &mut self,
+        prover_messages: &[Self::ProverMessage],
+        transcript: &mut T,
+    ) -> Option<(Vec<F>, F)> {
+        transcript.absorb(&[self.claim()]);
+        let verifier_messages: Vec<F> = prover_messages
+            .iter()
+            .map(|prover_message| {
+                Self::absorb_prover_message(transcript, prover_message);
+                transcript.challenge()
+            })
+            .collect();
+        self.verify(prover_messages, &verifier_messages)
+    }
+    /*
+     * Checks a compressed proof: each round polynomial is rebuilt at the proof's nodes
+     * from the running claim, then goes through next_message as the verifier's own
+     * message, which still checks its degree
+     */
+    fn verify_compressed(
+        &mut self,
+        proof: &CompressedProof<F>,
+        verifier_messages: &[F],
+    ) -> Option<(Vec<F>, F)> {
+        if proof.messages.len() != self.num_variables()
+            || verifier_messages.len() != self.num_variables()
+        {
+            return None;
+        }
+
+        let mut reduced_claim = self.claim();
+        for (compressed, verifier_message) in proof.messages.iter().zip(verifier_messages) {
+            // one evaluation per node but the dropped p(1), so the nodes fit the degree
+            if compressed.len() != self.degree() {
+                return None;
+            }
+            let round_polynomial =
+                RoundPolynomial::decompress(compressed, &proof.nodes, reduced_claim).ok()?;
+            let prover_message = self.prover_message(&round_polynomial)?;
+            reduced_claim = self.next_message(&prover_message, *verifier_message)?;
+        }
+
+        Some((verifier_messages.to_vec(), reduced_claim))
+    }
+    // the same for a non-interactive proof, the rebuilt messages are absorbed as the prover absorbed its own
+    fn verify_compressed_non_interactive<T: Transcript<F>>(
+        &mut self,
+        proof: &CompressedProof<F>,
+        transcript: &mut T,
+    ) -> Option<(Vec<F>, F)> {
+        if proof.messages.len() != self.num_variables() {
+            return None;
+        }
+
+        transcript.absorb(&[self.claim()]);
+        let mut reduced_claim = self.claim();
+        let mut verifier_messages = Vec::with_capacity(proof.messages.len());
+        for compressed in proof.messages.iter() {
+            if compressed.len() != self.degree() {
+                return None;
+            }
+            let round_polynomial =
+                RoundPolynomial::decompress(compressed, &proof.nodes, reduced_claim).ok()?;
+            let prover_message = self.prover_message(&round_polynomial)?;
+            Self::absorb_prover_message(transcript, &prover_message);
+            let verifier_message = transcript.challenge();
+            reduced_claim = self.next_message(&prover_message, verifier_message)?;
+            verifier_messages.push(verifier_message);
+        }
+
+        Some((verifier_messages, reduced_claim))
+    }
+}
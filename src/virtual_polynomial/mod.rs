@@ -0,0 +1,9 @@
+mod provers;
+mod virtual_polynomial;
+
+pub use provers::{
+    blendy::{BlendyVirtualProver, BlendyVirtualProverConfig},
+    space::{SpaceVirtualProver, SpaceVirtualProverConfig},
+    time::{TimeVirtualProver, TimeVirtualProverConfig},
+};
+pub use virtual_polynomial::VirtualPolynomial;
@@ -0,0 +1,48 @@
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::{
+    interpolation::LagrangePolynomial, multi_product::BlendyMultiProductProver,
+    order_strategy::GraycodeOrder, streams::Stream, virtual_polynomial::VirtualPolynomial,
+};
+
+/*
+ * g is a sum of products of the streams, so the round polynomial is the sum of
+ * the round polynomials of its terms, each scaled by its coefficient. One product
+ * prover computes all of them, so a stream shared by several terms is read once per pass
+ */
+pub struct BlendyVirtualProver<F: Field, S: Stream<F>> {
+    pub claim: F,
+    pub current_round: usize,
+    pub num_variables: usize,
+    pub polynomial: VirtualPolynomial<F, S>,
+    pub terms_prover: BlendyMultiProductProver<F, S>,
+}
+
+impl<F: Field, S: Stream<F>> BlendyVirtualProver<F, S> {
+    pub fn total_rounds(&self) -> usize {
+        self.num_variables
+    }
+    pub fn compute_round(&mut self, verifier_message: Option<F>) -> Vec<F> {
+        let degree = self.polynomial.degree();
+        let mut sums = vec![F::ZERO; degree + 1];
+        let terms_sums = self
+            .terms_prover
+            .next_term_messages(verifier_message)
+            .unwrap();
+        for ((coefficient, _), term_sums) in self.polynomial.terms.iter().zip(terms_sums) {
+            for (point, sum) in sums.iter_mut().enumerate() {
+                // lower degree terms are extended to the remaining points by interpolation
+                let term_sum = match term_sums.get(point) {
+                    Some(term_sum) => *term_sum,
+                    None => LagrangePolynomial::<F, GraycodeOrder>::evaluate_from_points(
+                        F::from(point as u64),
+                        &term_sums,
+                    ),
+                };
+                *sum += *coefficient * term_sum;
+            }
+        }
+        sums
+    }
+}
@@ -0,0 +1,74 @@
+use ark_ff::Field;
+
+use crate::{
+    error::SumcheckError, multilinear_product::BlendyProductProverConfig,
+    prover::VirtualProverConfig, streams::Stream, virtual_polynomial::VirtualPolynomial,
+};
+
+const DEFAULT_NUM_STAGES: usize = 2;
+
+pub struct BlendyVirtualProverConfig<F, S>
+where
+    F: Field,
+    S: Stream<F>,
+{
+    pub num_stages: usize,
+    pub num_variables: usize,
+    pub claim: F,
+    pub polynomial: VirtualPolynomial<F, S>,
+}
+
+impl<F, S> BlendyVirtualProverConfig<F, S>
+where
+    F: Field,
+    S: Stream<F>,
+{
+    pub fn new(
+        claim: F,
+        num_stages: usize,
+        num_variables: usize,
+        polynomial: VirtualPolynomial<F, S>,
+    ) -> Self {
+        Self {
+            claim,
+            num_stages,
+            num_variables,
+            polynomial,
+        }
+    }
+    pub fn try_new(
+        claim: F,
+        num_stages: usize,
+        num_variables: usize,
+        polynomial: VirtualPolynomial<F, S>,
+    ) -> Result<Self, SumcheckError> {
+        if polynomial.num_variables() != num_variables {
+            return Err(SumcheckError::NumVariablesMismatch {
+                expected: num_variables,
+                found: polynomial.num_variables(),
+            });
+        }
+        // the schedule needs at least one state computation
+        if num_stages == 0
+            || BlendyProductProverConfig::<F, S>::compute_state_comp_set(num_variables, num_stages)
+                .is_empty()
+        {
+            return Err(SumcheckError::InvalidNumStages {
+                num_stages,
+                num_variables,
+            });
+        }
+        Ok(Self::new(claim, num_stages, num_variables, polynomial))
+    }
+}
+
+impl<F: Field, S: Stream<F>> VirtualProverConfig<F, S> for BlendyVirtualProverConfig<F, S> {
+    fn default(claim: F, num_variables: usize, polynomial: VirtualPolynomial<F, S>) -> Self {
+        Self {
+            claim,
+            num_stages: DEFAULT_NUM_STAGES,
+            num_variables,
+            polynomial,
+        }
+    }
+}
@@ -0,0 +1,6 @@
+mod blendy;
+mod config;
+mod prover;
+
+pub use blendy::BlendyVirtualProver;
+pub use config::BlendyVirtualProverConfig;
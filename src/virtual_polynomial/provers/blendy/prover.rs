@@ -0,0 +1,120 @@
+use ark_ff::Field;
+
+use crate::{
+    multi_product::BlendyMultiProductProver,
+    prover::Prover,
+    streams::Stream,
+    virtual_polynomial::{BlendyVirtualProver, BlendyVirtualProverConfig},
+};
+
+impl<F: Field, S: Stream<F>> Prover<F> for BlendyVirtualProver<F, S> {
+    type ProverConfig = BlendyVirtualProverConfig<F, S>;
+    type ProverMessage = Option<Vec<F>>;
+    type VerifierMessage = Option<F>;
+
+    fn claim(&self) -> F {
+        self.claim
+    }
+
+    fn new(prover_config: Self::ProverConfig) -> Self {
+        let polynomial = prover_config.polynomial;
+        // the claims of the terms are never checked, only the combined one is
+        let terms_prover = BlendyMultiProductProver::new_with_terms(
+            F::ZERO,
+            prover_config.num_stages,
+            prover_config.num_variables,
+            polynomial.streams.clone(),
+            polynomial
+                .terms
+                .iter()
+                .map(|(_, factors)| factors.clone())
+                .collect(),
+        );
+
+        Self {
+            claim: prover_config.claim,
+            current_round: 0,
+            num_variables: prover_config.num_variables,
+            polynomial,
+            terms_prover,
+        }
+    }
+
+    fn next_message(&mut self, verifier_message: Option<F>) -> Option<Vec<F>> {
+        // Ensure the current round is within bounds
+        if self.current_round >= self.total_rounds() {
+            return None;
+        }
+
+        let sums = self.compute_round(verifier_message);
+
+        // Increment the round counter
+        self.current_round += 1;
+
+        // Return the computed polynomial
+        Some(sums)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{Field, UniformRand};
+
+    use crate::{
+        prover::Prover,
+        streams::MemoryStream,
+        tests::{virtual_polynomial::consistency_test, CountingStream, F64},
+        virtual_polynomial::{BlendyVirtualProver, BlendyVirtualProverConfig, VirtualPolynomial},
+        MultiProductSumcheck,
+    };
+
+    #[test]
+    fn parity_with_time_prover() {
+        consistency_test::<F64, MemoryStream<F64>, BlendyVirtualProver<F64, MemoryStream<F64>>>();
+    }
+
+    #[test]
+    fn shared_streams_read_once() {
+        let num_variables = 8;
+        let mut rng = ark_std::test_rng();
+        let streams: Vec<CountingStream<F64>> = (0..4)
+            .map(|_| {
+                CountingStream::new(MemoryStream::new(
+                    (0..1 << num_variables)
+                        .map(|_| F64::rand(&mut rng))
+                        .collect(),
+                ))
+            })
+            .collect();
+
+        // e * a * b - e * c, where e is in both terms
+        let polynomial = VirtualPolynomial::new(
+            streams.clone(),
+            vec![(F64::ONE, vec![0, 1, 2]), (-F64::ONE, vec![0, 3])],
+        );
+        let claim = polynomial.claim();
+        let reads_before: Vec<usize> = streams.iter().map(|stream| stream.reads()).collect();
+        let transcript = MultiProductSumcheck::<F64>::prove::<
+            CountingStream<F64>,
+            BlendyVirtualProver<F64, CountingStream<F64>>,
+        >(
+            &mut BlendyVirtualProver::new(BlendyVirtualProverConfig::new(
+                claim,
+                2,
+                num_variables,
+                polynomial,
+            )),
+            &mut ark_std::test_rng(),
+        );
+        assert!(transcript.is_accepted);
+
+        // every pass reads e as many times as the streams that are in one term
+        let reads: Vec<usize> = streams
+            .iter()
+            .zip(reads_before)
+            .map(|(stream, before)| stream.reads() - before)
+            .collect();
+        assert!(reads[0] > 0);
+        assert!(reads.iter().all(|stream_reads| *stream_reads == reads[0]));
+    }
+}
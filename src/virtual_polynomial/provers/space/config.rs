@@ -0,0 +1,53 @@
+use ark_ff::Field;
+
+use crate::{
+    error::SumcheckError, prover::VirtualProverConfig, streams::Stream,
+    virtual_polynomial::VirtualPolynomial,
+};
+
+pub struct SpaceVirtualProverConfig<F, S>
+where
+    F: Field,
+    S: Stream<F>,
+{
+    pub num_variables: usize,
+    pub claim: F,
+    pub polynomial: VirtualPolynomial<F, S>,
+}
+
+impl<F, S> SpaceVirtualProverConfig<F, S>
+where
+    F: Field,
+    S: Stream<F>,
+{
+    pub fn new(claim: F, num_variables: usize, polynomial: VirtualPolynomial<F, S>) -> Self {
+        Self {
+            claim,
+            num_variables,
+            polynomial,
+        }
+    }
+    pub fn try_new(
+        claim: F,
+        num_variables: usize,
+        polynomial: VirtualPolynomial<F, S>,
+    ) -> Result<Self, SumcheckError> {
+        if polynomial.num_variables() != num_variables {
+            return Err(SumcheckError::NumVariablesMismatch {
+                expected: num_variables,
+                found: polynomial.num_variables(),
+            });
+        }
+        Ok(Self::new(claim, num_variables, polynomial))
+    }
+}
+
+impl<F: Field, S: Stream<F>> VirtualProverConfig<F, S> for SpaceVirtualProverConfig<F, S> {
+    fn default(claim: F, num_variables: usize, polynomial: VirtualPolynomial<F, S>) -> Self {
+        Self {
+            claim,
+            num_variables,
+            polynomial,
+        }
+    }
+}
@@ -0,0 +1,6 @@
+mod config;
+mod prover;
+mod space;
+
+pub use config::SpaceVirtualProverConfig;
+pub use space::SpaceVirtualProver;
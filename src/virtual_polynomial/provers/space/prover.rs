@@ -0,0 +1,63 @@
+use ark_ff::Field;
+
+use crate::{
+    messages::VerifierMessages,
+    prover::Prover,
+    streams::Stream,
+    virtual_polynomial::{SpaceVirtualProver, SpaceVirtualProverConfig},
+};
+
+impl<F: Field, S: Stream<F>> Prover<F> for SpaceVirtualProver<F, S> {
+    type ProverConfig = SpaceVirtualProverConfig<F, S>;
+    type ProverMessage = Option<Vec<F>>;
+    type VerifierMessage = Option<F>;
+
+    fn claim(&self) -> F {
+        self.claim
+    }
+
+    fn new(prover_config: Self::ProverConfig) -> Self {
+        Self {
+            claim: prover_config.claim,
+            current_round: 0,
+            polynomial: prover_config.polynomial,
+            num_variables: prover_config.num_variables,
+            verifier_messages: VerifierMessages::new(&vec![]),
+        }
+    }
+
+    fn next_message(&mut self, verifier_message: Option<F>) -> Option<Vec<F>> {
+        // Ensure the current round is within bounds
+        if self.current_round >= self.total_rounds() {
+            return None;
+        }
+
+        // If it's not the first round, add the verifier message to verifier_messages
+        if self.current_round != 0 {
+            self.verifier_messages
+                .receive_message(verifier_message.unwrap());
+        }
+
+        // evaluate using cty
+        let sums = self.cty_evaluate();
+
+        // don't forget to increment the round
+        self.current_round += 1;
+
+        Some(sums)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        streams::MemoryStream,
+        tests::{virtual_polynomial::sanity_test, F19},
+        virtual_polynomial::SpaceVirtualProver,
+    };
+
+    #[test]
+    fn sumcheck() {
+        sanity_test::<F19, MemoryStream<F19>, SpaceVirtualProver<F19, MemoryStream<F19>>>();
+    }
+}
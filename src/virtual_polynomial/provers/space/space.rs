@@ -0,0 +1,55 @@
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::{
+    interpolation::LagrangePolynomial,
+    messages::VerifierMessages,
+    order_strategy::SignificantBitOrder,
+    parallel::add_sums,
+    streams::{fold_partial_sums, Stream},
+    virtual_polynomial::VirtualPolynomial,
+};
+
+pub struct SpaceVirtualProver<F: Field, S: Stream<F>> {
+    pub claim: F,
+    pub current_round: usize,
+    pub polynomial: VirtualPolynomial<F, S>,
+    pub num_variables: usize,
+    pub verifier_messages: VerifierMessages<F>,
+}
+
+impl<F: Field, S: Stream<F>> SpaceVirtualProver<F, S> {
+    pub fn total_rounds(&self) -> usize {
+        self.num_variables
+    }
+    /*
+     * The same walk as SpaceMultiProductProver, but the partial sums are kept per
+     * stream rather than per factor, so a stream shared by many terms is read once
+     */
+    pub fn cty_evaluate(&self) -> Vec<F> {
+        let degree = self.polynomial.degree();
+        fold_partial_sums(
+            &self.polynomial.streams,
+            self.current_round,
+            || LagrangePolynomial::<F, SignificantBitOrder>::new(&self.verifier_messages),
+            || vec![F::ZERO; degree + 1],
+            |mut sums, partial_sums_0, partial_sums_1| {
+                // walk each stream along the line through its partial sums at 0 and 1, and combine them with g at each point
+                let mut values = partial_sums_0.to_vec();
+                let steps: Vec<F> = partial_sums_1
+                    .iter()
+                    .zip(&values)
+                    .map(|(one, zero)| *one - zero)
+                    .collect();
+                for sum in sums.iter_mut() {
+                    *sum += self.polynomial.combine(&values);
+                    for (value, step) in values.iter_mut().zip(&steps) {
+                        *value += step;
+                    }
+                }
+                sums
+            },
+            add_sums,
+        )
+    }
+}
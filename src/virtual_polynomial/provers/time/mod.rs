@@ -0,0 +1,6 @@
+mod config;
+mod prover;
+mod time;
+
+pub use config::TimeVirtualProverConfig;
+pub use time::TimeVirtualProver;
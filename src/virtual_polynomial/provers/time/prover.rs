@@ -0,0 +1,66 @@
+use ark_ff::Field;
+
+use crate::{
+    prover::Prover,
+    streams::Stream,
+    virtual_polynomial::{TimeVirtualProver, TimeVirtualProverConfig},
+};
+
+impl<F: Field, S: Stream<F>> Prover<F> for TimeVirtualProver<F, S> {
+    type ProverConfig = TimeVirtualProverConfig<F, S>;
+    type ProverMessage = Option<Vec<F>>;
+    type VerifierMessage = Option<F>;
+
+    fn claim(&self) -> F {
+        self.claim
+    }
+
+    fn new(prover_config: Self::ProverConfig) -> Self {
+        Self {
+            claim: prover_config.claim,
+            current_round: 0,
+            evaluations: vec![None; prover_config.polynomial.streams.len()],
+            polynomial: prover_config.polynomial,
+            num_variables: prover_config.num_variables,
+        }
+    }
+
+    fn next_message(&mut self, verifier_message: Option<F>) -> Option<Vec<F>> {
+        // Ensure the current round is within bounds
+        if self.current_round >= self.total_rounds() {
+            return None;
+        }
+
+        // If it's not the first round, reduce the evaluations table
+        if self.current_round != 0 {
+            // update the evaluations table by absorbing leftmost variable assigned to verifier_message
+            self.vsbw_reduce_evaluations(
+                verifier_message.unwrap(),
+                F::ONE - verifier_message.unwrap(),
+            );
+        }
+
+        // evaluate using vsbw
+        let sums = self.vsbw_evaluate();
+
+        // Increment the round counter
+        self.current_round += 1;
+
+        // Return the computed polynomial
+        Some(sums)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        streams::MemoryStream,
+        tests::{virtual_polynomial::sanity_test, F19},
+        virtual_polynomial::TimeVirtualProver,
+    };
+
+    #[test]
+    fn sumcheck() {
+        sanity_test::<F19, MemoryStream<F19>, TimeVirtualProver<F19, MemoryStream<F19>>>();
+    }
+}
@@ -0,0 +1,120 @@
+use ark_ff::Field;
+use ark_std::{cfg_iter, vec::Vec};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{
+    parallel::{add_sums, fold_range},
+    streams::{reduce_stream_evaluations, MaybeSync, Stream, EVALUATIONS_CHUNK_SIZE},
+    virtual_polynomial::VirtualPolynomial,
+};
+
+pub struct TimeVirtualProver<F: Field, S: Stream<F>> {
+    pub claim: F,
+    pub current_round: usize,
+    pub evaluations: Vec<Option<Vec<F>>>,
+    pub polynomial: VirtualPolynomial<F, S>,
+    pub num_variables: usize,
+}
+
+impl<F: Field, S: Stream<F>> TimeVirtualProver<F, S> {
+    pub fn total_rounds(&self) -> usize {
+        self.num_variables
+    }
+    pub fn num_free_variables(&self) -> usize {
+        self.num_variables - self.current_round
+    }
+    fn point_evaluations(&self, stream: usize, start: usize, evaluations: &mut [F]) {
+        match &self.evaluations[stream] {
+            None => self.polynomial.streams[stream].evaluations_into(start, evaluations),
+            Some(table) => evaluations.copy_from_slice(&table[start..start + evaluations.len()]),
+        }
+    }
+    /*
+     * Each stream restricted to the current variable is a line, so we walk every stream
+     * along it to get its values at 0, 1, ..., d and combine them with g at each point
+     */
+    pub fn vsbw_evaluate(&self) -> Vec<F> {
+        self.vsbw_evaluate_weighted(|_| F::ONE)
+    }
+    // same as above, but each term of the sum over the remaining variables is scaled by its weight
+    pub fn vsbw_evaluate_weighted(&self, weight: impl Fn(usize) -> F + MaybeSync) -> Vec<F> {
+        let degree = self.polynomial.degree();
+        let num_streams = self.polynomial.streams.len();
+
+        // Calculate the bitmask for the number of free variables
+        let bitmask: usize = 1 << (self.num_free_variables() - 1);
+        let chunk_len = bitmask.min(EVALUATIONS_CHUNK_SIZE);
+
+        // accumulates the sums alongside values[s][k], which holds the k-th stream evaluated at s,
+        // and both halves of a chunk of every stream, which are read a chunk at a time
+        let (sums, ..) = fold_range(
+            bitmask / chunk_len,
+            || {
+                (
+                    vec![F::ZERO; degree + 1],
+                    vec![vec![F::ZERO; num_streams]; degree + 1],
+                    vec![F::ZERO; num_streams * chunk_len],
+                    vec![F::ZERO; num_streams * chunk_len],
+                )
+            },
+            |(mut sums, mut values, mut zeros, mut ones), chunk| {
+                let start = chunk * chunk_len;
+                for (stream, (zeros, ones)) in zeros
+                    .chunks_exact_mut(chunk_len)
+                    .zip(ones.chunks_exact_mut(chunk_len))
+                    .enumerate()
+                {
+                    self.point_evaluations(stream, start, zeros);
+                    self.point_evaluations(stream, start | bitmask, ones);
+                }
+                for offset in 0..chunk_len {
+                    for stream in 0..num_streams {
+                        let zero = zeros[stream * chunk_len + offset];
+                        let step = ones[stream * chunk_len + offset] - zero;
+                        let mut value = zero;
+                        for point_values in values.iter_mut() {
+                            point_values[stream] = value;
+                            value += step;
+                        }
+                    }
+                    let weight = weight(start + offset);
+                    for (sum, point_values) in sums.iter_mut().zip(values.iter()) {
+                        *sum += weight * self.polynomial.combine(point_values);
+                    }
+                }
+                (sums, values, zeros, ones)
+            },
+            |(sums, values, zeros, ones), (other_sums, ..)| {
+                (add_sums(sums, other_sums), values, zeros, ones)
+            },
+        );
+
+        sums
+    }
+    pub fn vsbw_reduce_evaluations(&mut self, verifier_message: F, verifier_message_hat: F) {
+        // Calculate what bit needs to be set to index the second half of the last round's evaluations
+        let setbit: usize = 1 << self.num_free_variables();
+
+        for stream in 0..self.polynomial.streams.len() {
+            // Iterate through pairs of evaluations, the first fold reads the stream a chunk at a time
+            let evaluations: Vec<F> = match &self.evaluations[stream] {
+                None => reduce_stream_evaluations(
+                    &self.polynomial.streams[stream],
+                    verifier_message,
+                    verifier_message_hat,
+                ),
+                Some(evaluations) => {
+                    let (zeros, ones) = evaluations.split_at(setbit);
+                    cfg_iter!(zeros)
+                        .zip(cfg_iter!(ones))
+                        .map(|(zero, one)| *zero * verifier_message_hat + *one * verifier_message)
+                        .collect()
+                }
+            };
+
+            // Update the internal state with the new evaluations vector
+            self.evaluations[stream] = Some(evaluations);
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::{
+    error::SumcheckError,
+    interpolation::check_degree,
+    streams::{check_streams, multivariate_evaluation, Stream},
+};
+
+/*
+ * Describes g(f_1(b), ..., f_k(b)) for streams f_1, ..., f_k, where g is given
+ * as a sum of terms c * f_i * f_j * ..., e.g. eq * a * b - eq * c is
+ *
+ *   streams: [eq, a, b, c]
+ *   terms: [(1, [0, 1, 2]), (-1, [0, 3])]
+ *
+ * a stream may appear more than once in a term, so any polynomial g can be expressed
+ */
+#[derive(Clone, Debug)]
+pub struct VirtualPolynomial<F: Field, S: Stream<F>> {
+    pub streams: Vec<S>,
+    pub terms: Vec<(F, Vec<usize>)>,
+}
+
+impl<F: Field, S: Stream<F>> VirtualPolynomial<F, S> {
+    pub fn new(streams: Vec<S>, terms: Vec<(F, Vec<usize>)>) -> Self {
+        Self::try_new(streams, terms).unwrap()
+    }
+    pub fn try_new(streams: Vec<S>, terms: Vec<(F, Vec<usize>)>) -> Result<Self, SumcheckError> {
+        // all streams should have the same number of variables as the first
+        let num_variables = streams
+            .first()
+            .ok_or(SumcheckError::NoStreams)?
+            .num_variables();
+        check_streams(&streams, num_variables)?;
+
+        // should be given at least one term, and every term should be a product of at least one of the streams
+        if terms.is_empty() {
+            return Err(SumcheckError::InvalidTerm(0));
+        }
+        for (term, (_, factors)) in terms.iter().enumerate() {
+            if factors.is_empty() || factors.iter().any(|factor| *factor >= streams.len()) {
+                return Err(SumcheckError::InvalidTerm(term));
+            }
+        }
+        // the round polynomials are of the degree of the largest term
+        let degree = terms
+            .iter()
+            .map(|(_, factors)| factors.len())
+            .max()
+            .unwrap();
+        check_degree::<F>(degree)?;
+
+        Ok(Self { streams, terms })
+    }
+    pub fn num_variables(&self) -> usize {
+        self.streams[0].num_variables()
+    }
+    // the degree of the round polynomials is the degree of g
+    pub fn degree(&self) -> usize {
+        self.terms
+            .iter()
+            .map(|(_, factors)| factors.len())
+            .max()
+            .unwrap()
+    }
+    // g evaluated on one value per stream
+    pub fn combine(&self, values: &[F]) -> F {
+        self.terms
+            .iter()
+            .map(|(coefficient, factors)| {
+                factors
+                    .iter()
+                    .fold(*coefficient, |product, factor| product * values[*factor])
+            })
+            .sum()
+    }
+    pub fn claim(&self) -> F {
+        // sum over the hypercube of g(f_1(b), ..., f_k(b))
+        let mut values = vec![F::ZERO; self.streams.len()];
+        let mut claim = F::ZERO;
+        for i in 0..1 << self.num_variables() {
+            for (value, stream) in values.iter_mut().zip(self.streams.iter()) {
+                *value = stream.evaluation(i);
+            }
+            claim += self.combine(&values);
+        }
+        claim
+    }
+    pub fn evaluation(&self, point: &[F]) -> F {
+        // g(f_1(r), ..., f_k(r)), which is what the sumcheck reduces the claim to
+        let values: Vec<F> = self
+            .streams
+            .iter()
+            .map(|stream| multivariate_evaluation(stream.clone(), point))
+            .collect();
+        self.combine(&values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{AdditiveGroup, Field, UniformRand};
+
+    use crate::{
+        multi_product::{MultiProductSumcheckVerifier, MultiProductSumcheckVerifierConfig},
+        prover::{Prover, VirtualProverConfig},
+        streams::{multivariate_product_claim, MemoryStream},
+        tests::{polynomials::four_variable_polynomial_evaluations, F19, F64},
+        verifier::Verifier,
+        virtual_polynomial::{TimeVirtualProver, VirtualPolynomial},
+        MultiProductSumcheck,
+    };
+
+    #[test]
+    fn claim() {
+        let s: MemoryStream<F19> = MemoryStream::new(four_variable_polynomial_evaluations());
+        // a single product term is the same as the product claim
+        let polynomial = VirtualPolynomial::new(vec![s.clone()], vec![(F19::from(1), vec![0, 0])]);
+        assert_eq!(polynomial.degree(), 2);
+        assert_eq!(
+            polynomial.claim(),
+            multivariate_product_claim(vec![s.clone(), s.clone()])
+        );
+        // and the claim is linear in the terms
+        let polynomial = VirtualPolynomial::new(
+            vec![s.clone()],
+            vec![(F19::from(3), vec![0, 0]), (-F19::from(1), vec![0, 0])],
+        );
+        assert_eq!(
+            polynomial.claim(),
+            F19::from(2) * multivariate_product_claim(vec![s.clone(), s])
+        );
+    }
+
+    #[test]
+    fn spartan() {
+        // a * b = c everywhere, as for a satisfied R1CS instance
+        let num_variables = 6;
+        let mut rng = ark_std::test_rng();
+        let a: Vec<F64> = (0..1 << num_variables)
+            .map(|_| F64::rand(&mut rng))
+            .collect();
+        let b: Vec<F64> = (0..1 << num_variables)
+            .map(|_| F64::rand(&mut rng))
+            .collect();
+        let c: Vec<F64> = a.iter().zip(b.iter()).map(|(a, b)| *a * *b).collect();
+
+        // eq(r, x) for a random r, the first variable is the most significant bit of x
+        let r: Vec<F64> = (0..num_variables).map(|_| F64::rand(&mut rng)).collect();
+        let eq: Vec<F64> = (0..1usize << num_variables)
+            .map(|x| {
+                r.iter().enumerate().fold(F64::ONE, |product, (i, r_i)| {
+                    match (x >> (num_variables - 1 - i)) & 1 {
+                        1 => product * r_i,
+                        _ => product * (F64::ONE - r_i),
+                    }
+                })
+            })
+            .collect();
+
+        // Σ_x eq(r, x) * (a(x) * b(x) - c(x)) = 0
+        let polynomial = VirtualPolynomial::new(
+            vec![
+                MemoryStream::new(eq),
+                MemoryStream::new(a),
+                MemoryStream::new(b),
+                MemoryStream::new(c),
+            ],
+            vec![(F64::ONE, vec![0, 1, 2]), (-F64::ONE, vec![0, 3])],
+        );
+        assert_eq!(polynomial.claim(), F64::ZERO);
+        let transcript = MultiProductSumcheck::<F64>::prove::<
+            MemoryStream<F64>,
+            TimeVirtualProver<F64, MemoryStream<F64>>,
+        >(
+            &mut TimeVirtualProver::new(VirtualProverConfig::default(
+                F64::ZERO,
+                num_variables,
+                polynomial.clone(),
+            )),
+            &mut rng,
+        );
+        assert!(transcript.is_accepted);
+        assert_eq!(
+            transcript.reduced_claim,
+            Some(polynomial.evaluation(&transcript.verifier_messages))
+        );
+        assert!(
+            MultiProductSumcheckVerifier::new(MultiProductSumcheckVerifierConfig::new(
+                F64::ZERO,
+                polynomial.degree(),
+                num_variables
+            ))
+            .verify(&transcript.prover_messages, &transcript.verifier_messages)
+            .is_some()
+        );
+    }
+}
@@ -0,0 +1,3 @@
+mod provers;
+
+pub use provers::time::{TimeZeroCheckProver, TimeZeroCheckProverConfig};
@@ -0,0 +1,52 @@
+use ark_ff::Field;
+
+use crate::{
+    error::SumcheckError, interpolation::check_degree, streams::Stream,
+    virtual_polynomial::VirtualPolynomial,
+};
+
+pub struct TimeZeroCheckProverConfig<F, S>
+where
+    F: Field,
+    S: Stream<F>,
+{
+    pub num_variables: usize,
+    pub point: Vec<F>,
+    pub polynomial: VirtualPolynomial<F, S>,
+}
+
+impl<F, S> TimeZeroCheckProverConfig<F, S>
+where
+    F: Field,
+    S: Stream<F>,
+{
+    pub fn new(num_variables: usize, point: Vec<F>, polynomial: VirtualPolynomial<F, S>) -> Self {
+        Self {
+            num_variables,
+            point,
+            polynomial,
+        }
+    }
+    pub fn try_new(
+        num_variables: usize,
+        point: Vec<F>,
+        polynomial: VirtualPolynomial<F, S>,
+    ) -> Result<Self, SumcheckError> {
+        // the point fixes every variable
+        if point.len() != num_variables {
+            return Err(SumcheckError::NumVariablesMismatch {
+                expected: num_variables,
+                found: point.len(),
+            });
+        }
+        if polynomial.num_variables() != num_variables {
+            return Err(SumcheckError::NumVariablesMismatch {
+                expected: num_variables,
+                found: polynomial.num_variables(),
+            });
+        }
+        // the eq factor raises the degree of the round polynomials by one
+        check_degree::<F>(polynomial.degree() + 1)?;
+        Ok(Self::new(num_variables, point, polynomial))
+    }
+}
@@ -0,0 +1,183 @@
+use ark_ff::Field;
+
+use crate::{
+    prover::Prover,
+    streams::Stream,
+    virtual_polynomial::{TimeVirtualProver, TimeVirtualProverConfig},
+    zerocheck::{TimeZeroCheckProver, TimeZeroCheckProverConfig},
+};
+
+impl<F: Field, S: Stream<F>> Prover<F> for TimeZeroCheckProver<F, S> {
+    type ProverConfig = TimeZeroCheckProverConfig<F, S>;
+    type ProverMessage = Option<Vec<F>>;
+    type VerifierMessage = Option<F>;
+
+    fn claim(&self) -> F {
+        F::ZERO
+    }
+
+    fn new(prover_config: Self::ProverConfig) -> Self {
+        // the point should fix every variable
+        assert_eq!(prover_config.point.len(), prover_config.num_variables);
+        Self {
+            current_round: 0,
+            eq_prefix: F::ONE,
+            num_variables: prover_config.num_variables,
+            point: prover_config.point,
+            // f is sumchecked without eq, so its claim is never checked
+            virtual_prover: TimeVirtualProver::new(TimeVirtualProverConfig::new(
+                F::ZERO,
+                prover_config.num_variables,
+                prover_config.polynomial,
+            )),
+        }
+    }
+
+    fn next_message(&mut self, verifier_message: Option<F>) -> Option<Vec<F>> {
+        // Ensure the current round is within bounds
+        if self.current_round >= self.total_rounds() {
+            return None;
+        }
+
+        // If it's not the first round, absorb the verifier message into eq and the tables
+        if self.current_round != 0 {
+            let verifier_message = verifier_message.unwrap();
+            let r_prev = self.point[self.current_round - 1];
+            self.eq_prefix *=
+                r_prev * verifier_message + (F::ONE - r_prev) * (F::ONE - verifier_message);
+            self.virtual_prover
+                .vsbw_reduce_evaluations(verifier_message, F::ONE - verifier_message);
+        }
+
+        let sums = self.compute_round();
+
+        // Increment the round counter
+        self.current_round += 1;
+        self.virtual_prover.current_round += 1;
+
+        // Return the computed polynomial
+        Some(sums)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{AdditiveGroup, Field, UniformRand};
+
+    use crate::{
+        multi_product::{MultiProductSumcheckVerifier, MultiProductSumcheckVerifierConfig},
+        prover::Prover,
+        streams::{multivariate_evaluation, EqStream, MemoryStream, Stream},
+        tests::F64,
+        verifier::Verifier,
+        virtual_polynomial::{TimeVirtualProver, TimeVirtualProverConfig, VirtualPolynomial},
+        zerocheck::{TimeZeroCheckProver, TimeZeroCheckProverConfig},
+        MultiProductSumcheck,
+    };
+
+    #[test]
+    fn zerocheck() {
+        // a * b = c everywhere, as for a satisfied R1CS instance
+        let num_variables = 6;
+        let mut rng = ark_std::test_rng();
+        let a: Vec<F64> = (0..1 << num_variables)
+            .map(|_| F64::rand(&mut rng))
+            .collect();
+        let b: Vec<F64> = (0..1 << num_variables)
+            .map(|_| F64::rand(&mut rng))
+            .collect();
+        let c: Vec<F64> = a.iter().zip(b.iter()).map(|(a, b)| *a * *b).collect();
+        let r: Vec<F64> = (0..num_variables).map(|_| F64::rand(&mut rng)).collect();
+        let a = MemoryStream::new(a);
+        let b = MemoryStream::new(b);
+        let c = MemoryStream::new(c);
+
+        // f = a * b - c, eq is left out
+        let f = VirtualPolynomial::new(
+            vec![a.clone(), b.clone(), c.clone()],
+            vec![(F64::ONE, vec![0, 1]), (-F64::ONE, vec![2])],
+        );
+        let transcript = MultiProductSumcheck::<F64>::prove::<
+            MemoryStream<F64>,
+            TimeZeroCheckProver<F64, MemoryStream<F64>>,
+        >(
+            &mut TimeZeroCheckProver::new(TimeZeroCheckProverConfig::new(
+                num_variables,
+                r.clone(),
+                f.clone(),
+            )),
+            &mut ark_std::test_rng(),
+        );
+        assert!(transcript.is_accepted);
+        // the reduced claim is eq(r, ρ) * f(ρ)
+        assert_eq!(
+            transcript.reduced_claim,
+            Some(
+                multivariate_evaluation(EqStream::new(r.clone()), &transcript.verifier_messages)
+                    * f.evaluation(&transcript.verifier_messages)
+            )
+        );
+        // and the round polynomials have one more degree than f
+        assert!(
+            MultiProductSumcheckVerifier::new(MultiProductSumcheckVerifierConfig::new(
+                F64::ZERO,
+                3,
+                num_variables
+            ))
+            .verify(&transcript.prover_messages, &transcript.verifier_messages)
+            .is_some()
+        );
+
+        // the messages are the same as treating eq as just another stream
+        let eq = EqStream::new(r.clone());
+        let eq_f = VirtualPolynomial::new(
+            vec![
+                MemoryStream::new((0..1 << num_variables).map(|i| eq.evaluation(i)).collect()),
+                a,
+                b,
+                c,
+            ],
+            vec![(F64::ONE, vec![0, 1, 2]), (-F64::ONE, vec![0, 3])],
+        );
+        let virtual_transcript = MultiProductSumcheck::<F64>::prove::<
+            MemoryStream<F64>,
+            TimeVirtualProver<F64, MemoryStream<F64>>,
+        >(
+            &mut TimeVirtualProver::new(TimeVirtualProverConfig::new(
+                F64::ZERO,
+                num_variables,
+                eq_f,
+            )),
+            &mut ark_std::test_rng(),
+        );
+        assert_eq!(transcript, virtual_transcript);
+    }
+
+    #[test]
+    fn eq_suffix_tables() {
+        let num_variables = 7;
+        let mut rng = ark_std::test_rng();
+        let r: Vec<F64> = (0..num_variables).map(|_| F64::rand(&mut rng)).collect();
+        let f = VirtualPolynomial::new(
+            vec![MemoryStream::new(vec![F64::ZERO; 1 << num_variables])],
+            vec![(F64::ONE, vec![0])],
+        );
+        let mut prover =
+            TimeZeroCheckProver::new(TimeZeroCheckProverConfig::new(num_variables, r.clone(), f));
+        for round in 0..num_variables {
+            prover.current_round = round;
+            let (eq_hi, eq_lo) = prover.eq_suffix_tables();
+            // about the square root of the table over all the remaining variables
+            let suffix_len = num_variables - round - 1;
+            assert_eq!(eq_hi.len(), 1 << (suffix_len / 2));
+            assert_eq!(eq_lo.len(), 1 << (suffix_len - suffix_len / 2));
+            let eq = EqStream::new(r[round + 1..].to_vec());
+            for index in 0..1 << suffix_len {
+                assert_eq!(
+                    eq_hi[index / eq_lo.len()] * eq_lo[index % eq_lo.len()],
+                    eq.evaluation(index)
+                );
+            }
+        }
+    }
+}
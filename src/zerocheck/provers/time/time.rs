@@ -0,0 +1,79 @@
+use ark_ff::Field;
+use ark_std::vec::Vec;
+
+use crate::{
+    interpolation::LagrangePolynomial, order_strategy::GraycodeOrder, streams::Stream,
+    virtual_polynomial::TimeVirtualProver,
+};
+
+/*
+ * Proves Σ_x eq(r, x) * f(x) = 0 without treating eq as another factor. Following Gruen,
+ * the round polynomial factors as
+ *
+ *   s_i(X) = eq(r_1..r_{i-1}, ρ_1..ρ_{i-1}) * eq(r_i, X) * t_i(X)
+ *   t_i(X) = Σ_x' eq(r_{i+1}..r_n, x') * f(ρ_1..ρ_{i-1}, X, x')
+ *
+ * so only t_i is computed from the tables, it has the degree of f rather than one more,
+ * and the eq factors are scalars or eq over the remaining variables only. That one is
+ * split in two tables over half of them each, eq(r_{i+1}..r_n, x') = eq_hi(x'_hi) * eq_lo(x'_lo),
+ * so it takes about the square root of the memory of the tables of f
+ */
+pub struct TimeZeroCheckProver<F: Field, S: Stream<F>> {
+    pub current_round: usize,
+    pub eq_prefix: F,
+    pub num_variables: usize,
+    pub point: Vec<F>,
+    pub virtual_prover: TimeVirtualProver<F, S>,
+}
+
+impl<F: Field, S: Stream<F>> TimeZeroCheckProver<F, S> {
+    pub fn total_rounds(&self) -> usize {
+        self.num_variables
+    }
+    // eq(r, x) for every x, the first variable is the most significant bit
+    fn eq_evaluations(point: &[F]) -> Vec<F> {
+        let mut evaluations = vec![F::ONE];
+        for r_j in point {
+            evaluations = evaluations
+                .iter()
+                .flat_map(|evaluation| [*evaluation * (F::ONE - r_j), *evaluation * r_j])
+                .collect();
+        }
+        evaluations
+    }
+    // eq(r_{i+1}..r_n, ·) as the tables of its high and low variables
+    pub fn eq_suffix_tables(&self) -> (Vec<F>, Vec<F>) {
+        let suffix = &self.point[self.current_round + 1..];
+        let (hi, lo) = suffix.split_at(suffix.len() / 2);
+        (Self::eq_evaluations(hi), Self::eq_evaluations(lo))
+    }
+    pub fn compute_round(&self) -> Vec<F> {
+        let r_i = self.point[self.current_round];
+        let degree = self.virtual_prover.polynomial.degree();
+
+        // t_i at 0, 1, ..., d
+        let (eq_hi, eq_lo) = self.eq_suffix_tables();
+        let lo_mask = eq_lo.len() - 1;
+        let lo_num_variables = eq_lo.len().ilog2();
+        let mut t_evaluations = self.virtual_prover.vsbw_evaluate_weighted(|index| {
+            eq_hi[index >> lo_num_variables] * eq_lo[index & lo_mask]
+        });
+
+        // s_i has one more degree than t_i because of eq(r_i, X)
+        t_evaluations.push(
+            LagrangePolynomial::<F, GraycodeOrder>::evaluate_from_points(
+                F::from((degree + 1) as u64),
+                &t_evaluations,
+            ),
+        );
+        t_evaluations
+            .into_iter()
+            .enumerate()
+            .map(|(point, t_evaluation)| {
+                let point = F::from(point as u64);
+                let eq_evaluation = r_i * point + (F::ONE - r_i) * (F::ONE - point);
+                self.eq_prefix * eq_evaluation * t_evaluation
+            })
+            .collect()
+    }
+}